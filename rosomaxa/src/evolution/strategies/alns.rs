@@ -0,0 +1,257 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/evolution/strategies/alns_test.rs"]
+mod alns_test;
+
+use super::*;
+use crate::hyper::HeuristicSearchOperators;
+use crate::utils::{compare_floats, Timer};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// Specifies acceptance criteria used by `Alns` strategy.
+#[derive(Clone, Debug)]
+pub enum AlnsAcceptance {
+    /// Simulated annealing: a worse solution is accepted with `exp(-delta / temperature)` probability,
+    /// where delta is a relative difference in fitness. Temperature decreases exponentially from initial
+    /// to final value along with search progress.
+    SimulatedAnnealing {
+        /// Initial temperature.
+        initial_temperature: f64,
+        /// Final temperature.
+        final_temperature: f64,
+    },
+
+    /// Record-to-record travel: a solution is accepted if its relative difference from the best known
+    /// one is within threshold. Threshold decreases linearly to zero along with search progress.
+    RecordToRecord {
+        /// Initial threshold.
+        deviation: f64,
+    },
+}
+
+/// Specifies `Alns` strategy settings.
+#[derive(Clone, Debug)]
+pub struct AlnsConfig {
+    /// An acceptance criteria.
+    pub acceptance: AlnsAcceptance,
+    /// Operator scores: for a new best known solution, for improving current solution and for
+    /// accepted one.
+    pub scores: (f64, f64, f64),
+    /// A reaction factor which controls how quickly weights react to operator performance.
+    pub reaction_factor: f64,
+    /// Amount of iterations after which operator weights are updated.
+    pub segment_size: usize,
+}
+
+impl Default for AlnsConfig {
+    fn default() -> Self {
+        Self {
+            acceptance: AlnsAcceptance::SimulatedAnnealing { initial_temperature: 0.05, final_temperature: 0.0001 },
+            scores: (33., 9., 13.),
+            reaction_factor: 0.1,
+            segment_size: 100,
+        }
+    }
+}
+
+/// A classic Adaptive Large Neighbourhood Search (ALNS) strategy: it keeps a single current solution,
+/// selects search operator using roulette wheel based on adaptive weights and accepts new solution
+/// using simulated annealing or record-to-record travel criteria. All produced solutions are passed
+/// to the population, so it keeps the best known ones.
+pub struct Alns<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    operators: HeuristicSearchOperators<C, O, S>,
+    weights: Vec<f64>,
+    scores: Vec<f64>,
+    usages: Vec<usize>,
+    config: AlnsConfig,
+    desired_solutions_amount: usize,
+}
+
+impl<C, O, S> Alns<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `Alns`. Operator weights are used as initial weights.
+    pub fn new(
+        operators: HeuristicSearchOperators<C, O, S>,
+        config: AlnsConfig,
+        desired_solutions_amount: usize,
+    ) -> Self {
+        assert!(!operators.is_empty());
+        assert!(config.segment_size > 0);
+
+        let weights = operators.iter().map(|(_, _, weight)| weight.max(f64::EPSILON)).collect();
+        let size = operators.len();
+
+        Self { operators, weights, scores: vec![0.; size], usages: vec![0; size], config, desired_solutions_amount }
+    }
+
+    /// Selects operator index using roulette wheel.
+    fn select_operator(&self, random: &(dyn Random + Send + Sync)) -> usize {
+        let total = self.weights.iter().sum::<f64>();
+        let mut target = random.uniform_real(0., total);
+
+        self.weights
+            .iter()
+            .position(|weight| {
+                target -= weight;
+                target <= 0.
+            })
+            .unwrap_or(self.weights.len() - 1)
+    }
+
+    /// Updates operator weights using scores collected during the last segment.
+    fn update_weights(&mut self) {
+        let reaction_factor = self.config.reaction_factor;
+
+        self.weights
+            .iter_mut()
+            .zip(self.scores.iter().zip(self.usages.iter()))
+            .filter(|(_, (_, &usages))| usages > 0)
+            .for_each(|(weight, (score, &usages))| {
+                *weight =
+                    (*weight * (1. - reaction_factor) + reaction_factor * score / usages as f64).max(f64::EPSILON);
+            });
+
+        self.scores.iter_mut().for_each(|score| *score = 0.);
+        self.usages.iter_mut().for_each(|usages| *usages = 0);
+    }
+
+    /// Checks whether a candidate solution, which is not better than current one, should be accepted.
+    fn is_accepted(
+        &self,
+        candidate: &S,
+        current: &S,
+        best: &S,
+        progress: f64,
+        random: &(dyn Random + Send + Sync),
+    ) -> bool {
+        let progress = progress.clamp(0., 1.);
+
+        match &self.config.acceptance {
+            AlnsAcceptance::SimulatedAnnealing { initial_temperature, final_temperature } => {
+                let temperature = initial_temperature * (final_temperature / initial_temperature).powf(progress);
+                let delta = get_relative_distance(candidate, current).max(0.);
+
+                temperature > 0. && random.uniform_real(0., 1.) < (-delta / temperature).exp()
+            }
+            AlnsAcceptance::RecordToRecord { deviation } => {
+                get_relative_distance(candidate, best) <= deviation * (1. - progress)
+            }
+        }
+    }
+}
+
+impl<C, O, S> EvolutionStrategy for Alns<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn run(
+        &mut self,
+        heuristic_ctx: Self::Context,
+        termination: Box<dyn Termination<Context = Self::Context, Objective = Self::Objective>>,
+    ) -> EvolutionResult<Self::Solution> {
+        let mut heuristic_ctx = heuristic_ctx;
+        let random = heuristic_ctx.environment().random.clone();
+
+        let mut current = heuristic_ctx
+            .ranked()
+            .next()
+            .map(|(solution, _)| solution.deep_copy())
+            .ok_or_else(|| GenericError::from("alns requires at least one initial solution"))?;
+        let mut iteration = 0_usize;
+
+        loop {
+            let is_terminated = termination.is_termination(&mut heuristic_ctx);
+            let is_quota_reached = heuristic_ctx.environment().quota.as_ref().is_some_and(|q| q.is_reached());
+
+            if is_terminated || is_quota_reached {
+                break;
+            }
+
+            let generation_time = Timer::start();
+            let termination_estimate = termination.estimate(&heuristic_ctx);
+
+            let operator_idx = self.select_operator(random.as_ref());
+            let candidate = self.operators[operator_idx].0.search(&heuristic_ctx, &current);
+
+            let objective = heuristic_ctx.objective();
+            let is_new_best = heuristic_ctx
+                .ranked()
+                .next()
+                .is_none_or(|(best, _)| objective.total_order(&candidate, best) == Ordering::Less);
+            let is_improving = objective.total_order(&candidate, &current) == Ordering::Less;
+            let is_accepted = is_improving
+                || heuristic_ctx.ranked().next().is_some_and(|(best, _)| {
+                    self.is_accepted(&candidate, &current, best, termination_estimate, random.as_ref())
+                });
+
+            let (best_score, improving_score, accepted_score) = self.config.scores;
+            self.scores[operator_idx] += match (is_new_best, is_improving, is_accepted) {
+                (true, ..) => best_score,
+                (_, true, _) => improving_score,
+                (_, _, true) => accepted_score,
+                _ => 0.,
+            };
+            self.usages[operator_idx] += 1;
+
+            iteration += 1;
+            if iteration.is_multiple_of(self.config.segment_size) {
+                self.update_weights();
+            }
+
+            if is_accepted {
+                current = candidate.deep_copy();
+            }
+
+            heuristic_ctx.on_generation(vec![candidate], termination_estimate, generation_time);
+        }
+
+        (heuristic_ctx.environment().logger)(&format!("{self}"));
+
+        let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
+
+        let solutions =
+            population.ranked().map(|(solution, _)| solution.deep_copy()).take(self.desired_solutions_amount).collect();
+
+        Ok((solutions, telemetry_metrics))
+    }
+}
+
+impl<C, O, S> Display for Alns<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "alns operator weights:")?;
+        self.operators
+            .iter()
+            .zip(self.weights.iter())
+            .try_for_each(|((_, name, _), weight)| writeln!(f, "\t{name}: {weight:.3}"))
+    }
+}
+
+/// Returns relative difference between two solutions using the first fitness value which differs.
+/// Positive value means that the first solution is worse.
+fn get_relative_distance<S: HeuristicSolution>(a: &S, b: &S) -> f64 {
+    a.fitness()
+        .zip(b.fitness())
+        .find(|(a, b)| compare_floats(*a, *b) != Ordering::Equal)
+        .map(|(a, b)| if b.abs() > f64::EPSILON { (a - b) / b.abs() } else { a - b })
+        .unwrap_or_default()
+}
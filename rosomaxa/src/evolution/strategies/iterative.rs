@@ -76,6 +76,8 @@ where
         (heuristic_ctx.environment().logger)(&format!("{heuristic}"));
 
         let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
+        let telemetry_metrics = telemetry_metrics
+            .map(|metrics| TelemetryMetrics { operators: heuristic.get_operator_statistics(), ..metrics });
 
         let solutions =
             population.ranked().map(|(solution, _)| solution.deep_copy()).take(self.desired_solutions_amount).collect();
@@ -7,6 +7,9 @@ mod async_iterative;
 #[cfg(feature = "async-evolution")]
 pub use self::async_iterative::{AsyncIterative, AsyncParams};
 
+mod alns;
+pub use self::alns::{Alns, AlnsAcceptance, AlnsConfig};
+
 mod iterative;
 pub use self::iterative::Iterative;
 
@@ -5,6 +5,7 @@
 mod telemetry_test;
 
 use crate::algorithms::math::relative_distance;
+use crate::hyper::SearchOperatorStatistics;
 use crate::prelude::*;
 use crate::utils::Timer;
 use crate::{DynHeuristicPopulation, RemedianUsize};
@@ -22,6 +23,10 @@ pub struct TelemetryMetrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// Statistics learned about search operators, if heuristic supports it. They are collected only when
+    /// metrics are enabled and the search is run by the default evolution strategy: ALNS and async
+    /// iterative heuristics do not export them.
+    pub operators: Vec<SearchOperatorStatistics>,
 }
 
 /// Represents information about generation.
@@ -117,7 +122,7 @@ where
     pub fn new(mode: TelemetryMode) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
+            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![], operators: vec![] },
             mode,
             statistics: Default::default(),
             improvement_tracker: ImprovementTracker::new(1000),
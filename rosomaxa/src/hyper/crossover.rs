@@ -0,0 +1,57 @@
+#[cfg(test)]
+#[path = "../../tests/unit/hyper/crossover_test.rs"]
+mod crossover_test;
+
+use super::*;
+
+/// A search operator which applies crossover operator on given solution and another one selected
+/// from the population. This way, crossover can be used by hyper-heuristics as any other search operator.
+pub struct CrossoverSearch<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    crossover: Arc<dyn HeuristicCrossoverOperator<Context = C, Objective = O, Solution = S> + Send + Sync>,
+}
+
+impl<C, O, S> CrossoverSearch<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `CrossoverSearch`.
+    pub fn new(
+        crossover: Arc<dyn HeuristicCrossoverOperator<Context = C, Objective = O, Solution = S> + Send + Sync>,
+    ) -> Self {
+        Self { crossover }
+    }
+}
+
+impl<C, O, S> HeuristicSearchOperator for CrossoverSearch<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        // NOTE given solution is usually a copy of one selected from the population, so parents
+        //      with the same fitness are excluded to avoid crossover of the solution with itself
+        let parents =
+            heuristic_ctx.selected().filter(|other| !other.fitness().eq(solution.fitness())).collect::<Vec<_>>();
+
+        if parents.is_empty() {
+            return solution.deep_copy();
+        }
+
+        let random = heuristic_ctx.environment().random.as_ref();
+        let other = parents[random.uniform_int(0, parents.len() as i32 - 1) as usize];
+
+        self.crossover.crossover(heuristic_ctx, solution, other)
+    }
+}
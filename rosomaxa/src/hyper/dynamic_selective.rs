@@ -22,6 +22,27 @@ pub type HeuristicSearchOperators<C, O, S> =
 pub type HeuristicDiversifyOperators<C, O, S> =
     Vec<Arc<dyn HeuristicDiversifyOperator<Context = C, Objective = O, Solution = S> + Send + Sync>>;
 
+/// Statistics of search operator reward distribution learned by `DynamicSelective` heuristic in the given
+/// search state. Can be exported at the end of the search and used as priors in the next one.
+/// NOTE: they are exported only within telemetry metrics, see `TelemetryMetrics::operators`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchOperatorStatistics {
+    /// A search state name.
+    pub state: String,
+    /// A search operator name.
+    pub name: String,
+    /// Gamma shape parameter.
+    pub alpha: f64,
+    /// Gamma rate parameter.
+    pub beta: f64,
+    /// Estimated reward mean.
+    pub mu: f64,
+    /// Estimated reward variance.
+    pub v: f64,
+    /// The number of times the operator has been used.
+    pub n: usize,
+}
+
 /// An experimental dynamic selective hyper heuristic which selects inner heuristics
 /// based on how they work during the search. The selection process is modeled using reinforcement
 /// learning technics.
@@ -84,6 +105,10 @@ where
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
         diversify_solutions(heuristic_ctx, solutions, self.diversify_operators.as_slice())
     }
+
+    fn get_operator_statistics(&self) -> Vec<SearchOperatorStatistics> {
+        self.agent.get_statistics()
+    }
 }
 
 impl<C, O, S> DynamicSelective<C, O, S>
@@ -100,6 +125,13 @@ where
     ) -> Self {
         Self { agent: SearchAgent::new(search_operators, environment), diversify_operators }
     }
+
+    /// Uses statistics learned in previous runs as priors for operator selection. Statistics of
+    /// unknown operators or states are ignored.
+    pub fn with_priors(mut self, priors: &[SearchOperatorStatistics]) -> Self {
+        self.agent.set_priors(priors);
+        self
+    }
 }
 
 type SlotMachines<'a, C, O, S> = Vec<(SlotMachine<SearchAction<'a, C, O, S>, DefaultDistributionSampler>, String)>;
@@ -261,6 +293,37 @@ where
         self.tracker.observe_sample(generation, feedback.sample.clone())
     }
 
+    /// Returns learned statistics of all slot machines.
+    pub fn get_statistics(&self) -> Vec<SearchOperatorStatistics> {
+        let mut statistics = self
+            .slot_machines
+            .iter()
+            .flat_map(|(state, slots)| {
+                slots.iter().map(move |(slot, name)| {
+                    let (alpha, beta, mu, v, n) = slot.get_params();
+                    SearchOperatorStatistics { state: state.to_string(), name: name.clone(), alpha, beta, mu, v, n }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE keep output stable as hash map has no defined order
+        statistics.sort_by(|a, b| a.state.cmp(&b.state).then_with(|| a.name.cmp(&b.name)));
+
+        statistics
+    }
+
+    /// Sets learned statistics of slot machines with matching state and operator name.
+    pub fn set_priors(&mut self, priors: &[SearchOperatorStatistics]) {
+        self.slot_machines.iter_mut().for_each(|(state, slots)| {
+            let state = state.to_string();
+            slots.iter_mut().for_each(|(slot, name)| {
+                if let Some(prior) = priors.iter().find(|prior| prior.state == state && prior.name == *name) {
+                    slot.set_params((prior.alpha, prior.beta, prior.mu, prior.v, prior.n));
+                }
+            });
+        });
+    }
+
     /// Updates statistics about heuristic internal parameters.
     pub fn save_params(&mut self, generation: usize) {
         if !self.tracker.telemetry_enabled() {
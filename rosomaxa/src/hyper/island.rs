@@ -0,0 +1,125 @@
+#[cfg(test)]
+#[path = "../../tests/unit/hyper/island_test.rs"]
+mod island_test;
+
+use super::*;
+use std::fmt::Formatter;
+
+/// Specifies a way to exchange solutions with other islands (e.g. solver instances running in other
+/// processes or on other machines) in the island model.
+pub trait Migration: Send + Sync {
+    /// A heuristic solution type.
+    type Solution: HeuristicSolution;
+
+    /// Sends given solutions to neighbour islands. Implementation is not supposed to block.
+    fn emigrate(&self, solutions: Vec<&Self::Solution>);
+
+    /// Returns solutions received from neighbour islands since the last call. Implementation is not
+    /// supposed to block.
+    fn immigrate(&self) -> Vec<Self::Solution>;
+}
+
+/// Specifies island model parameters.
+#[derive(Clone, Debug)]
+pub struct IslandConfig {
+    /// An amount of generations between two emigrations.
+    pub migration_interval: usize,
+    /// A max amount of best known solutions sent on each emigration.
+    pub migration_size: usize,
+}
+
+impl Default for IslandConfig {
+    fn default() -> Self {
+        Self { migration_interval: 100, migration_size: 1 }
+    }
+}
+
+/// A hyper-heuristic which wraps another one and periodically exchanges best known solutions with
+/// other islands using given migration: emigrants are taken from the population, immigrants are
+/// returned as offspring, so they are considered by the population as any other new solution.
+pub struct IslandHeuristic<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    inner: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+    migration: Box<dyn Migration<Solution = S>>,
+    config: IslandConfig,
+}
+
+impl<C, O, S> IslandHeuristic<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `IslandHeuristic`.
+    pub fn new(
+        inner: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+        migration: Box<dyn Migration<Solution = S>>,
+        config: IslandConfig,
+    ) -> Self {
+        assert!(config.migration_interval > 0);
+
+        Self { inner, migration, config }
+    }
+
+    fn migrate(&self, heuristic_ctx: &C, mut offspring: Vec<S>) -> Vec<S> {
+        let generation = heuristic_ctx.statistics().generation;
+
+        if generation > 0 && generation.is_multiple_of(self.config.migration_interval) {
+            let emigrants =
+                heuristic_ctx.ranked().map(|(solution, _)| solution).take(self.config.migration_size).collect();
+            self.migration.emigrate(emigrants);
+        }
+
+        offspring.extend(self.migration.immigrate());
+
+        offspring
+    }
+}
+
+impl<C, O, S> HyperHeuristic for IslandHeuristic<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn search(&mut self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        let offspring = self.inner.search(heuristic_ctx, solution);
+        self.migrate(heuristic_ctx, offspring)
+    }
+
+    fn search_many(&mut self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        let offspring = self.inner.search_many(heuristic_ctx, solutions);
+        self.migrate(heuristic_ctx, offspring)
+    }
+
+    fn diversify(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        self.inner.diversify(heuristic_ctx, solution)
+    }
+
+    fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        self.inner.diversify_many(heuristic_ctx, solutions)
+    }
+
+    fn get_operator_statistics(&self) -> Vec<SearchOperatorStatistics> {
+        self.inner.get_operator_statistics()
+    }
+}
+
+impl<C, O, S> Display for IslandHeuristic<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
@@ -1,8 +1,14 @@
 //! This module contains a hyper-heuristic logic.
 
+mod crossover;
+pub use self::crossover::*;
+
 mod dynamic_selective;
 pub use self::dynamic_selective::*;
 
+mod island;
+pub use self::island::*;
+
 mod static_selective;
 pub use self::static_selective::*;
 
@@ -25,6 +31,24 @@ pub trait HeuristicSearchOperator {
     fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution;
 }
 
+/// A heuristic operator which is supposed to create a new solution by combining two parent solutions.
+pub trait HeuristicCrossoverOperator {
+    /// A heuristic context type.
+    type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
+    /// A heuristic objective type.
+    type Objective: HeuristicObjective<Solution = Self::Solution>;
+    /// A heuristic solution type.
+    type Solution: HeuristicSolution;
+
+    /// Creates a new solution (child) from two given ones (parents).
+    fn crossover(
+        &self,
+        heuristic_ctx: &Self::Context,
+        first: &Self::Solution,
+        second: &Self::Solution,
+    ) -> Self::Solution;
+}
+
 /// A heuristic operator which is supposed to diversify passed solution.
 pub trait HeuristicDiversifyOperator {
     /// A heuristic context type.
@@ -62,6 +86,11 @@ pub trait HyperHeuristic: Display {
     /// As the `diversify` method requires a mutable reference, implementations of `diversify_many` is
     /// supposed to run its logic in parallel for each solution.
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution>;
+
+    /// Returns statistics learned about search operators during the search, if heuristic supports it.
+    fn get_operator_statistics(&self) -> Vec<SearchOperatorStatistics> {
+        Vec::default()
+    }
 }
 
 /// Gets probability to run diversify search.
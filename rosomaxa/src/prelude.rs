@@ -20,6 +20,7 @@ pub use crate::population::HeuristicPopulation;
 pub use crate::population::RosomaxaConfig;
 pub use crate::population::SelectionPhase;
 
+pub use crate::hyper::HeuristicCrossoverOperator;
 pub use crate::hyper::HeuristicSearchOperator;
 pub use crate::hyper::HyperHeuristic;
 
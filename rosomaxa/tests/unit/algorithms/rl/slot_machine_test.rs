@@ -66,3 +66,13 @@ fn can_find_proper_estimations() {
         panic!("too many estimation failures: {failed_slot_estimations} < {expected_failures_threshold}")
     }
 }
+
+#[test]
+fn can_set_params() {
+    let sampler = DefaultDistributionSampler::new(create_test_random());
+    let mut slot = SlotMachine::new(1., TestAction(sampler.clone()), sampler);
+
+    slot.set_params((2., 3., 4., 5., 6));
+
+    assert_eq!(slot.get_params(), (2., 3., 4., 5., 6));
+}
@@ -0,0 +1,150 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::{create_example_objective, create_heuristic_context_with_solutions};
+use crate::helpers::utils::create_test_random;
+use crate::hyper::HeuristicSearchOperator;
+use crate::termination::MaxGeneration;
+use std::sync::Arc;
+
+/// Moves solution towards optimum of rosenbrock function by a random step.
+struct RandomStep {
+    max_step: f64,
+}
+
+impl HeuristicSearchOperator for RandomStep {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let random = heuristic_ctx.environment().random.clone();
+        let data =
+            solution.data.iter().map(|value| value + random.uniform_real(-self.max_step, self.max_step)).collect();
+
+        VectorSolution::new(data, create_example_objective())
+    }
+}
+
+fn create_alns(weights: Vec<f64>, acceptance: AlnsAcceptance) -> Alns<VectorContext, VectorObjective, VectorSolution> {
+    let operators = weights
+        .into_iter()
+        .enumerate()
+        .map(|(idx, weight)| {
+            let operator: Arc<
+                dyn HeuristicSearchOperator<
+                        Context = VectorContext,
+                        Objective = VectorObjective,
+                        Solution = VectorSolution,
+                    > + Send
+                    + Sync,
+            > = Arc::new(RandomStep { max_step: 0.1 });
+
+            (operator, format!("op{idx}"), weight)
+        })
+        .collect();
+
+    Alns::new(operators, AlnsConfig { acceptance, segment_size: 10, ..AlnsConfig::default() }, 1)
+}
+
+fn create_solution(value: f64) -> VectorSolution {
+    let objective =
+        Arc::new(VectorObjective::new(Arc::new(|data: &[f64]| data[0]), Arc::new(|data: &[f64]| data.to_vec())));
+
+    VectorSolution::new(vec![value], objective)
+}
+
+parameterized_test! {can_update_weights, (scores, usages, expected), {
+    can_update_weights_impl(scores, usages, expected);
+}}
+
+can_update_weights! {
+    case01_no_usages: (vec![0., 0.], vec![0, 0], vec![1., 1.]),
+    case02_one_used: (vec![33., 0.], vec![1, 0], vec![4.2, 1.]),
+    case03_both_used: (vec![33., 0.], vec![3, 2], vec![2., 0.9]),
+}
+
+fn can_update_weights_impl(scores: Vec<f64>, usages: Vec<usize>, expected: Vec<f64>) {
+    let mut alns = create_alns(vec![1., 1.], AlnsConfig::default().acceptance);
+    alns.scores = scores;
+    alns.usages = usages;
+
+    alns.update_weights();
+
+    assert_eq!(alns.weights.len(), expected.len());
+    alns.weights.iter().zip(expected.iter()).for_each(|(actual, expected)| assert!((actual - expected).abs() < 1E-6));
+    assert!(alns.scores.iter().all(|score| *score == 0.));
+    assert!(alns.usages.iter().all(|usages| *usages == 0));
+}
+
+#[test]
+fn can_select_operator_with_higher_weight() {
+    let alns = create_alns(vec![0., 1., 0.], AlnsConfig::default().acceptance);
+    let random = create_test_random();
+
+    assert!((0..100).all(|_| alns.select_operator(random.as_ref()) == 1));
+}
+
+parameterized_test! {can_accept_with_record_to_record, (candidate, progress, expected), {
+    can_accept_with_record_to_record_impl(candidate, progress, expected);
+}}
+
+can_accept_with_record_to_record! {
+    case01_within_threshold: (10.5, 0., true),
+    case02_out_of_threshold: (12., 0., false),
+    case03_within_reduced_threshold: (10.2, 0.5, true),
+    case04_out_of_reduced_threshold: (10.7, 0.5, false),
+    case05_end_of_search: (10.1, 1., false),
+}
+
+fn can_accept_with_record_to_record_impl(candidate: f64, progress: f64, expected: bool) {
+    let alns = create_alns(vec![1.], AlnsAcceptance::RecordToRecord { deviation: 0.1 });
+    let (candidate, current, best) = (create_solution(candidate), create_solution(11.), create_solution(10.));
+
+    let result = alns.is_accepted(&candidate, &current, &best, progress, create_test_random().as_ref());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_accept_with_simulated_annealing, (candidate, temperature, expected), {
+    can_accept_with_simulated_annealing_impl(candidate, temperature, expected);
+}}
+
+can_accept_with_simulated_annealing! {
+    case01_same_fitness: (10., 0.0001, true),
+    case02_much_worse_with_low_temperature: (20., 0.0001, false),
+    case03_zero_temperature: (10.1, 0., false),
+}
+
+fn can_accept_with_simulated_annealing_impl(candidate: f64, temperature: f64, expected: bool) {
+    let alns = create_alns(
+        vec![1.],
+        AlnsAcceptance::SimulatedAnnealing { initial_temperature: temperature, final_temperature: temperature },
+    );
+    let (candidate, current) = (create_solution(candidate), create_solution(10.));
+
+    let result = alns.is_accepted(&candidate, &current, &current, 0.5, create_test_random().as_ref());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_improve_initial_solution() {
+    let heuristic_ctx = create_heuristic_context_with_solutions(vec![vec![2., 2.]]);
+    let initial_fitness = heuristic_ctx.ranked().next().map(|(solution, _)| solution.fitness()).unwrap();
+    let mut alns = create_alns(vec![1., 1.], AlnsConfig::default().acceptance);
+
+    let (solutions, _) = alns.run(heuristic_ctx, Box::new(MaxGeneration::new(200))).expect("cannot run alns");
+
+    assert_eq!(solutions.len(), 1);
+    assert!(solutions[0].fitness() < initial_fitness);
+}
+
+#[test]
+fn can_return_error_without_initial_solution() {
+    let heuristic_ctx = create_heuristic_context_with_solutions(vec![]);
+    let mut alns = create_alns(vec![1.], AlnsConfig::default().acceptance);
+
+    let result = alns.run(heuristic_ctx, Box::new(MaxGeneration::new(10)));
+
+    assert!(result.is_err());
+}
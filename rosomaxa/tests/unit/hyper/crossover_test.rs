@@ -0,0 +1,37 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::{create_example_objective, create_heuristic_context_with_solutions};
+
+struct AverageCrossover {}
+
+impl HeuristicCrossoverOperator for AverageCrossover {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn crossover(&self, _: &Self::Context, first: &Self::Solution, second: &Self::Solution) -> Self::Solution {
+        let data = first.data.iter().zip(second.data.iter()).map(|(a, b)| (a + b) / 2.).collect();
+
+        VectorSolution::new(data, create_example_objective())
+    }
+}
+
+parameterized_test! {can_use_crossover_as_search_operator, (population, solution, expected), {
+    can_use_crossover_as_search_operator_impl(population, solution, expected);
+}}
+
+can_use_crossover_as_search_operator! {
+    case01_with_other_parent: (vec![vec![0., 0.]], vec![2., 2.], vec![1., 1.]),
+    case02_without_other_parent: (vec![vec![0., 0.]], vec![0., 0.], vec![0., 0.]),
+    case03_empty_population: (vec![], vec![1., 1.], vec![1., 1.]),
+}
+
+fn can_use_crossover_as_search_operator_impl(population: Vec<Vec<f64>>, solution: Vec<f64>, expected: Vec<f64>) {
+    let heuristic_ctx = create_heuristic_context_with_solutions(population);
+    let solution = VectorSolution::new(solution, create_example_objective());
+    let operator = CrossoverSearch::new(Arc::new(AverageCrossover {}));
+
+    let result = operator.search(&heuristic_ctx, &solution);
+
+    assert_eq!(result.data, expected);
+}
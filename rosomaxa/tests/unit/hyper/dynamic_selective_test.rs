@@ -154,3 +154,57 @@ fn can_handle_when_objective_lies() {
 
     assert_eq!(distance, 0.)
 }
+
+#[test]
+fn can_export_and_use_learned_statistics_as_priors() {
+    struct CopyOperator;
+    impl HeuristicSearchOperator for CopyOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+            solution.deep_copy()
+        }
+    }
+    let create_heuristic = |names: &[&str]| {
+        DynamicSelective::<VectorContext, VectorObjective, VectorSolution>::new(
+            names
+                .iter()
+                .map(|name| {
+                    let operator: Arc<
+                        dyn HeuristicSearchOperator<
+                                Context = VectorContext,
+                                Objective = VectorObjective,
+                                Solution = VectorSolution,
+                            > + Send
+                            + Sync,
+                    > = Arc::new(CopyOperator);
+                    (operator, name.to_string(), 1.)
+                })
+                .collect(),
+            vec![],
+            &Environment::default(),
+        )
+    };
+    let solution = VectorSolution::new(vec![0., 0.], create_example_objective());
+    let mut heuristic = create_heuristic(&["first", "second"]);
+    heuristic.search_many(&create_default_heuristic_context(), (0..10).map(|_| &solution).collect());
+    let learned = heuristic.get_operator_statistics();
+
+    let restored = create_heuristic(&["first", "second"]).with_priors(learned.as_slice());
+    let partially_restored = create_heuristic(&["second", "third"]).with_priors(learned.as_slice());
+
+    assert_eq!(learned.len(), 4);
+    assert_eq!(learned.iter().map(|statistic| statistic.n).sum::<usize>(), 10);
+    assert_eq!(restored.get_operator_statistics(), learned);
+    let partially_restored = partially_restored.get_operator_statistics();
+    assert_eq!(partially_restored.len(), 4);
+    partially_restored.iter().for_each(|statistic| {
+        let expected = learned.iter().find(|prior| prior.state == statistic.state && prior.name == statistic.name);
+        match expected {
+            Some(expected) => assert_eq!(statistic, expected),
+            None => assert_eq!(statistic.n, 0),
+        }
+    });
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::example::{VectorContext, VectorObjective, VectorSolution};
+use crate::helpers::example::{create_example_objective, create_heuristic_context_with_solutions};
+use crate::utils::Timer;
+use std::sync::Mutex;
+
+struct NoopHeuristic;
+
+impl HyperHeuristic for NoopHeuristic {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&mut self, _: &Self::Context, solution: &Self::Solution) -> Vec<Self::Solution> {
+        vec![solution.deep_copy()]
+    }
+
+    fn search_many(&mut self, _: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        solutions.into_iter().map(|solution| solution.deep_copy()).collect()
+    }
+
+    fn diversify(&self, _: &Self::Context, _: &Self::Solution) -> Vec<Self::Solution> {
+        vec![]
+    }
+
+    fn diversify_many(&self, _: &Self::Context, _: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        vec![]
+    }
+}
+
+impl Display for NoopHeuristic {
+    fn fmt(&self, _: &mut Formatter<'_>) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+/// Sends emigrants into outbox and takes immigrants from inbox.
+struct InMemoryMigration {
+    outbox: Arc<Mutex<Vec<Vec<f64>>>>,
+    inbox: Arc<Mutex<Vec<Vec<f64>>>>,
+    objective: Arc<VectorObjective>,
+}
+
+impl Migration for InMemoryMigration {
+    type Solution = VectorSolution;
+
+    fn emigrate(&self, solutions: Vec<&Self::Solution>) {
+        self.outbox.lock().unwrap().extend(solutions.into_iter().map(|solution| solution.data.clone()));
+    }
+
+    fn immigrate(&self) -> Vec<Self::Solution> {
+        self.inbox.lock().unwrap().drain(..).map(|data| VectorSolution::new(data, self.objective.clone())).collect()
+    }
+}
+
+fn create_island(
+    outbox: Arc<Mutex<Vec<Vec<f64>>>>,
+    inbox: Arc<Mutex<Vec<Vec<f64>>>>,
+    config: IslandConfig,
+) -> IslandHeuristic<VectorContext, VectorObjective, VectorSolution> {
+    let migration = InMemoryMigration { outbox, inbox, objective: create_example_objective() };
+
+    IslandHeuristic::new(Box::new(NoopHeuristic), Box::new(migration), config)
+}
+
+#[test]
+fn can_exchange_solutions_between_islands() {
+    let (first_to_second, second_to_first) = (Arc::new(Mutex::new(vec![])), Arc::new(Mutex::new(vec![])));
+    let config = IslandConfig { migration_interval: 2, migration_size: 1 };
+    let mut first = create_island(first_to_second.clone(), second_to_first.clone(), config.clone());
+    let mut second = create_island(second_to_first.clone(), first_to_second.clone(), config);
+    let mut first_ctx = create_heuristic_context_with_solutions(vec![vec![0., 0.], vec![1., 1.]]);
+    let second_ctx = create_heuristic_context_with_solutions(vec![vec![2., 2.]]);
+
+    let generations = (0..4)
+        .map(|_| {
+            let parents = first_ctx.selected().collect::<Vec<_>>();
+            let offspring = first.search_many(&first_ctx, parents);
+            let emigrated = first_to_second.lock().unwrap().len();
+            first_ctx.on_generation(offspring, 0., Timer::start());
+
+            emigrated
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(generations, vec![0, 0, 0, 1]);
+
+    let parents = second_ctx.selected().collect::<Vec<_>>();
+    let parents_len = parents.len();
+    let offspring = second.search_many(&second_ctx, parents);
+
+    assert_eq!(offspring.len(), parents_len + 1);
+    assert_eq!(offspring.last().unwrap().data, vec![0., 0.]);
+    assert!(first_to_second.lock().unwrap().is_empty());
+}
@@ -3,8 +3,14 @@
 mod analyze_test;
 
 use super::*;
-use vrp_cli::extensions::analyze::get_clusters;
+use serde::Serialize;
+use std::fmt::Display;
+use vrp_cli::extensions::analyze::{
+    get_clusters, get_feasibility_analysis, get_unassigned_analysis, RelaxationOptions,
+};
 use vrp_core::utils::GenericError;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, Matrix, Problem};
+use vrp_pragmatic::format::solution::deserialize_solution;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -12,50 +18,132 @@ const MATRIX_ARG_NAME: &str = "matrix";
 const MIN_POINTS_ARG_NAME: &str = "min-points";
 const EPSILON_ARG_NAME: &str = "epsilon";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const SOLUTION_ARG_NAME: &str = "solution";
+const TIME_STEP_ARG_NAME: &str = "time-step";
+const MAX_STEPS_ARG_NAME: &str = "max-steps";
 
 pub fn get_analyze_app() -> Command {
-    Command::new("analyze").about("Provides helper functionality to analyze problem or solution").subcommand(
-        Command::new("clusters")
-            .about("Analyzes job clusters")
-            .arg(
-                Arg::new(FORMAT_ARG_NAME)
-                    .help("Specifies input type")
-                    .required(true)
-                    .value_parser(["pragmatic"])
-                    .index(1),
-            )
-            .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
-            .arg(
-                Arg::new(MIN_POINTS_ARG_NAME)
-                    .help("Minimum cluster size")
-                    .short('c')
-                    .default_value("3")
-                    .long(MIN_POINTS_ARG_NAME)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(EPSILON_ARG_NAME)
-                    .help("Epsilon parameter in DBSCAN")
-                    .short('e')
-                    .long(EPSILON_ARG_NAME)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(MATRIX_ARG_NAME)
-                    .help("Specifies path to file with routing matrix")
-                    .short('m')
-                    .long(MATRIX_ARG_NAME)
-                    .num_args(1..)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(OUT_RESULT_ARG_NAME)
-                    .help("Specifies path to the file for result output")
-                    .short('o')
-                    .long(OUT_RESULT_ARG_NAME)
-                    .required(true),
-            ),
-    )
+    Command::new("analyze")
+        .about("Provides helper functionality to analyze problem or solution")
+        .subcommand(
+            Command::new("clusters")
+                .about("Analyzes job clusters")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .value_parser(["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MIN_POINTS_ARG_NAME)
+                        .help("Minimum cluster size")
+                        .short('c')
+                        .default_value("3")
+                        .long(MIN_POINTS_ARG_NAME)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(EPSILON_ARG_NAME)
+                        .help("Epsilon parameter in DBSCAN")
+                        .short('e')
+                        .long(EPSILON_ARG_NAME)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            Command::new("feasibility")
+                .about(
+                    "Analyzes problem feasibility: jobs which cannot be served and a lower bound on required vehicles",
+                )
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .value_parser(["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output in json format")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("unassigned")
+                .about("Analyzes unassigned jobs by trying to relax restrictions which prevent their assignment")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .value_parser(["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(SOLUTION_ARG_NAME)
+                        .help("Sets the solution file with unassigned jobs")
+                        .short('s')
+                        .long(SOLUTION_ARG_NAME)
+                        .required(true),
+                )
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(TIME_STEP_ARG_NAME)
+                        .help("Specifies a step in seconds used to widen job time windows")
+                        .long(TIME_STEP_ARG_NAME)
+                        .default_value("900")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(MAX_STEPS_ARG_NAME)
+                        .help("Specifies a max amount of steps used to widen time windows or increase capacity")
+                        .long(MAX_STEPS_ARG_NAME)
+                        .default_value("8")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output in json format")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(false),
+                ),
+        )
 }
 
 pub fn run_analyze(
@@ -89,6 +177,80 @@ pub fn run_analyze(
 
             geo_writer.write_all(clusters.as_bytes()).map_err(|err| format!("cannot write result: '{err}'").into())
         }
+        Some(("unassigned", unassigned_matches)) => {
+            let problem_path = unassigned_matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+            let solution_path = unassigned_matches.get_one::<String>(SOLUTION_ARG_NAME).unwrap();
+
+            let problem = read_problem(problem_path)?;
+            let solution = deserialize_solution(BufReader::new(open_file(solution_path, "solution")))
+                .map_err(|err| format!("cannot read solution: '{err}'"))?;
+            let matrices = read_matrices(unassigned_matches)?;
+
+            let default_options = RelaxationOptions::default();
+            let options = RelaxationOptions {
+                time_step: parse_float_value::<f64>(unassigned_matches, TIME_STEP_ARG_NAME, "time step")?
+                    .unwrap_or(default_options.time_step),
+                max_steps: parse_int_value::<usize>(unassigned_matches, MAX_STEPS_ARG_NAME, "max steps")?
+                    .unwrap_or(default_options.max_steps),
+            };
+
+            if options.time_step <= 0. || options.max_steps == 0 {
+                return Err("time step and max steps should be positive".into());
+            }
+
+            let analysis = get_unassigned_analysis(&problem, &solution, matrices, &options)
+                .map_err(|err| format!("cannot analyze unassigned jobs: '{err}'"))?;
+
+            write_analysis(unassigned_matches, &analysis, out_writer_func)
+        }
+        Some(("feasibility", feasibility_matches)) => {
+            let problem_path = feasibility_matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+
+            let problem = read_problem(problem_path)?;
+            let matrices = read_matrices(feasibility_matches)?;
+
+            let analysis = get_feasibility_analysis(&problem, matrices)
+                .map_err(|err| format!("cannot analyze feasibility: '{err}'"))?;
+
+            write_analysis(feasibility_matches, &analysis, out_writer_func)
+        }
         _ => Err("no argument with analyze subcommand was used. Use -h to print help information".into()),
     }
 }
+
+fn read_problem(path: &str) -> Result<Problem, GenericError> {
+    deserialize_problem(BufReader::new(open_file(path, "problem")))
+        .map_err(|errs| format!("cannot read problem: '{errs}'").into())
+}
+
+fn read_matrices(matches: &ArgMatches) -> Result<Option<Vec<Matrix>>, GenericError> {
+    matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .map(|paths| {
+            paths
+                .map(|path| deserialize_matrix(BufReader::new(open_file(path, "routing matrix"))))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(|errs| format!("cannot read routing matrix: '{errs}'").into())
+}
+
+/// Writes analysis result in json format: when output file is specified, a summary is printed too.
+fn write_analysis<T: Serialize + Display>(
+    matches: &ArgMatches,
+    analysis: &T,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+    let is_file_output = out_result.is_some();
+
+    let mut writer = out_writer_func(out_result);
+    serde_json::to_writer_pretty(&mut writer, analysis).map_err(|err| format!("cannot write result: '{err}'"))?;
+    writer.flush().map_err(|err| format!("cannot write result: '{err}'"))?;
+
+    if is_file_output {
+        print!("{analysis}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/diff_test.rs"]
+mod diff_test;
+
+use super::*;
+use vrp_cli::extensions::diff::{get_problem_diff, get_solution_diff};
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::problem::{deserialize_problem, Problem};
+use vrp_pragmatic::format::solution::{deserialize_solution, Solution};
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const SOLUTIONS_ARG_NAME: &str = "solutions";
+const PROBLEMS_ARG_NAME: &str = "problems";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_diff_app() -> Command {
+    Command::new("diff")
+        .about("Provides the way to compare two solutions and, optionally, their problems")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(
+            Arg::new(SOLUTIONS_ARG_NAME)
+                .help("Sets two solution files to compare: the first one is used as a baseline")
+                .short('s')
+                .long(SOLUTIONS_ARG_NAME)
+                .required(true)
+                .num_args(2),
+        )
+        .arg(
+            Arg::new(PROBLEMS_ARG_NAME)
+                .help("Sets two problem files to compare in the same order as solutions")
+                .short('p')
+                .long(PROBLEMS_ARG_NAME)
+                .required(false)
+                .num_args(2),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for diff output in json format")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_diff(matches: &ArgMatches) -> Result<(), GenericError> {
+    let solutions = matches.get_many::<String>(SOLUTIONS_ARG_NAME).unwrap().map(|path| read_solution(path));
+    let solutions = solutions.collect::<Result<Vec<_>, _>>()?;
+
+    let problem_diff = matches
+        .get_many::<String>(PROBLEMS_ARG_NAME)
+        .map(|paths| paths.map(|path| read_problem(path)).collect::<Result<Vec<_>, _>>())
+        .transpose()?
+        .map(|problems| get_problem_diff(&problems[0], &problems[1]));
+
+    let diff = get_solution_diff(&solutions[0], &solutions[1], problem_diff);
+
+    if let Some(out_result) = matches.get_one::<String>(OUT_RESULT_ARG_NAME) {
+        let mut writer = create_write_buffer(Some(create_file(out_result, "out diff")));
+        serde_json::to_writer_pretty(&mut writer, &diff).map_err(|err| format!("cannot write diff: '{err}'"))?;
+        writer.flush().map_err(|err| format!("cannot write diff: '{err}'"))?;
+    }
+
+    print!("{diff}");
+
+    Ok(())
+}
+
+fn read_solution(path: &str) -> Result<Solution, GenericError> {
+    deserialize_solution(BufReader::new(open_file(path, "solution")))
+        .map_err(|err| format!("cannot read solution from '{path}': '{err}'").into())
+}
+
+fn read_problem(path: &str) -> Result<Problem, GenericError> {
+    deserialize_problem(BufReader::new(open_file(path, "problem")))
+        .map_err(|errs| format!("cannot read problem from '{path}': '{errs}'").into())
+}
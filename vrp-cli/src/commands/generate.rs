@@ -3,8 +3,9 @@
 mod generate_test;
 
 use super::*;
+use clap::ArgAction;
 use std::io::BufReader;
-use vrp_cli::extensions::generate::generate_problem;
+use vrp_cli::extensions::generate::*;
 use vrp_core::prelude::GenericError;
 use vrp_pragmatic::format::problem::{serialize_problem, Problem};
 use vrp_pragmatic::format::CoordIndex;
@@ -17,6 +18,15 @@ pub const JOBS_SIZE_ARG_NAME: &str = "jobs-size";
 pub const VEHICLES_SIZE_ARG_NAME: &str = "vehicles-size";
 pub const LOCATIONS_ARG_NAME: &str = "locations";
 pub const AREA_SIZE_ARG_NAME: &str = "area-size";
+pub const DISTRIBUTION_ARG_NAME: &str = "distribution";
+pub const CLUSTERS_ARG_NAME: &str = "clusters";
+pub const TIME_WINDOW_WIDTH_ARG_NAME: &str = "tw-width";
+pub const PICKUP_DELIVERY_RATIO_ARG_NAME: &str = "pickup-delivery-ratio";
+pub const SKILLS_ARG_NAME: &str = "skills";
+pub const SKILLS_RATIO_ARG_NAME: &str = "skills-ratio";
+pub const BREAKS_ARG_NAME: &str = "breaks";
+pub const RELOADS_ARG_NAME: &str = "reloads";
+pub const SEED_ARG_NAME: &str = "seed";
 
 pub fn get_generate_app() -> Command {
     Command::new("generate")
@@ -65,6 +75,67 @@ pub fn get_generate_app() -> Command {
                 .long(AREA_SIZE_ARG_NAME)
                 .required(false),
         )
+        .arg(
+            Arg::new(DISTRIBUTION_ARG_NAME)
+                .help("Specifies job locations distribution: uniform (Solomon R), clustered (Solomon C), random-clustered (Solomon RC) or ring")
+                .short('d')
+                .long(DISTRIBUTION_ARG_NAME)
+                .required(false)
+                .default_value("uniform")
+                .value_parser(["uniform", "clustered", "random-clustered", "ring"]),
+        )
+        .arg(
+            Arg::new(CLUSTERS_ARG_NAME)
+                .help("Amount of clusters used by clustered distributions")
+                .long(CLUSTERS_ARG_NAME)
+                .required(false)
+                .default_value("5"),
+        )
+        .arg(
+            Arg::new(TIME_WINDOW_WIDTH_ARG_NAME)
+                .help("Specifies time window width distribution in seconds as uniform:<min>,<max> or normal:<mean>,<std_dev>.\n\
+                       When omitted, time windows are copied from prototype")
+                .long(TIME_WINDOW_WIDTH_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(PICKUP_DELIVERY_RATIO_ARG_NAME)
+                .help("A ratio of jobs with pickup and delivery. When omitted, job types are copied from prototype")
+                .long(PICKUP_DELIVERY_RATIO_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(SKILLS_ARG_NAME)
+                .help("Amount of distinct skills required by jobs and distributed among vehicle types")
+                .long(SKILLS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(SKILLS_RATIO_ARG_NAME)
+                .help("A ratio of jobs which require a skill")
+                .long(SKILLS_RATIO_ARG_NAME)
+                .required(false)
+                .default_value("0.5"),
+        )
+        .arg(
+            Arg::new(BREAKS_ARG_NAME)
+                .help("Adds an optional break in the middle of each vehicle shift")
+                .long(BREAKS_ARG_NAME)
+                .action(ArgAction::SetTrue)
+                .required(false),
+        )
+        .arg(
+            Arg::new(RELOADS_ARG_NAME)
+                .help("Amount of reload depots added to each vehicle shift")
+                .long(RELOADS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(SEED_ARG_NAME)
+                .help("A seed which makes generation reproducible")
+                .long(SEED_ARG_NAME)
+                .required(false),
+        )
 }
 
 pub fn run_generate(matches: &ArgMatches) -> Result<(), GenericError> {
@@ -95,15 +166,40 @@ fn generate_problem_from_args(matches: &ArgMatches) -> Result<(Problem, String),
 
     let jobs_size = parse_int_value::<usize>(matches, JOBS_SIZE_ARG_NAME, "jobs size")?.unwrap();
     let vehicles_size = parse_int_value::<usize>(matches, VEHICLES_SIZE_ARG_NAME, "vehicles size")?.unwrap();
-    let area_size = parse_float_value::<f64>(matches, AREA_SIZE_ARG_NAME, "area size")?;
+    let clusters = parse_int_value::<usize>(matches, CLUSTERS_ARG_NAME, "clusters")?.unwrap_or(5);
+    let distribution = match matches.get_one::<String>(DISTRIBUTION_ARG_NAME).map(String::as_str) {
+        Some("clustered") => LocationDistribution::Clustered { clusters },
+        Some("random-clustered") => LocationDistribution::RandomClustered { clusters },
+        Some("ring") => LocationDistribution::Ring,
+        _ => LocationDistribution::Uniform,
+    };
+    let time_window_width = matches
+        .get_one::<String>(TIME_WINDOW_WIDTH_ARG_NAME)
+        .map(|value| value.parse::<TimeWindowWidth>())
+        .transpose()?;
+
+    let options = GenerateOptions {
+        area_size: parse_float_value::<f64>(matches, AREA_SIZE_ARG_NAME, "area size")?,
+        distribution,
+        time_window_width,
+        pickup_delivery_ratio: parse_float_value::<f64>(
+            matches,
+            PICKUP_DELIVERY_RATIO_ARG_NAME,
+            "pickup delivery ratio",
+        )?,
+        skills: parse_int_value::<usize>(matches, SKILLS_ARG_NAME, "skills")?,
+        skills_ratio: parse_float_value::<f64>(matches, SKILLS_RATIO_ARG_NAME, "skills ratio")?.unwrap_or(0.5),
+        breaks: matches.get_flag(BREAKS_ARG_NAME),
+        reloads: parse_int_value::<usize>(matches, RELOADS_ARG_NAME, "reloads")?,
+        seed: parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed")?,
+        ..GenerateOptions::new(jobs_size, vehicles_size)
+    };
 
-    generate_problem(input_format, input_files, locations_file, jobs_size, vehicles_size, area_size).and_then(
-        |problem| {
-            let coord_index = CoordIndex::new(&problem);
-            ValidationContext::new(&problem, None, &coord_index)
-                .validate()
-                .map_err(|errs| format!("generated problem has some validation errors:\n{errs}",).into())
-                .map(|_| (problem, input_format.to_owned()))
-        },
-    )
+    generate_problem(input_format, input_files, locations_file, &options).and_then(|problem| {
+        let coord_index = CoordIndex::new(&problem);
+        ValidationContext::new(&problem, None, &coord_index)
+            .validate()
+            .map_err(|errs| format!("generated problem has some validation errors:\n{errs}",).into())
+            .map(|_| (problem, input_format.to_owned()))
+    })
 }
@@ -4,18 +4,28 @@ mod import_test;
 
 use super::*;
 use std::io::BufReader;
-use vrp_cli::extensions::import::import_problem;
+use std::path::Path;
+use vrp_cli::extensions::export::write_csv_solution_to_dir;
+use vrp_cli::extensions::import::{import_csv_problem, import_problem, CsvColumns};
 use vrp_core::prelude::GenericError;
 use vrp_pragmatic::format::problem::serialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
 
 pub const FORMAT_ARG_NAME: &str = "FORMAT";
 pub const INPUT_ARG_NAME: &str = "input-files";
 pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+pub const CSV_COLUMNS_ARG_NAME: &str = "csv-columns";
 
 pub fn get_import_app() -> Command {
     Command::new("import")
-        .about("Provides the way to import problem from various formats")
-        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["csv"]).index(1))
+        .about("Provides the way to import problem from various formats or convert pragmatic solution into csv")
+        .arg(
+            Arg::new(FORMAT_ARG_NAME)
+                .help("Specifies input type: csv problem or pragmatic solution which is converted into csv tables")
+                .required(true)
+                .value_parser(["csv", "solution"])
+                .index(1),
+        )
         .arg(
             Arg::new(INPUT_ARG_NAME)
                 .help("Sets input files which contains a VRP definition")
@@ -24,9 +34,15 @@ pub fn get_import_app() -> Command {
                 .required(true)
                 .num_args(1..),
         )
+        .arg(
+            Arg::new(CSV_COLUMNS_ARG_NAME)
+                .help("Specifies path to json file with csv column names mapping")
+                .long(CSV_COLUMNS_ARG_NAME)
+                .required(false),
+        )
         .arg(
             Arg::new(OUT_RESULT_ARG_NAME)
-                .help("Specifies path to file for result output")
+                .help("Specifies path to file for result output or to directory for csv tables of the solution")
                 .short('o')
                 .long(OUT_RESULT_ARG_NAME)
                 .required(false),
@@ -35,11 +51,25 @@ pub fn get_import_app() -> Command {
 
 pub fn run_import(matches: &ArgMatches) -> Result<(), GenericError> {
     let input_format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+
+    if input_format == "solution" {
+        return convert_solution_to_csv(matches);
+    }
     let input_files = matches
         .get_many::<String>(INPUT_ARG_NAME)
         .map(|paths| paths.map(|path| BufReader::new(open_file(path, "input"))).collect::<Vec<_>>());
 
-    match import_problem(input_format, input_files) {
+    let problem = match matches.get_one::<String>(CSV_COLUMNS_ARG_NAME) {
+        Some(path) if input_format == "csv" => {
+            let columns: CsvColumns = serde_json::from_reader(BufReader::new(open_file(path, "csv columns")))
+                .map_err(|err| format!("cannot read csv columns: '{err}'"))?;
+            import_csv_problem(input_files, &columns)
+        }
+        Some(_) => Err(format!("csv columns are not supported by '{input_format}' format").into()),
+        None => import_problem(input_format, input_files),
+    };
+
+    match problem {
         Ok(problem) => {
             let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
             let mut out_buffer = create_write_buffer(out_result);
@@ -49,3 +79,19 @@ pub fn run_import(matches: &ArgMatches) -> Result<(), GenericError> {
         Err(err) => Err(format!("cannot import problem: '{err}'").into()),
     }
 }
+
+fn convert_solution_to_csv(matches: &ArgMatches) -> Result<(), GenericError> {
+    let solution_path = match matches.get_many::<String>(INPUT_ARG_NAME).map(|paths| paths.collect::<Vec<_>>()) {
+        Some(paths) if paths.len() == 1 => paths[0],
+        _ => return Err("solution conversion expects exactly one input file".into()),
+    };
+    let out_dir = matches
+        .get_one::<String>(OUT_RESULT_ARG_NAME)
+        .ok_or_else(|| GenericError::from("solution conversion requires output directory"))?;
+
+    let solution = deserialize_solution(BufReader::new(open_file(solution_path, "solution")))
+        .map_err(|err| format!("cannot read solution from '{solution_path}': '{err}'"))?;
+
+    write_csv_solution_to_dir(&solution, Path::new(out_dir))
+        .map_err(|err| format!("cannot convert solution: '{err}'").into())
+}
@@ -2,8 +2,11 @@ use clap::{Arg, ArgMatches, Command};
 
 pub mod analyze;
 pub mod check;
+pub mod diff;
 pub mod generate;
 pub mod import;
+pub mod report;
+pub mod serve;
 pub mod solve;
 
 use std::fs::File;
@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/report_test.rs"]
+mod report_test;
+
+use super::*;
+use vrp_cli::extensions::report::get_html_report;
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_report_app() -> Command {
+    Command::new("report")
+        .about("Provides the way to render a standalone html report with map and timeline for the solution")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets problem file").short('p').long(PROBLEM_ARG_NAME).required(true))
+        .arg(Arg::new(SOLUTION_ARG_NAME).help("Sets solution file").short('s').long(SOLUTION_ARG_NAME).required(true))
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for html report output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_report(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let problem_path = matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+    let solution_path = matches.get_one::<String>(SOLUTION_ARG_NAME).unwrap();
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out report"));
+
+    let problem = deserialize_problem(BufReader::new(open_file(problem_path, "problem")))
+        .map_err(|errs| format!("cannot read problem from '{problem_path}': '{errs}'"))?;
+    let solution = deserialize_solution(BufReader::new(open_file(solution_path, "solution")))
+        .map_err(|err| format!("cannot read solution from '{solution_path}': '{err}'"))?;
+
+    let report = get_html_report(&problem, &solution)?;
+
+    let mut writer = out_writer_func(out_result);
+    writer
+        .write_all(report.as_bytes())
+        .and_then(|_| writer.flush())
+        .map_err(|err| format!("cannot write report: '{err}'").into())
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/serve_test.rs"]
+mod serve_test;
+
+use super::*;
+use vrp_cli::extensions::serve::server::{ServerConfig, SolverServer};
+use vrp_core::prelude::GenericError;
+
+const ADDRESS_ARG_NAME: &str = "address";
+const WORKERS_ARG_NAME: &str = "workers";
+const QUEUE_SIZE_ARG_NAME: &str = "queue-size";
+const MAX_FINISHED_ARG_NAME: &str = "max-finished";
+
+pub fn get_serve_app() -> Command {
+    Command::new("serve")
+        .about("Runs HTTP server which solves submitted problems in pragmatic format")
+        .arg(
+            Arg::new(ADDRESS_ARG_NAME)
+                .help("Specifies address to listen on")
+                .short('a')
+                .long(ADDRESS_ARG_NAME)
+                .default_value("127.0.0.1:8080")
+                .required(false),
+        )
+        .arg(
+            Arg::new(WORKERS_ARG_NAME)
+                .help("Specifies amount of jobs solved in parallel")
+                .short('w')
+                .long(WORKERS_ARG_NAME)
+                .default_value("1")
+                .required(false),
+        )
+        .arg(
+            Arg::new(QUEUE_SIZE_ARG_NAME)
+                .help("Specifies max amount of jobs waiting for a free worker")
+                .short('q')
+                .long(QUEUE_SIZE_ARG_NAME)
+                .default_value("16")
+                .required(false),
+        )
+        .arg(
+            Arg::new(MAX_FINISHED_ARG_NAME)
+                .help("Specifies max amount of finished jobs kept with their results")
+                .short('f')
+                .long(MAX_FINISHED_ARG_NAME)
+                .default_value("64")
+                .required(false),
+        )
+}
+
+pub fn run_serve(matches: &ArgMatches) -> Result<(), GenericError> {
+    let server = create_server(matches)?;
+
+    if let Some(address) = server.address() {
+        println!("listening on http://{address}");
+    }
+
+    server.run();
+
+    Ok(())
+}
+
+fn create_server(matches: &ArgMatches) -> Result<SolverServer, GenericError> {
+    let address = matches.get_one::<String>(ADDRESS_ARG_NAME).unwrap();
+    let workers = parse_int_value::<usize>(matches, WORKERS_ARG_NAME, "workers")?.unwrap();
+    let queue_size = parse_int_value::<usize>(matches, QUEUE_SIZE_ARG_NAME, "queue size")?.unwrap();
+    let max_finished = parse_int_value::<usize>(matches, MAX_FINISHED_ARG_NAME, "max finished")?.unwrap();
+
+    SolverServer::new(address, ServerConfig { workers, queue_size, max_finished })
+}
@@ -8,20 +8,22 @@ use clap::ArgAction;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use vrp_cli::core::solver::TargetHeuristic;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::export::write_csv_solution_to_dir;
+use vrp_cli::extensions::solve::config::{create_builder_from_config_file, with_granularity};
 use vrp_cli::get_locations_serialized;
 use vrp_cli::scientific::tsplib::{TsplibProblem, TsplibSolution};
 use vrp_core::construction::heuristics::InsertionContext;
-use vrp_core::models::GoalContext;
+use vrp_core::models::{GoalContext, Problem};
 use vrp_core::prelude::*;
 use vrp_core::rosomaxa::evolution::*;
 use vrp_core::rosomaxa::{get_default_population, get_default_selection_size};
 use vrp_core::solver::*;
 use vrp_core::utils::*;
-use vrp_pragmatic::format::solution::{write_pragmatic, PragmaticOutputType};
+use vrp_pragmatic::format::solution::{create_solution, deserialize_solution, write_pragmatic, PragmaticOutputType};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -30,9 +32,11 @@ const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const MIN_CV_ARG_NAME: &str = "min-cv";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const INIT_SIZE_ARG_NAME: &str = "init-size";
+const REFERENCE_SOLUTION_ARG_NAME: &str = "reference-solution";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
@@ -43,6 +47,7 @@ const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const GRANULARITY_ARG_NAME: &str = "granularity";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, GenericError>>);
@@ -119,14 +124,20 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
+fn add_pragmatic(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
 
+    let reference_solution = matches.get_one::<String>(REFERENCE_SOLUTION_ARG_NAME).cloned();
+
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+            ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                if let Some(reference_solution) = reference_solution.as_ref() {
+                    return read_pragmatic_with_reference(problem, matrices, reference_solution);
+                }
+
                 if let Some(matrices) = matrices {
                     let matrices = matrices.into_iter().map(BufReader::new).collect();
                     (BufReader::new(problem), matrices).read_pragmatic()
@@ -156,11 +167,32 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
     );
 }
 
+fn read_pragmatic_with_reference(
+    problem: File,
+    matrices: Option<Vec<File>>,
+    reference_solution: &str,
+) -> Result<Problem, GenericError> {
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, set_reference_solution};
+
+    let mut problem = deserialize_problem(BufReader::new(problem)).map_err(|errs| errs.to_string())?;
+    let matrices = matrices
+        .map(|matrices| matrices.into_iter().map(|matrix| deserialize_matrix(BufReader::new(matrix))).collect())
+        .transpose()
+        .map_err(|errs| errs.to_string())?;
+
+    let reference = deserialize_solution(BufReader::new(open_file(reference_solution, "reference solution")))
+        .map_err(|err| format!("cannot read reference solution: '{err}'"))?;
+    set_reference_solution(&mut problem, &reference)?;
+
+    (problem, matrices).read_pragmatic().map_err(|errs| errs.into())
+}
+
 fn get_formats<'a>(matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) -> FormatMap<'a> {
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats, matches, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, matches, random);
 
     formats
 }
@@ -206,6 +238,12 @@ pub fn get_solve_app() -> Command {
                 .long(INIT_SOLUTION_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(REFERENCE_SOLUTION_ARG_NAME)
+                .help("Specifies path to file with reference solution used by minimize-changes objective (pragmatic format only)")
+                .long(REFERENCE_SOLUTION_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(INIT_SIZE_ARG_NAME)
                 .help("Specifies amount of initial solutions. Min is 1")
@@ -241,6 +279,14 @@ pub fn get_solve_app() -> Command {
                 .long(GEO_JSON_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(OUT_FORMAT_ARG_NAME)
+                .help("Specifies solution output type. For csv, out result specifies a directory for csv tables (pragmatic format only)")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .value_parser(["json", "csv"])
+                .default_value("json")
+        )
         .arg(
             Arg::new(CONFIG_ARG_NAME)
                 .help("Specifies path to algorithm configuration file")
@@ -301,6 +347,12 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(GRANULARITY_ARG_NAME)
+                .help("Specifies amount of job's nearest neighbours considered when insertion is evaluated. Min is 1")
+                .long(GRANULARITY_ARG_NAME)
+                .required(false)
+        )
 }
 
 /// Runs solver commands.
@@ -318,12 +370,16 @@ pub fn run_solve(
     let init_solution = matches.get_one::<String>(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
     let config = matches.get_one::<String>(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
     let matrix_files = get_matrix_files(matches);
-    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
-    let out_geojson = matches.get_one::<String>(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
-
     let is_get_locations_set = matches.get_one::<bool>(GET_LOCATIONS_ARG_NAME).copied().unwrap_or(false);
     let is_check_requested = matches.get_one::<bool>(CHECK_ARG_NAME).copied().unwrap_or(false);
 
+    let out_csv = get_csv_output_dir(matches, problem_format, is_check_requested)?;
+    let out_result = matches
+        .get_one::<String>(OUT_RESULT_ARG_NAME)
+        .filter(|_| out_csv.is_none())
+        .map(|path| create_file(path, "out solution"));
+    let out_geojson = matches.get_one::<String>(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+
     match formats.get(problem_format.as_str()) {
         Some((
             ProblemReader(problem_reader),
@@ -340,7 +396,10 @@ pub fn run_solve(
             } else {
                 match problem_reader(problem_file, matrix_files) {
                     Ok(problem) => {
-                        let problem = Arc::new(problem);
+                        let problem = match get_granularity(matches)? {
+                            Some(neighbours) => with_granularity(Arc::new(problem), neighbours)?,
+                            None => Arc::new(problem),
+                        };
                         let init_solutions = init_solution
                             .map(|file| read_init_solution(problem.clone(), environment.clone(), file, init_reader))
                             .unwrap_or_else(|| Ok(Vec::default()))?;
@@ -353,7 +412,11 @@ pub fn run_solve(
 
                         let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
 
-                        solution_writer(&problem, solution, out_buffer, geo_buffer).unwrap();
+                        if let Some(out_csv) = out_csv {
+                            write_csv_solution(&problem, &solution, out_csv)?;
+                        } else {
+                            solution_writer(&problem, solution, out_buffer, geo_buffer).unwrap();
+                        }
 
                         if is_check_requested {
                             check_pragmatic_solution_with_args(matches)?;
@@ -372,6 +435,36 @@ pub fn run_solve(
     }
 }
 
+/// Returns a directory for solution output in csv format if it is requested.
+fn get_csv_output_dir<'a>(
+    matches: &'a ArgMatches,
+    problem_format: &str,
+    is_check_requested: bool,
+) -> Result<Option<&'a String>, GenericError> {
+    if matches.get_one::<String>(OUT_FORMAT_ARG_NAME).is_none_or(|format| format != "csv") {
+        return Ok(None);
+    }
+
+    if problem_format != "pragmatic" {
+        return Err(format!("csv output is not supported for '{problem_format}' format").into());
+    }
+
+    if is_check_requested {
+        return Err("feasibility check is not supported for csv output".into());
+    }
+
+    matches
+        .get_one::<String>(OUT_RESULT_ARG_NAME)
+        .map(Some)
+        .ok_or_else(|| "csv output requires a directory specified by out result".into())
+}
+
+fn write_csv_solution(problem: &Problem, solution: &Solution, out_dir: &str) -> Result<(), GenericError> {
+    let solution = create_solution(problem, solution, &PragmaticOutputType::OnlyPragmatic);
+
+    write_csv_solution_to_dir(&solution, Path::new(out_dir))
+}
+
 fn read_init_solution(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
@@ -454,6 +547,16 @@ fn get_min_cv(matches: &ArgMatches) -> Result<Option<(String, usize, f64, bool)>
         .unwrap_or(Ok(None))
 }
 
+fn get_granularity(matches: &ArgMatches) -> Result<Option<usize>, GenericError> {
+    matches
+        .get_one::<String>(GRANULARITY_ARG_NAME)
+        .map(|value| match value.parse::<usize>() {
+            Ok(neighbours) if neighbours > 0 => Ok(Some(neighbours)),
+            _ => Err(format!("granularity must be an integer bigger than 0, got '{value}'").into()),
+        })
+        .unwrap_or(Ok(None))
+}
+
 fn get_init_size(matches: &ArgMatches) -> Result<Option<usize>, GenericError> {
     matches
         .get_one::<String>(INIT_SIZE_ARG_NAME)
@@ -0,0 +1,303 @@
+//! Detects infeasible or suspicious problem inputs before solving.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/feasibility_test.rs"]
+mod feasibility_test;
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::problem::Job as CoreJob;
+use vrp_core::prelude::GenericError;
+use vrp_core::utils::Environment;
+use vrp_pragmatic::construction::enablers::JobTie;
+use vrp_pragmatic::format::problem::{Job, JobTask, Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::map_code_reason;
+
+/// Specifies feasibility of a single job.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobFeasibility {
+    /// A job id.
+    pub job_id: String,
+    /// An amount of vehicle groups (vehicles of the same type and shift) which can serve the job
+    /// in a separate tour.
+    pub vehicles: usize,
+    /// Reason codes of violated constraints when the job cannot be served by any vehicle.
+    pub reasons: Vec<String>,
+}
+
+/// Specifies a lower bound on amount of vehicles required to serve all feasible jobs.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleBound {
+    /// An amount of available vehicle shifts.
+    pub available: usize,
+    /// A lower bound on required vehicle shifts: the maximum of other bounds.
+    pub required: usize,
+    /// A bound based on total demand and the biggest vehicle capacity. It is not defined when
+    /// vehicles have reloads.
+    pub capacity: Option<usize>,
+    /// A bound based on total service duration and the longest shift. It is not defined when
+    /// some shift has no end time.
+    pub duration: Option<usize>,
+}
+
+/// Specifies problem feasibility analysis result.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeasibilityAnalysis {
+    /// Feasibility per job.
+    pub jobs: Vec<JobFeasibility>,
+    /// An amount of routing matrix entries marked with error codes.
+    pub unreachable_entries: usize,
+    /// A lower bound on required vehicles.
+    pub vehicles: VehicleBound,
+}
+
+impl FeasibilityAnalysis {
+    /// Returns jobs which cannot be served by any vehicle.
+    pub fn infeasible_jobs(&self) -> impl Iterator<Item = &JobFeasibility> + '_ {
+        self.jobs.iter().filter(|job| job.vehicles == 0)
+    }
+}
+
+/// Analyzes problem feasibility: checks whether each job can be served by some vehicle in a
+/// separate tour (time windows within shift hours, capacity, skills, reachability and other hard
+/// constraints) and estimates a lower bound on amount of vehicles required to serve feasible jobs.
+pub fn get_feasibility_analysis(
+    problem: &Problem,
+    matrices: Option<Vec<Matrix>>,
+) -> Result<FeasibilityAnalysis, GenericError> {
+    let unreachable_entries = matrices
+        .iter()
+        .flatten()
+        .filter_map(|matrix| matrix.error_codes.as_ref())
+        .flat_map(|error_codes| error_codes.iter())
+        .filter(|error| **error > 0)
+        .count();
+
+    let core_problem = Arc::new((problem.clone(), matrices).read_pragmatic().map_err(|errs| errs.to_string())?);
+    let insertion_ctx = InsertionContext::new_empty(core_problem.clone(), Arc::new(Environment::default()));
+
+    // NOTE analyze only jobs from the plan (e.g. skip breaks and reloads) keeping their original order
+    let mut jobs = core_problem
+        .jobs
+        .all()
+        .filter_map(|job| {
+            let position =
+                problem.plan.jobs.iter().position(|plan_job| Some(&plan_job.id) == job.dimens().get_job_id());
+            position.map(|position| (position, get_job_feasibility(&insertion_ctx, &job)))
+        })
+        .collect::<Vec<_>>();
+    jobs.sort_by_key(|(position, _)| *position);
+    let jobs = jobs.into_iter().map(|(_, job)| job).collect::<Vec<_>>();
+
+    let feasible = jobs.iter().filter(|job| job.vehicles > 0).map(|job| job.job_id.as_str()).collect::<HashSet<_>>();
+    let feasible = problem.plan.jobs.iter().filter(|job| feasible.contains(job.id.as_str())).collect::<Vec<_>>();
+
+    let capacity = get_capacity_bound(problem, feasible.as_slice());
+    let duration = get_duration_bound(problem, feasible.as_slice())?;
+    let required = capacity.into_iter().chain(duration).chain((!feasible.is_empty()).then_some(1)).max().unwrap_or(0);
+
+    Ok(FeasibilityAnalysis {
+        jobs,
+        unreachable_entries,
+        vehicles: VehicleBound {
+            available: insertion_ctx.solution.registry.resources().all().count(),
+            required,
+            capacity,
+            duration,
+        },
+    })
+}
+
+fn get_job_feasibility(insertion_ctx: &InsertionContext, job: &CoreJob) -> JobFeasibility {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    let (vehicles, codes) = insertion_ctx.solution.registry.next_route().fold(
+        (0, Vec::<i32>::default()),
+        |(vehicles, mut codes), route_ctx| match eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            route_ctx,
+            InsertionPosition::Any,
+            InsertionResult::make_failure(),
+        ) {
+            InsertionResult::Success(_) => (vehicles + 1, codes),
+            InsertionResult::Failure(failure) => {
+                if !codes.contains(&failure.constraint) {
+                    codes.push(failure.constraint);
+                }
+                (vehicles, codes)
+            }
+        },
+    );
+
+    let reasons = if vehicles == 0 {
+        let mut visited = HashSet::new();
+        codes
+            .into_iter()
+            .map(|code| map_code_reason(code).0)
+            .filter(|reason| visited.insert(*reason))
+            .map(String::from)
+            .collect()
+    } else {
+        Vec::default()
+    };
+
+    JobFeasibility { job_id: job.dimens().get_job_id().cloned().unwrap_or_default(), vehicles, reasons }
+}
+
+fn get_capacity_bound(problem: &Problem, jobs: &[&Job]) -> Option<usize> {
+    let has_reloads = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .any(|shift| shift.reloads.as_ref().is_some_and(|reloads| !reloads.is_empty()));
+
+    if has_reloads || jobs.is_empty() {
+        return None;
+    }
+
+    let sum_demand = |tasks: &Option<Vec<_>>, dimension: usize| {
+        tasks
+            .iter()
+            .flatten()
+            .filter_map(|task: &JobTask| task.demand.as_ref())
+            .map(|demand| demand.get(dimension).copied().unwrap_or(0).max(0) as i64)
+            .sum::<i64>()
+    };
+
+    let dimensions = problem.fleet.vehicles.iter().map(|vehicle| vehicle.capacity.len()).max().unwrap_or(0);
+
+    (0..dimensions)
+        .map(|dimension| {
+            // NOTE static delivery is loaded at the tour start and static pickup is kept till its end, so each of
+            //      them cannot exceed vehicle capacity within one tour. Demand of jobs with both pickups and deliveries
+            //      is dynamic: such jobs can be served one after another within the same tour, so it is ignored.
+            let (pickup, delivery) = jobs.iter().fold((0, 0), |(pickup, delivery), job| {
+                let replacement = sum_demand(&job.replacements, dimension);
+                let is_static_demand = job.pickups.as_ref().is_none_or(|tasks| tasks.is_empty())
+                    || job.deliveries.as_ref().is_none_or(|tasks| tasks.is_empty());
+
+                if is_static_demand {
+                    (
+                        pickup + sum_demand(&job.pickups, dimension) + replacement,
+                        delivery + sum_demand(&job.deliveries, dimension) + replacement,
+                    )
+                } else {
+                    (pickup + replacement, delivery + replacement)
+                }
+            });
+            let demand = pickup.max(delivery);
+
+            let capacity = problem
+                .fleet
+                .vehicles
+                .iter()
+                .map(|vehicle| vehicle.capacity.get(dimension).copied().unwrap_or(0) as i64)
+                .max()
+                .unwrap_or(0);
+
+            // NOTE feasible jobs cannot have demand in dimension without capacity
+            if demand == 0 || capacity <= 0 {
+                0
+            } else {
+                ((demand + capacity - 1) / capacity) as usize
+            }
+        })
+        .max()
+}
+
+fn get_duration_bound(problem: &Problem, jobs: &[&Job]) -> Result<Option<usize>, GenericError> {
+    let parse_time = |time: &str| -> Result<i64, GenericError> {
+        OffsetDateTime::parse(time, &Rfc3339)
+            .map(|time| time.unix_timestamp())
+            .map_err(|err| format!("cannot parse time '{time}': {err}").into())
+    };
+
+    let shift_durations = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| {
+            shift
+                .end
+                .as_ref()
+                .map(|end| Ok(parse_time(end.latest.as_str())? - parse_time(shift.start.earliest.as_str())?))
+                .transpose()
+        })
+        .collect::<Result<Vec<_>, GenericError>>()?;
+
+    // NOTE shift without end has unlimited duration
+    let Some(max_shift_duration) =
+        shift_durations.into_iter().collect::<Option<Vec<_>>>().and_then(|durations| durations.into_iter().max())
+    else {
+        return Ok(None);
+    };
+
+    let service_duration = jobs
+        .iter()
+        .flat_map(|job| {
+            [&job.pickups, &job.deliveries, &job.replacements, &job.services].into_iter().flatten().flatten()
+        })
+        .map(|task| task.places.iter().map(|place| place.duration).fold(f64::MAX, f64::min))
+        .filter(|duration| *duration < f64::MAX)
+        .sum::<f64>();
+
+    if max_shift_duration <= 0 {
+        return Ok(None);
+    }
+
+    Ok(Some((service_duration / max_shift_duration as f64).ceil() as usize))
+}
+
+impl Display for FeasibilityAnalysis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let infeasible = self.infeasible_jobs().collect::<Vec<_>>();
+
+        writeln!(f, "jobs: {}, infeasible: {}", self.jobs.len(), infeasible.len())?;
+        for job in infeasible {
+            writeln!(f, "  {}: {}", job.job_id, job.reasons.join(", "))?;
+        }
+
+        let single = self.jobs.iter().filter(|job| job.vehicles == 1).count();
+        if single > 0 && self.vehicles.available > 1 {
+            writeln!(f, "jobs which can be served by one vehicle group only: {single}")?;
+        }
+
+        if self.unreachable_entries > 0 {
+            writeln!(f, "unreachable routing matrix entries: {}", self.unreachable_entries)?;
+        }
+
+        let format_bound = |bound: Option<usize>| bound.map_or("n/a".to_string(), |bound| bound.to_string());
+        writeln!(
+            f,
+            "vehicles: available {}, required at least {} (capacity bound: {}, duration bound: {})",
+            self.vehicles.available,
+            self.vehicles.required,
+            format_bound(self.vehicles.capacity),
+            format_bound(self.vehicles.duration)
+        )?;
+
+        if self.vehicles.required > self.vehicles.available {
+            writeln!(f, "warning: not enough vehicles to serve all feasible jobs")?;
+        }
+
+        Ok(())
+    }
+}
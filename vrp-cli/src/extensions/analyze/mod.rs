@@ -2,3 +2,9 @@
 
 mod clusters;
 pub use self::clusters::get_clusters;
+
+mod feasibility;
+pub use self::feasibility::*;
+
+mod unassigned;
+pub use self::unassigned::*;
@@ -0,0 +1,384 @@
+//! Explains unassigned jobs by trying to relax problem's restrictions for each of them.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/unassigned_test.rs"]
+mod unassigned_test;
+
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::io::BufReader;
+use std::sync::Arc;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::Cost;
+use vrp_core::prelude::GenericError;
+use vrp_core::utils::Environment;
+use vrp_pragmatic::construction::enablers::JobTie;
+use vrp_pragmatic::format::problem::{Job, JobTask, Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::{read_init_solution, Solution};
+
+/// Specifies options of unassigned jobs analysis.
+#[derive(Clone, Debug)]
+pub struct RelaxationOptions {
+    /// A step (in seconds) used to widen job's time windows.
+    pub time_step: f64,
+    /// A maximum amount of steps used for time windows widening and capacity increase.
+    pub max_steps: usize,
+}
+
+impl Default for RelaxationOptions {
+    fn default() -> Self {
+        Self { time_step: 900., max_steps: 8 }
+    }
+}
+
+/// Specifies a relaxation of problem's restrictions.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum Relaxation {
+    /// No relaxation is needed: the job can be inserted into the solution as is.
+    None,
+    /// Job's time windows are widened by the given amount of seconds on both sides.
+    TimeWindow {
+        /// An amount of seconds.
+        seconds: f64,
+    },
+    /// Capacity of all vehicles is increased by the given amount.
+    Capacity {
+        /// An extra capacity per dimension.
+        extra: Vec<i32>,
+    },
+    /// Job's skills requirement is ignored.
+    Skills,
+    /// One more vehicle of the given type is added.
+    #[serde(rename_all = "camelCase")]
+    Vehicle {
+        /// A vehicle type id.
+        type_id: String,
+    },
+}
+
+impl Display for Relaxation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Relaxation::None => write!(f, "no relaxation"),
+            Relaxation::TimeWindow { seconds } => write!(f, "widen time windows by {seconds}s"),
+            Relaxation::Capacity { extra } => write!(
+                f,
+                "add capacity [{}]",
+                extra.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(", ")
+            ),
+            Relaxation::Skills => write!(f, "ignore skills"),
+            Relaxation::Vehicle { type_id } => write!(f, "add vehicle of '{type_id}' type"),
+        }
+    }
+}
+
+/// Specifies a relaxation which makes the job insertable.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RelaxationResult {
+    /// A minimal relaxation of its kind.
+    #[serde(flatten)]
+    pub relaxation: Relaxation,
+    /// A solution cost increase after job insertion.
+    pub cost: Cost,
+}
+
+/// Specifies analysis result of a single unassigned job.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnassignedJobAnalysis {
+    /// A job id.
+    pub job_id: String,
+    /// Unassignment reason codes reported in the solution.
+    pub reasons: Vec<String>,
+    /// Relaxations which make the job insertable, ordered by cost. Empty if none of them helps.
+    pub relaxations: Vec<RelaxationResult>,
+}
+
+/// Specifies analysis result of all unassigned jobs.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct UnassignedAnalysis {
+    /// Analysis results per unassigned job.
+    pub jobs: Vec<UnassignedJobAnalysis>,
+}
+
+/// Analyzes unassigned jobs of the solution: for each job, it tries to relax problem's restrictions
+/// one by one (widen time windows, add capacity, ignore skills, add a vehicle) and finds a minimal
+/// relaxation of each kind which allows to insert the job into the solution.
+pub fn get_unassigned_analysis(
+    problem: &Problem,
+    solution: &Solution,
+    matrices: Option<Vec<Matrix>>,
+    options: &RelaxationOptions,
+) -> Result<UnassignedAnalysis, GenericError> {
+    let reasons = solution
+        .unassigned
+        .iter()
+        .flatten()
+        .map(|job| (job.job_id.clone(), job.reasons.iter().map(|reason| reason.code.clone()).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    let solution = serde_json::to_vec(solution).map_err(|err| format!("cannot serialize solution: '{err}'"))?;
+    let evaluator = JobEvaluator { solution: solution.as_slice(), matrices };
+
+    let jobs = reasons
+        .into_iter()
+        // NOTE skip unassigned breaks and other entities which are not jobs
+        .filter_map(|(job_id, reasons)| problem.plan.jobs.iter().find(|job| job.id == job_id).map(|job| (job, reasons)))
+        .map(|(job, reasons)| {
+            get_relaxations(problem, job, &evaluator, options).map(|relaxations| UnassignedJobAnalysis {
+                job_id: job.id.clone(),
+                reasons,
+                relaxations,
+            })
+        })
+        .collect::<Result<Vec<_>, GenericError>>()?;
+
+    Ok(UnassignedAnalysis { jobs })
+}
+
+fn get_relaxations(
+    problem: &Problem,
+    job: &Job,
+    evaluator: &JobEvaluator,
+    options: &RelaxationOptions,
+) -> Result<Vec<RelaxationResult>, GenericError> {
+    if let Some(cost) = evaluator.evaluate(problem.clone(), job.id.as_str())? {
+        return Ok(vec![RelaxationResult { relaxation: Relaxation::None, cost }]);
+    }
+
+    let mut results = Vec::default();
+
+    if has_time_windows(job) {
+        let levels = (1..=options.max_steps).map(|step| step as f64 * options.time_step).collect::<Vec<_>>();
+        let result = find_minimal_level(levels.as_slice(), |seconds| {
+            let mut problem = problem.clone();
+            widen_time_windows(&mut problem, job.id.as_str(), *seconds)?;
+            evaluator.evaluate(problem, job.id.as_str())
+        })?;
+
+        results.extend(result.map(|(seconds, cost)| RelaxationResult {
+            relaxation: Relaxation::TimeWindow { seconds: *seconds },
+            cost,
+        }));
+    }
+
+    let demand = get_max_demand(job);
+    if demand.iter().any(|value| *value > 0) {
+        let levels = (1..=options.max_steps)
+            .map(|step| {
+                demand
+                    .iter()
+                    .map(|value| (*value as f64 * step as f64 / options.max_steps as f64).ceil() as i32)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let result = find_minimal_level(levels.as_slice(), |extra| {
+            let mut problem = problem.clone();
+            add_capacity(&mut problem, extra.as_slice());
+            evaluator.evaluate(problem, job.id.as_str())
+        })?;
+
+        results.extend(
+            result.map(|(extra, cost)| RelaxationResult {
+                relaxation: Relaxation::Capacity { extra: extra.clone() },
+                cost,
+            }),
+        );
+    }
+
+    if job.skills.is_some() {
+        let mut problem = problem.clone();
+        problem.plan.jobs.iter_mut().filter(|other| other.id == job.id).for_each(|job| job.skills = None);
+
+        results.extend(
+            evaluator
+                .evaluate(problem, job.id.as_str())?
+                .map(|cost| RelaxationResult { relaxation: Relaxation::Skills, cost }),
+        );
+    }
+
+    let vehicle = problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            let mut problem = problem.clone();
+            add_vehicle(&mut problem, vehicle.type_id.as_str());
+            evaluator.evaluate(problem, job.id.as_str()).map(|cost| cost.map(|cost| (vehicle.type_id.clone(), cost)))
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    results
+        .extend(vehicle.map(|(type_id, cost)| RelaxationResult { relaxation: Relaxation::Vehicle { type_id }, cost }));
+
+    results.sort_by(|a, b| a.cost.partial_cmp(&b.cost).unwrap_or(Ordering::Equal));
+
+    Ok(results)
+}
+
+/// Finds the first level which makes job insertable using binary search: it assumes that
+/// a bigger relaxation keeps the job insertable.
+fn find_minimal_level<T, F>(levels: &[T], evaluate: F) -> Result<Option<(&T, Cost)>, GenericError>
+where
+    F: Fn(&T) -> Result<Option<Cost>, GenericError>,
+{
+    let Some(last) = levels.last() else { return Ok(None) };
+    let Some(mut best) = evaluate(last)?.map(|cost| (last, cost)) else { return Ok(None) };
+
+    let (mut left, mut right) = (0, levels.len() - 1);
+    while left < right {
+        let middle = left + (right - left) / 2;
+        match evaluate(&levels[middle])? {
+            Some(cost) => {
+                best = (&levels[middle], cost);
+                right = middle;
+            }
+            None => left = middle + 1,
+        }
+    }
+
+    Ok(Some(best))
+}
+
+/// Evaluates job insertion into the solution for the given (relaxed) problem.
+struct JobEvaluator<'a> {
+    solution: &'a [u8],
+    matrices: Option<Vec<Matrix>>,
+}
+
+impl JobEvaluator<'_> {
+    /// Returns a solution cost increase if the job can be inserted.
+    fn evaluate(&self, problem: Problem, job_id: &str) -> Result<Option<Cost>, GenericError> {
+        let problem = Arc::new((problem, self.matrices.clone()).read_pragmatic().map_err(|errs| errs.to_string())?);
+        let environment = Arc::new(Environment::default());
+
+        let solution = read_init_solution(BufReader::new(self.solution), problem.clone(), environment.random.clone())?;
+        let mut insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+        // NOTE keep only the analyzed job for insertion, other unassigned jobs are ignored
+        let job = insertion_ctx
+            .solution
+            .required
+            .iter()
+            .chain(insertion_ctx.solution.unassigned.keys())
+            .find(|job| job.dimens().get_job_id().is_some_and(|id| id == job_id))
+            .cloned()
+            .ok_or_else(|| format!("cannot find unassigned job '{job_id}' in the solution"))?;
+
+        insertion_ctx.solution.required = vec![job.clone()];
+        insertion_ctx.solution.unassigned.clear();
+        let cost_before = insertion_ctx.get_total_cost().unwrap_or_default();
+
+        let insertion_ctx = InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &LegSelection::Exhaustive,
+            &BestResultSelector::default(),
+        );
+
+        let is_assigned = insertion_ctx.solution.routes.iter().any(|route_ctx| route_ctx.route().tour.contains(&job));
+
+        Ok(if is_assigned { Some(insertion_ctx.get_total_cost().unwrap_or_default() - cost_before) } else { None })
+    }
+}
+
+fn get_tasks_mut(job: &mut Job) -> impl Iterator<Item = &mut JobTask> {
+    [job.pickups.as_mut(), job.deliveries.as_mut(), job.replacements.as_mut(), job.services.as_mut()]
+        .into_iter()
+        .flatten()
+        .flat_map(|tasks| tasks.iter_mut())
+}
+
+fn get_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
+    [job.pickups.as_ref(), job.deliveries.as_ref(), job.replacements.as_ref(), job.services.as_ref()]
+        .into_iter()
+        .flatten()
+        .flat_map(|tasks| tasks.iter())
+}
+
+fn has_time_windows(job: &Job) -> bool {
+    get_tasks(job).flat_map(|task| task.places.iter()).any(|place| place.times.is_some())
+}
+
+fn get_max_demand(job: &Job) -> Vec<i32> {
+    get_tasks(job).filter_map(|task| task.demand.as_ref()).fold(Vec::default(), |mut acc, demand| {
+        acc.resize(acc.len().max(demand.len()), 0);
+        acc.iter_mut().zip(demand.iter()).for_each(|(acc, value)| *acc = (*acc).max(*value));
+        acc
+    })
+}
+
+fn widen_time_windows(problem: &mut Problem, job_id: &str, seconds: f64) -> Result<(), GenericError> {
+    let shift = |time: &str, seconds: f64| -> Result<String, GenericError> {
+        let time = OffsetDateTime::parse(time, &Rfc3339).map_err(|err| format!("cannot parse time '{time}': {err}"))?;
+        (time + time::Duration::seconds_f64(seconds))
+            .format(&Rfc3339)
+            .map_err(|err| format!("cannot format time: {err}").into())
+    };
+
+    problem
+        .plan
+        .jobs
+        .iter_mut()
+        .filter(|job| job.id == job_id)
+        .flat_map(get_tasks_mut)
+        .flat_map(|task| task.places.iter_mut())
+        .filter_map(|place| place.times.as_mut())
+        .flat_map(|times| times.iter_mut())
+        .try_for_each(|tw| match tw.as_mut_slice() {
+            [start, end] => {
+                *start = shift(start, -seconds)?;
+                *end = shift(end, seconds)?;
+                Ok(())
+            }
+            _ => Err(format!("invalid time window in job '{job_id}'").into()),
+        })
+}
+
+fn add_capacity(problem: &mut Problem, extra: &[i32]) {
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        vehicle.capacity.resize(vehicle.capacity.len().max(extra.len()), 0);
+        vehicle.capacity.iter_mut().zip(extra.iter()).for_each(|(capacity, extra)| *capacity += *extra);
+    });
+}
+
+fn add_vehicle(problem: &mut Problem, type_id: &str) {
+    let vehicle_ids =
+        problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.vehicle_ids.iter().cloned()).collect::<Vec<_>>();
+    let vehicle_id = (1..)
+        .map(|idx| format!("{type_id}_extra_{idx}"))
+        .find(|vehicle_id| !vehicle_ids.contains(vehicle_id))
+        .expect("cannot generate vehicle id");
+
+    if let Some(vehicle) = problem.fleet.vehicles.iter_mut().find(|vehicle| vehicle.type_id == type_id) {
+        vehicle.vehicle_ids.push(vehicle_id);
+    }
+}
+
+impl Display for UnassignedAnalysis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "unassigned jobs: {}", self.jobs.len())?;
+
+        for job in self.jobs.iter() {
+            writeln!(f, "  {} ({}):", job.job_id, job.reasons.join(", "))?;
+
+            if job.relaxations.is_empty() {
+                writeln!(f, "    no relaxation found")?;
+            }
+
+            for result in job.relaxations.iter() {
+                writeln!(f, "    {}: cost {:+.2}", result.relaxation, result.cost)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+//! Diff command helpers
+
+mod problem;
+pub use self::problem::*;
+
+mod solution;
+pub use self::solution::*;
@@ -0,0 +1,79 @@
+//! Compares two problems in pragmatic format.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/diff/problem_test.rs"]
+mod problem_test;
+
+use serde::Serialize;
+use std::collections::HashMap;
+use vrp_pragmatic::format::problem::Problem;
+
+/// Specifies a difference between two lists of entities identified by their ids.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct EntityDiff {
+    /// Ids of entities which exist only in the second problem.
+    pub added: Vec<String>,
+    /// Ids of entities which exist only in the first problem.
+    pub removed: Vec<String>,
+    /// Ids of entities which exist in both problems, but have different definitions.
+    pub changed: Vec<String>,
+}
+
+impl EntityDiff {
+    /// Returns true if there is no difference.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Specifies a difference between two problems.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProblemDiff {
+    /// A difference in jobs.
+    pub jobs: EntityDiff,
+    /// A difference in vehicle types.
+    pub vehicles: EntityDiff,
+}
+
+/// Compares two problems: jobs are matched by their ids, vehicle types by type ids.
+pub fn get_problem_diff(before: &Problem, after: &Problem) -> ProblemDiff {
+    ProblemDiff {
+        jobs: get_entity_diff(
+            before.plan.jobs.iter().map(|job| (job.id.as_str(), job)),
+            after.plan.jobs.iter().map(|job| (job.id.as_str(), job)),
+        ),
+        vehicles: get_entity_diff(
+            before.fleet.vehicles.iter().map(|vehicle| (vehicle.type_id.as_str(), vehicle)),
+            after.fleet.vehicles.iter().map(|vehicle| (vehicle.type_id.as_str(), vehicle)),
+        ),
+    }
+}
+
+fn get_entity_diff<'a, T: Serialize + 'a>(
+    before: impl Iterator<Item = (&'a str, &'a T)>,
+    after: impl Iterator<Item = (&'a str, &'a T)>,
+) -> EntityDiff {
+    // NOTE models do not implement PartialEq, so compare their json representations
+    let to_values = |entities: Vec<(&'a str, &'a T)>| {
+        let order = entities.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        let values = entities
+            .into_iter()
+            .map(|(id, entity)| (id, serde_json::to_value(entity).unwrap_or_default()))
+            .collect::<HashMap<_, _>>();
+
+        (order, values)
+    };
+
+    let (before_order, before) = to_values(before.collect());
+    let (after_order, after) = to_values(after.collect());
+
+    EntityDiff {
+        added: after_order.iter().filter(|id| !before.contains_key(*id)).map(|id| id.to_string()).collect(),
+        removed: before_order.iter().filter(|id| !after.contains_key(*id)).map(|id| id.to_string()).collect(),
+        changed: after_order
+            .iter()
+            .filter(|id| before.get(*id).zip(after.get(*id)).is_some_and(|(lhs, rhs)| lhs != rhs))
+            .map(|id| id.to_string())
+            .collect(),
+    }
+}
@@ -0,0 +1,300 @@
+//! Compares two solutions in pragmatic format.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/diff/solution_test.rs"]
+mod solution_test;
+
+use super::ProblemDiff;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use vrp_pragmatic::format::solution::{Solution, Tour};
+
+/// Activity types which are not related to any job from the plan.
+const NON_JOB_ACTIVITY_TYPES: &[&str] = &["departure", "arrival", "break", "reload", "recharge"];
+
+/// Identifies a tour within solution.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TourKey {
+    /// A vehicle id.
+    pub vehicle_id: String,
+    /// A vehicle's shift index.
+    pub shift_index: usize,
+}
+
+impl Display for TourKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.vehicle_id, self.shift_index)
+    }
+}
+
+/// Specifies a change of numeric value.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ValueDiff {
+    /// A value in the first solution.
+    pub before: f64,
+    /// A value in the second solution.
+    pub after: f64,
+    /// A difference between the second and the first value.
+    pub delta: f64,
+}
+
+impl ValueDiff {
+    fn new(before: f64, after: f64) -> Self {
+        Self { before, after, delta: after - before }
+    }
+}
+
+/// Specifies a kind of tour change.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TourChange {
+    /// Tour exists only in the second solution.
+    Added,
+    /// Tour exists only in the first solution.
+    Removed,
+    /// Tour serves different jobs or serves them in different order.
+    Changed,
+    /// Tour serves the same jobs in the same order, its cost still might be different.
+    Unchanged,
+}
+
+/// Specifies a difference between two tours of the same vehicle shift.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TourDiff {
+    /// A tour identifier.
+    #[serde(flatten)]
+    pub key: TourKey,
+    /// A kind of change.
+    pub change: TourChange,
+    /// A tour cost change, missing tour has zero cost.
+    pub cost: ValueDiff,
+    /// A tour distance change, missing tour has zero distance.
+    pub distance: ValueDiff,
+    /// Jobs served only by the tour in the second solution.
+    pub added_jobs: Vec<String>,
+    /// Jobs served only by the tour in the first solution.
+    pub removed_jobs: Vec<String>,
+    /// True if jobs served by both tours are visited in different order.
+    pub order_changed: bool,
+}
+
+/// Specifies a job which is assigned to a different tour in the second solution.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MovedJob {
+    /// A job id.
+    pub job_id: String,
+    /// A tour in the first solution, none if the job is not assigned there.
+    pub before: Option<TourKey>,
+    /// A tour in the second solution, none if the job is not assigned there.
+    pub after: Option<TourKey>,
+}
+
+/// Specifies a difference between two solutions.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// A total cost change.
+    pub cost: ValueDiff,
+    /// A total distance change.
+    pub distance: ValueDiff,
+    /// A difference per tour.
+    pub tours: Vec<TourDiff>,
+    /// Jobs assigned to a different tour.
+    pub moved_jobs: Vec<MovedJob>,
+    /// A difference between problems, if they are specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub problem: Option<ProblemDiff>,
+}
+
+/// Compares two solutions: tours are matched by vehicle id and shift index, jobs by their ids.
+pub fn get_solution_diff(before: &Solution, after: &Solution, problem: Option<ProblemDiff>) -> SolutionDiff {
+    let before_tours = before.tours.iter().map(|tour| (get_tour_key(tour), tour)).collect::<HashMap<_, _>>();
+    let after_tours = after.tours.iter().map(|tour| (get_tour_key(tour), tour)).collect::<HashMap<_, _>>();
+
+    // NOTE keep order of tours from the first solution and append new tours from the second one
+    let keys = before
+        .tours
+        .iter()
+        .map(get_tour_key)
+        .chain(after.tours.iter().map(get_tour_key).filter(|key| !before_tours.contains_key(key)))
+        .collect::<Vec<_>>();
+
+    let tours = keys
+        .into_iter()
+        .map(|key| {
+            let before = before_tours.get(&key).copied();
+            let after = after_tours.get(&key).copied();
+            get_tour_diff(key, before, after)
+        })
+        .collect();
+
+    SolutionDiff {
+        cost: ValueDiff::new(before.statistic.cost, after.statistic.cost),
+        distance: ValueDiff::new(before.statistic.distance as f64, after.statistic.distance as f64),
+        tours,
+        moved_jobs: get_moved_jobs(before, after),
+        problem,
+    }
+}
+
+fn get_tour_diff(key: TourKey, before: Option<&Tour>, after: Option<&Tour>) -> TourDiff {
+    let before_jobs = before.map(get_job_sequence).unwrap_or_default();
+    let after_jobs = after.map(get_job_sequence).unwrap_or_default();
+
+    let before_set = before_jobs.iter().copied().collect::<HashSet<_>>();
+    let after_set = after_jobs.iter().copied().collect::<HashSet<_>>();
+
+    let get_unique = |jobs: &[&str], other: &HashSet<&str>| {
+        let mut visited = HashSet::new();
+        jobs.iter().filter(|job| !other.contains(*job) && visited.insert(**job)).map(|job| job.to_string()).collect()
+    };
+    let added_jobs: Vec<String> = get_unique(after_jobs.as_slice(), &before_set);
+    let removed_jobs: Vec<String> = get_unique(before_jobs.as_slice(), &after_set);
+
+    // NOTE compare relative order of jobs which are served by both tours
+    let order_changed = before_jobs
+        .iter()
+        .filter(|job| after_set.contains(*job))
+        .ne(after_jobs.iter().filter(|job| before_set.contains(*job)));
+
+    let change = match (before, after) {
+        (None, _) => TourChange::Added,
+        (_, None) => TourChange::Removed,
+        _ if !added_jobs.is_empty() || !removed_jobs.is_empty() || order_changed => TourChange::Changed,
+        _ => TourChange::Unchanged,
+    };
+
+    let get_cost = |tour: Option<&Tour>| tour.map_or(0., |tour| tour.statistic.cost);
+    let get_distance = |tour: Option<&Tour>| tour.map_or(0., |tour| tour.statistic.distance as f64);
+
+    TourDiff {
+        key,
+        change,
+        cost: ValueDiff::new(get_cost(before), get_cost(after)),
+        distance: ValueDiff::new(get_distance(before), get_distance(after)),
+        added_jobs,
+        removed_jobs,
+        order_changed,
+    }
+}
+
+fn get_moved_jobs(before: &Solution, after: &Solution) -> Vec<MovedJob> {
+    let before_assignments = get_job_assignments(before);
+    let after_assignments = get_job_assignments(after);
+
+    let before_index = before_assignments.iter().cloned().collect::<HashMap<_, _>>();
+    let after_index = after_assignments.iter().cloned().collect::<HashMap<_, _>>();
+
+    before_assignments
+        .iter()
+        .map(|(job_id, _)| *job_id)
+        .chain(after_assignments.iter().map(|(job_id, _)| *job_id).filter(|job_id| !before_index.contains_key(job_id)))
+        .filter_map(|job_id| {
+            let before = before_index.get(job_id).cloned();
+            let after = after_index.get(job_id).cloned();
+
+            if before == after {
+                None
+            } else {
+                Some(MovedJob { job_id: job_id.to_string(), before, after })
+            }
+        })
+        .collect()
+}
+
+/// Returns unique job ids with tours where they are assigned, ordered as in the solution.
+fn get_job_assignments(solution: &Solution) -> Vec<(&str, TourKey)> {
+    let mut visited = HashSet::new();
+
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let key = get_tour_key(tour);
+            get_job_sequence(tour).into_iter().map(move |job_id| (job_id, key.clone()))
+        })
+        .filter(|(job_id, _)| visited.insert(*job_id))
+        .collect()
+}
+
+fn get_job_sequence(tour: &Tour) -> Vec<&str> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| !NON_JOB_ACTIVITY_TYPES.contains(&activity.activity_type.as_str()))
+        .map(|activity| activity.job_id.as_str())
+        .collect()
+}
+
+fn get_tour_key(tour: &Tour) -> TourKey {
+    TourKey { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index }
+}
+
+impl Display for SolutionDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "cost: {}", format_value(&self.cost, 2))?;
+        writeln!(f, "distance: {}", format_value(&self.distance, 0))?;
+
+        let count = |change: TourChange| self.tours.iter().filter(|tour| tour.change == change).count();
+        writeln!(
+            f,
+            "tours: {} changed, {} added, {} removed, {} unchanged",
+            count(TourChange::Changed),
+            count(TourChange::Added),
+            count(TourChange::Removed),
+            count(TourChange::Unchanged)
+        )?;
+
+        for tour in self.tours.iter().filter(|tour| tour.change != TourChange::Unchanged) {
+            let change = match tour.change {
+                TourChange::Added => "added",
+                TourChange::Removed => "removed",
+                TourChange::Changed | TourChange::Unchanged => "changed",
+            };
+            write!(f, "  {} {change}: cost {}", tour.key, format_value(&tour.cost, 2))?;
+            if !tour.added_jobs.is_empty() {
+                write!(f, ", added jobs: {}", tour.added_jobs.join(", "))?;
+            }
+            if !tour.removed_jobs.is_empty() {
+                write!(f, ", removed jobs: {}", tour.removed_jobs.join(", "))?;
+            }
+            if tour.order_changed {
+                write!(f, ", order changed")?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "moved jobs: {}", self.moved_jobs.len())?;
+        for job in self.moved_jobs.iter() {
+            let format_key =
+                |key: &Option<TourKey>| key.as_ref().map_or("unassigned".to_string(), |key| key.to_string());
+            writeln!(f, "  {}: {} -> {}", job.job_id, format_key(&job.before), format_key(&job.after))?;
+        }
+
+        if let Some(problem) = &self.problem {
+            for (name, diff) in [("jobs", &problem.jobs), ("vehicles", &problem.vehicles)] {
+                if diff.is_empty() {
+                    writeln!(f, "problem {name}: no changes")?;
+                } else {
+                    writeln!(
+                        f,
+                        "problem {name}: added: [{}], removed: [{}], changed: [{}]",
+                        diff.added.join(", "),
+                        diff.removed.join(", "),
+                        diff.changed.join(", ")
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_value(value: &ValueDiff, precision: usize) -> String {
+    format!("{:.precision$} -> {:.precision$} ({:+.precision$})", value.before, value.after, value.delta)
+}
@@ -0,0 +1,248 @@
+//! Export of pragmatic solution into a flat csv format.
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/export/csv_test.rs"]
+mod csv_test;
+
+pub use self::actual::write_csv_solution;
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::solution::Solution;
+
+/// A file name of the activities table.
+pub const CSV_ACTIVITIES_FILE_NAME: &str = "activities.csv";
+/// A file name of the tours summary table.
+pub const CSV_TOURS_FILE_NAME: &str = "tours.csv";
+/// A file name of the unassigned jobs table.
+pub const CSV_UNASSIGNED_FILE_NAME: &str = "unassigned.csv";
+
+/// Writes pragmatic solution as csv files into given directory. The directory is created if it
+/// does not exist.
+pub fn write_csv_solution_to_dir(solution: &Solution, dir: &Path) -> Result<(), GenericError> {
+    std::fs::create_dir_all(dir).map_err(|err| format!("cannot create directory '{}': {err}", dir.display()))?;
+
+    let create_writer = |name: &str| -> Result<BufWriter<File>, GenericError> {
+        let path = dir.join(name);
+        File::create(&path)
+            .map(BufWriter::new)
+            .map_err(|err| format!("cannot create file '{}': {err}", path.display()).into())
+    };
+
+    write_csv_solution(
+        solution,
+        create_writer(CSV_ACTIVITIES_FILE_NAME)?,
+        create_writer(CSV_TOURS_FILE_NAME)?,
+        create_writer(CSV_UNASSIGNED_FILE_NAME)?,
+    )
+}
+
+#[cfg(feature = "csv-format")]
+mod actual {
+    extern crate csv;
+
+    use super::*;
+    use serde::Serialize;
+    use vrp_pragmatic::format::solution::{Activity, Stop, Tour};
+    use vrp_pragmatic::format::Location;
+
+    /// One row per activity.
+    #[derive(Serialize)]
+    struct CsvActivity<'a> {
+        vehicle_id: &'a str,
+        shift_index: usize,
+        sequence: usize,
+        job_id: &'a str,
+        activity_type: &'a str,
+        arrival: &'a str,
+        departure: &'a str,
+        lat: Option<f64>,
+        lng: Option<f64>,
+        location_index: Option<usize>,
+        load: String,
+        distance: Option<i64>,
+        tag: Option<&'a str>,
+    }
+
+    /// One row per tour.
+    #[derive(Serialize)]
+    struct CsvTour<'a> {
+        vehicle_id: &'a str,
+        type_id: &'a str,
+        shift_index: usize,
+        stops: usize,
+        activities: usize,
+        start: Option<&'a str>,
+        end: Option<&'a str>,
+        cost: f64,
+        distance: i64,
+        duration: i64,
+        driving: i64,
+        serving: i64,
+        waiting: i64,
+        break_time: i64,
+    }
+
+    /// One row per unassigned job reason.
+    #[derive(Serialize)]
+    struct CsvUnassigned<'a> {
+        job_id: &'a str,
+        code: &'a str,
+        description: &'a str,
+    }
+
+    fn get_activity_rows(tour: &Tour) -> impl Iterator<Item = CsvActivity<'_>> + '_ {
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .enumerate()
+            .map(|(sequence, (stop, activity))| create_activity_row(tour, sequence, stop, activity))
+    }
+
+    fn create_activity_row<'a>(
+        tour: &'a Tour,
+        sequence: usize,
+        stop: &'a Stop,
+        activity: &'a Activity,
+    ) -> CsvActivity<'a> {
+        // NOTE activity has own time and location only when there are multiple activities at the stop
+        let (arrival, departure) = activity
+            .time
+            .as_ref()
+            .map(|time| (time.start.as_str(), time.end.as_str()))
+            .unwrap_or_else(|| (stop.schedule().arrival.as_str(), stop.schedule().departure.as_str()));
+
+        let (lat, lng, location_index) = match activity.location.as_ref().or(stop.location()) {
+            Some(Location::Coordinate { lat, lng }) => (Some(*lat), Some(*lng), None),
+            Some(Location::Reference { index }) => (None, None, Some(*index)),
+            Some(Location::Custom { .. }) | None => (None, None, None),
+        };
+
+        CsvActivity {
+            vehicle_id: tour.vehicle_id.as_str(),
+            shift_index: tour.shift_index,
+            sequence,
+            job_id: activity.job_id.as_str(),
+            activity_type: activity.activity_type.as_str(),
+            arrival,
+            departure,
+            lat,
+            lng,
+            location_index,
+            load: stop.load().iter().map(|value| value.to_string()).collect::<Vec<_>>().join(";"),
+            distance: stop.as_point().map(|point| point.distance),
+            tag: activity.job_tag.as_deref(),
+        }
+    }
+
+    fn create_tour_row(tour: &Tour) -> CsvTour<'_> {
+        CsvTour {
+            vehicle_id: tour.vehicle_id.as_str(),
+            type_id: tour.type_id.as_str(),
+            shift_index: tour.shift_index,
+            stops: tour.stops.len(),
+            activities: tour.stops.iter().map(|stop| stop.activities().len()).sum(),
+            start: tour.stops.first().map(|stop| stop.schedule().departure.as_str()),
+            end: tour.stops.last().map(|stop| stop.schedule().arrival.as_str()),
+            cost: tour.statistic.cost,
+            distance: tour.statistic.distance,
+            duration: tour.statistic.duration,
+            driving: tour.statistic.times.driving,
+            serving: tour.statistic.times.serving,
+            waiting: tour.statistic.times.waiting,
+            break_time: tour.statistic.times.break_time,
+        }
+    }
+
+    fn write_rows<W: Write, T: Serialize>(
+        writer: W,
+        rows: impl Iterator<Item = T>,
+        headers: &[&str],
+    ) -> Result<(), GenericError> {
+        let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(writer);
+
+        // NOTE write headers explicitly to have them even for an empty table
+        writer.write_record(headers).map_err(|err| err.to_string())?;
+        rows.into_iter().try_for_each(|row| writer.serialize(row)).map_err(|err| err.to_string())?;
+        writer.flush().map_err(|err| err.to_string().into())
+    }
+
+    /// Writes pragmatic solution as three csv tables: activities (one row per activity), tours
+    /// summary (one row per tour) and unassigned jobs (one row per unassigned reason).
+    /// Multiple load dimensions are separated by `;`.
+    pub fn write_csv_solution<W1: Write, W2: Write, W3: Write>(
+        solution: &Solution,
+        activities_writer: W1,
+        tours_writer: W2,
+        unassigned_writer: W3,
+    ) -> Result<(), GenericError> {
+        write_rows(
+            activities_writer,
+            solution.tours.iter().flat_map(get_activity_rows),
+            &[
+                "VEHICLE_ID",
+                "SHIFT_INDEX",
+                "SEQUENCE",
+                "JOB_ID",
+                "ACTIVITY_TYPE",
+                "ARRIVAL",
+                "DEPARTURE",
+                "LAT",
+                "LNG",
+                "LOCATION_INDEX",
+                "LOAD",
+                "DISTANCE",
+                "TAG",
+            ],
+        )?;
+
+        write_rows(
+            tours_writer,
+            solution.tours.iter().map(create_tour_row),
+            &[
+                "VEHICLE_ID",
+                "TYPE_ID",
+                "SHIFT_INDEX",
+                "STOPS",
+                "ACTIVITIES",
+                "START",
+                "END",
+                "COST",
+                "DISTANCE",
+                "DURATION",
+                "DRIVING",
+                "SERVING",
+                "WAITING",
+                "BREAK",
+            ],
+        )?;
+
+        write_rows(
+            unassigned_writer,
+            solution.unassigned.iter().flatten().flat_map(|job| {
+                job.reasons.iter().map(|reason| CsvUnassigned {
+                    job_id: job.job_id.as_str(),
+                    code: reason.code.as_str(),
+                    description: reason.description.as_str(),
+                })
+            }),
+            &["JOB_ID", "CODE", "DESCRIPTION"],
+        )
+    }
+}
+
+#[cfg(not(feature = "csv-format"))]
+mod actual {
+    use super::*;
+
+    /// A stub method for writing solution in csv format.
+    pub fn write_csv_solution<W1: Write, W2: Write, W3: Write>(
+        _solution: &Solution,
+        _activities_writer: W1,
+        _tours_writer: W2,
+        _unassigned_writer: W3,
+    ) -> Result<(), GenericError> {
+        Err("csv-format feature is not included".into())
+    }
+}
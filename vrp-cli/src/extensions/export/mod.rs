@@ -0,0 +1,4 @@
+//! Export command helpers
+
+mod csv;
+pub use self::csv::*;
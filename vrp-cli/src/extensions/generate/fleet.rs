@@ -4,11 +4,24 @@ mod fleet_test;
 
 use super::*;
 use vrp_pragmatic::format::problem::{Fleet, VehicleCosts, VehicleLimits, VehicleShift, VehicleType};
+use vrp_pragmatic::format::Location;
 
-/// Generates fleet of vehicles.
-pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize) -> Fleet {
-    let rnd = DefaultRandom::default();
+/// A duration of generated break.
+const BREAK_DURATION: f64 = 1800.;
+
+/// A break offset range relative to shift start when shift has no end.
+const BREAK_OFFSET: (f64, f64) = (3. * 3600., 5. * 3600.);
 
+/// A duration of generated reload.
+const RELOAD_DURATION: f64 = 1800.;
+
+/// Generates fleet of vehicles.
+pub(crate) fn generate_fleet(
+    problem_proto: &Problem,
+    options: &GenerateOptions,
+    reload_locations: &[Location],
+    rnd: &GeneratorRandom,
+) -> Fleet {
     let profiles = problem_proto.fleet.profiles.clone();
     let shifts = get_vehicle_shifts(problem_proto);
     let costs = get_vehicle_costs(problem_proto);
@@ -17,22 +30,29 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
     let limits = get_vehicle_limits(problem_proto);
     let vehicles_sizes = get_vehicles_sizes(problem_proto);
 
-    let vehicles = (1..=vehicle_types_size)
+    let vehicles = (1..=options.vehicles_size)
         .map(|type_idx| {
             let type_id = format!("type{type_idx}");
-            let vehicles = *get_random_item(vehicles_sizes.as_slice(), &rnd).expect("cannot find any capacity");
+            let vehicles = *get_random_item(vehicles_sizes.as_slice(), rnd).expect("cannot find any capacity");
+            let shifts = get_random_item(shifts.as_slice(), rnd).expect("cannot find any shifts").clone();
+            let skills = match options.skills {
+                Some(size) if size > 0 => Some(generate_vehicle_skills(type_idx, options.vehicles_size, size, rnd)),
+                _ => get_random_item(skills.as_slice(), rnd).expect("cannot find any skills").clone(),
+            };
+
             VehicleType {
                 type_id,
                 vehicle_ids: (1..=vehicles).map(|vehicle_idx| format!("type{type_idx}_{vehicle_idx}")).collect(),
                 profile: VehicleProfile {
-                    matrix: get_random_item(profiles.as_slice(), &rnd).expect("cannot find any profile").name.clone(),
+                    matrix: get_random_item(profiles.as_slice(), rnd).expect("cannot find any profile").name.clone(),
                     scale: None,
                 },
-                costs: get_random_item(costs.as_slice(), &rnd).expect("cannot find any costs").clone(),
-                shifts: get_random_item(shifts.as_slice(), &rnd).expect("cannot find any shifts").clone(),
-                capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
-                skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
-                limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                costs: get_random_item(costs.as_slice(), rnd).expect("cannot find any costs").clone(),
+                shifts: shifts.into_iter().map(|shift| generate_shift(shift, options, reload_locations)).collect(),
+                capacity: get_random_item(capacities.as_slice(), rnd).expect("cannot find any capacity").clone(),
+                skills,
+                limits: get_random_item(limits.as_slice(), rnd).expect("cannot find any limits").clone(),
+                territories: None,
             }
         })
         .collect();
@@ -40,6 +60,63 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
     Fleet { vehicles, profiles, resources: None }
 }
 
+/// Generates vehicle skills: each skill is assigned at least to one vehicle type in round-robin
+/// manner, other skills are added at random.
+fn generate_vehicle_skills(
+    type_idx: usize,
+    types_size: usize,
+    skills_size: usize,
+    rnd: &GeneratorRandom,
+) -> Vec<String> {
+    (1..=skills_size)
+        .filter(|skill_idx| (skill_idx - 1) % types_size == type_idx - 1 || rnd.is_hit(0.5))
+        .map(|skill_idx| format!("skill{skill_idx}"))
+        .collect()
+}
+
+fn generate_shift(shift: VehicleShift, options: &GenerateOptions, reload_locations: &[Location]) -> VehicleShift {
+    let breaks = match (options.breaks, parse_time(shift.start.earliest.as_str())) {
+        (true, Ok(start)) => {
+            let shift_duration = shift
+                .end
+                .as_ref()
+                .and_then(|end| parse_time(end.latest.as_str()).ok())
+                .map(|end| end - start)
+                .filter(|duration| *duration > BREAK_DURATION);
+
+            // NOTE place break in the middle of the shift
+            let (from, to) =
+                shift_duration.map_or(BREAK_OFFSET, |duration| ((duration * 0.4).round(), (duration * 0.6).round()));
+
+            Some(vec![VehicleBreak::Optional {
+                time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(start + from), format_time(start + to)]),
+                places: vec![VehicleOptionalBreakPlace { duration: BREAK_DURATION, location: None, tag: None }],
+                policy: None,
+            }])
+        }
+        _ => shift.breaks,
+    };
+
+    let reloads = if reload_locations.is_empty() {
+        shift.reloads
+    } else {
+        Some(
+            reload_locations
+                .iter()
+                .map(|location| VehicleReload {
+                    location: location.clone(),
+                    duration: RELOAD_DURATION,
+                    times: None,
+                    tag: None,
+                    resource_id: None,
+                })
+                .collect(),
+        )
+    };
+
+    VehicleShift { breaks, reloads, ..shift }
+}
+
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
 where
     F: Fn(&VehicleType) -> T,
@@ -1,7 +1,7 @@
 //! Generate command helpers.
 
 mod plan;
-use self::plan::generate_plan;
+use self::plan::{generate_plan, get_location_fn};
 
 mod fleet;
 use self::fleet::generate_fleet;
@@ -9,19 +9,131 @@ use self::fleet::generate_fleet;
 mod prototype;
 use self::prototype::generate_from_prototype;
 
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
 use std::io::{BufReader, Read};
+use std::str::FromStr;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
 use vrp_core::prelude::GenericError;
 use vrp_core::utils::{DefaultRandom, Random};
 use vrp_pragmatic::format::problem::*;
 
+/// Specifies spatial distribution of generated job locations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocationDistribution {
+    /// Locations are sampled uniformly inside bounding box (similar to Solomon R class).
+    Uniform,
+    /// Locations are sampled from Gaussian mixture around given amount of cluster centers
+    /// (similar to Solomon C class).
+    Clustered {
+        /// Amount of clusters.
+        clusters: usize,
+    },
+    /// A half of locations is clustered, another half is sampled uniformly (similar to Solomon RC class).
+    RandomClustered {
+        /// Amount of clusters.
+        clusters: usize,
+    },
+    /// Locations are sampled inside a ring around bounding box center, e.g. suburbs around city center.
+    Ring,
+}
+
+/// Specifies distribution of time window width in seconds.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeWindowWidth {
+    /// Width is sampled uniformly from the range.
+    Uniform {
+        /// Minimum width.
+        min: f64,
+        /// Maximum width.
+        max: f64,
+    },
+    /// Width is sampled from normal distribution.
+    Normal {
+        /// Mean width.
+        mean: f64,
+        /// Standard deviation.
+        std_dev: f64,
+    },
+}
+
+impl FromStr for TimeWindowWidth {
+    type Err = GenericError;
+
+    /// Parses time window width distribution from `uniform:<min>,<max>` or `normal:<mean>,<std_dev>` format.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (kind, params) = value.split_once(':').ok_or_else(|| format!("unknown time window width: '{value}'"))?;
+        let params = params
+            .split(',')
+            .map(|param| param.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("cannot parse time window width '{value}': {err}"))?;
+
+        match (kind, params.as_slice()) {
+            ("uniform", &[min, max]) if min > 0. && min <= max => Ok(Self::Uniform { min, max }),
+            ("normal", &[mean, std_dev]) if mean > 0. && std_dev >= 0. => Ok(Self::Normal { mean, std_dev }),
+            _ => Err(format!("invalid time window width: '{value}'").into()),
+        }
+    }
+}
+
+/// Specifies problem generation options.
+#[derive(Clone, Debug)]
+pub struct GenerateOptions {
+    /// Amount of jobs in the plan.
+    pub jobs_size: usize,
+    /// Amount of vehicle types in the fleet.
+    pub vehicles_size: usize,
+    /// Half size of job distribution bounding box in meters. When not specified, bounding box of
+    /// prototype jobs is used.
+    pub area_size: Option<f64>,
+    /// Spatial distribution of job locations. Ignored when locations are specified explicitly.
+    pub distribution: LocationDistribution,
+    /// Time window width distribution. When not specified, time windows are copied from prototype.
+    pub time_window_width: Option<TimeWindowWidth>,
+    /// A ratio of jobs with pickup and delivery. When not specified, job types are copied from prototype.
+    pub pickup_delivery_ratio: Option<f64>,
+    /// Amount of distinct skills. When specified, jobs require one of them with `skills_ratio`
+    /// probability and each skill is available at least in one vehicle type.
+    pub skills: Option<usize>,
+    /// A ratio of jobs which require a skill.
+    pub skills_ratio: f64,
+    /// Adds an optional break in the middle of each vehicle shift.
+    pub breaks: bool,
+    /// Amount of reload depots added to each vehicle shift.
+    pub reloads: Option<usize>,
+    /// A seed which makes generation reproducible.
+    pub seed: Option<u64>,
+}
+
+impl GenerateOptions {
+    /// Creates a new instance of `GenerateOptions` which keeps uniform job distribution and copies
+    /// other properties from prototype.
+    pub fn new(jobs_size: usize, vehicles_size: usize) -> Self {
+        Self {
+            jobs_size,
+            vehicles_size,
+            area_size: None,
+            distribution: LocationDistribution::Uniform,
+            time_window_width: None,
+            pickup_delivery_ratio: None,
+            skills: None,
+            skills_ratio: 0.5,
+            breaks: false,
+            reloads: None,
+            seed: None,
+        }
+    }
+}
+
 /// Generates a pragmatic problem.
 pub fn generate_problem<R: Read>(
     input_format: &str,
     prototype_readers: Option<Vec<BufReader<R>>>,
     locations_reader: Option<BufReader<R>>,
-    job_size: usize,
-    vehicles_size: usize,
-    area_size: Option<f64>,
+    options: &GenerateOptions,
 ) -> Result<Problem, GenericError> {
     let locations = if let Some(locations_reader) = locations_reader {
         Some(deserialize_locations(locations_reader).map_err(|errs| errs.to_string())?)
@@ -39,10 +151,58 @@ pub fn generate_problem<R: Read>(
         _ => Err(format!("unknown format: '{input_format}'")),
     }?;
 
-    generate_from_prototype(&problem_proto, locations, job_size, vehicles_size, area_size)
+    generate_from_prototype(&problem_proto, locations, options)
+}
+
+/// A random generator which produces the same sequence of values for the same seed.
+pub(crate) struct GeneratorRandom {
+    rng: RefCell<SmallRng>,
 }
 
-fn get_random_item<'a, T>(items: &'a [T], rnd: &DefaultRandom) -> Option<&'a T> {
+impl GeneratorRandom {
+    /// Creates a new instance of `GeneratorRandom`. When seed is not specified, it is randomized.
+    pub fn new(seed: Option<u64>) -> Self {
+        let rng = match seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_rng(DefaultRandom::default().get_rng()).expect("cannot create random generator"),
+        };
+
+        Self { rng: RefCell::new(rng) }
+    }
+
+    /// Produces integral random value, uniformly distributed on the closed interval [min, max].
+    pub fn uniform_int(&self, min: i32, max: i32) -> i32 {
+        if min >= max {
+            return min;
+        }
+
+        self.rng.borrow_mut().gen_range(min..=max)
+    }
+
+    /// Produces real random value, uniformly distributed on the interval [min, max).
+    pub fn uniform_real(&self, min: f64, max: f64) -> f64 {
+        if min >= max {
+            return min;
+        }
+
+        self.rng.borrow_mut().gen_range(min..max)
+    }
+
+    /// Samples from normal distribution using Box-Muller transform.
+    pub fn normal(&self, mean: f64, std_dev: f64) -> f64 {
+        let u1 = 1. - self.uniform_real(0., 1.);
+        let u2 = self.uniform_real(0., 1.);
+
+        mean + std_dev * (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos()
+    }
+
+    /// Tests probability value in [0, 1] range.
+    pub fn is_hit(&self, probability: f64) -> bool {
+        self.rng.borrow_mut().gen_bool(probability.clamp(0., 1.))
+    }
+}
+
+fn get_random_item<'a, T>(items: &'a [T], rnd: &GeneratorRandom) -> Option<&'a T> {
     if items.is_empty() {
         return None;
     }
@@ -50,3 +210,16 @@ fn get_random_item<'a, T>(items: &'a [T], rnd: &DefaultRandom) -> Option<&'a T>
     let idx = rnd.uniform_int(0, items.len() as i32 - 1) as usize;
     items.get(idx)
 }
+
+fn parse_time(time: &str) -> Result<f64, GenericError> {
+    OffsetDateTime::parse(time, &Rfc3339)
+        .map(|time| time.unix_timestamp() as f64)
+        .map_err(|err| format!("cannot parse time '{time}': {err}").into())
+}
+
+fn format_time(time: f64) -> String {
+    OffsetDateTime::from_unix_timestamp(time as i64)
+        .ok()
+        .and_then(|time| time.format(&Rfc3339).ok())
+        .expect("cannot format time")
+}
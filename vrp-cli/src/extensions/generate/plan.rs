@@ -2,48 +2,63 @@
 #[path = "../../../tests/unit/extensions/generate/plan_test.rs"]
 mod plan_test;
 
-use super::get_random_item;
-use vrp_core::prelude::GenericError;
-use vrp_core::utils::{DefaultRandom, Random};
-use vrp_pragmatic::format::problem::{Job, JobPlace, JobTask, Plan, Problem};
+use super::*;
 use vrp_pragmatic::format::Location;
 
-/// Generates a new plan for given problem with amount of jobs specified by`jobs_size` and
-/// bounding box of size `area_size` (half size in meters). When not specified, jobs bounding
-/// box is used.
+/// A default time horizon used when prototype shifts have no end.
+const DEFAULT_HORIZON: f64 = 8. * 3600.;
+
+/// A minimum width of generated time window.
+const MIN_TIME_WINDOW_WIDTH: f64 = 60.;
+
+/// A cluster spread relative to bounding box half size.
+const CLUSTER_SPREAD: f64 = 0.1;
+
+/// A ring inner radius relative to bounding box half size.
+const RING_INNER_RADIUS: f64 = 0.75;
+
+pub(crate) type LocationFn = Box<dyn Fn(&GeneratorRandom) -> Location>;
+
+/// Generates a new plan for given problem with amount of jobs specified in options. Job locations
+/// are produced by location function, time windows are either copied from prototype or generated
+/// within vehicle shifts horizon.
 pub(crate) fn generate_plan(
     problem_proto: &Problem,
-    locations: Option<Vec<Location>>,
-    jobs_size: usize,
-    area_size: Option<f64>,
+    location_fn: &LocationFn,
+    options: &GenerateOptions,
+    rnd: &GeneratorRandom,
 ) -> Result<Plan, GenericError> {
-    let rnd = DefaultRandom::default();
-
-    let get_location_fn = get_location_fn(problem_proto, locations, area_size)?;
-
     let time_windows = get_plan_time_windows(&problem_proto.plan);
     let demands = get_plan_demands(&problem_proto.plan);
     let durations = get_plan_durations(&problem_proto.plan);
+    let time_window_width = options
+        .time_window_width
+        .as_ref()
+        .map(|width| get_time_horizon(problem_proto).map(|horizon| (width, horizon)))
+        .transpose()?;
+
+    let get_times = || match time_window_width {
+        Some((width, horizon)) => Some(vec![generate_time_window(width, horizon, rnd)]),
+        None => get_random_item(time_windows.as_slice(), rnd).cloned(),
+    };
+
+    let generate_place = |tag: Option<String>| JobPlace {
+        location: location_fn(rnd),
+        duration: get_random_item(durations.as_slice(), rnd).cloned().unwrap(),
+        times: get_times(),
+        tag,
+    };
 
     let generate_tasks = |tasks: &Option<Vec<JobTask>>, keep_original_demand: bool| {
         tasks.as_ref().map(|tasks| {
             tasks
                 .iter()
                 .map(|task| JobTask {
-                    places: task
-                        .places
-                        .iter()
-                        .map(|place| JobPlace {
-                            location: get_location_fn(&rnd),
-                            duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
-                            times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
-                            tag: place.tag.clone(),
-                        })
-                        .collect(),
+                    places: task.places.iter().map(|place| generate_place(place.tag.clone())).collect(),
                     demand: if keep_original_demand {
                         task.demand.clone()
                     } else {
-                        get_random_item(demands.as_slice(), &rnd).cloned()
+                        get_random_item(demands.as_slice(), rnd).cloned()
                     },
                     order: task.order,
                 })
@@ -51,58 +66,185 @@ pub(crate) fn generate_plan(
         })
     };
 
-    let jobs = (1..=jobs_size)
+    let is_pickup_delivery = |job: &Job| {
+        job.pickups.as_ref().is_some_and(|t| !t.is_empty()) && job.deliveries.as_ref().is_some_and(|t| !t.is_empty())
+    };
+
+    let other_protos = problem_proto.plan.jobs.iter().filter(|job| !is_pickup_delivery(job)).collect::<Vec<_>>();
+    let all_protos = problem_proto.plan.jobs.iter().collect::<Vec<_>>();
+
+    let jobs = (1..=options.jobs_size)
         .map(|job_idx| {
-            let job_proto = get_random_item(problem_proto.plan.jobs.as_slice(), &rnd).unwrap();
-
-            // TODO implement more sophisticated logic for jobs with pickup and delivery
-            let keep_original_demand = job_proto.pickups.as_ref().map_or(false, |t| !t.is_empty())
-                && job_proto.deliveries.as_ref().map_or(false, |t| !t.is_empty());
-
-            Job {
-                id: format!("job{job_idx}"),
-                pickups: generate_tasks(&job_proto.pickups, keep_original_demand),
-                deliveries: generate_tasks(&job_proto.deliveries, keep_original_demand),
-                replacements: generate_tasks(&job_proto.replacements, false),
-                services: generate_tasks(&job_proto.services, true),
-                skills: job_proto.skills.clone(),
-                value: job_proto.value,
-                group: job_proto.group.clone(),
-                compatibility: job_proto.compatibility.clone(),
+            let id = format!("job{job_idx}");
+
+            let job = match options.pickup_delivery_ratio {
+                Some(ratio) if rnd.is_hit(ratio) => {
+                    let demand = get_random_item(demands.as_slice(), rnd).cloned();
+                    let create_task = || {
+                        Some(vec![JobTask { places: vec![generate_place(None)], demand: demand.clone(), order: None }])
+                    };
+
+                    Job {
+                        id,
+                        pickups: create_task(),
+                        deliveries: create_task(),
+                        replacements: None,
+                        services: None,
+                        skills: None,
+                        preferences: None,
+                        value: None,
+                        group: None,
+                        compatibility: None,
+                    }
+                }
+                ratio => {
+                    let protos = if ratio.is_some() && !other_protos.is_empty() { &other_protos } else { &all_protos };
+                    let job_proto = *get_random_item(protos.as_slice(), rnd).unwrap();
+
+                    // TODO implement more sophisticated logic for jobs with pickup and delivery
+                    let keep_original_demand = is_pickup_delivery(job_proto);
+
+                    Job {
+                        id,
+                        pickups: generate_tasks(&job_proto.pickups, keep_original_demand),
+                        deliveries: generate_tasks(&job_proto.deliveries, keep_original_demand),
+                        replacements: generate_tasks(&job_proto.replacements, false),
+                        services: generate_tasks(&job_proto.services, true),
+                        skills: job_proto.skills.clone(),
+                        preferences: job_proto.preferences.clone(),
+                        value: job_proto.value,
+                        group: job_proto.group.clone(),
+                        compatibility: job_proto.compatibility.clone(),
+                    }
+                }
+            };
+
+            match options.skills {
+                Some(skills) if skills > 0 => Job {
+                    skills: rnd.is_hit(options.skills_ratio).then(|| JobSkills {
+                        all_of: Some(vec![format!("skill{}", rnd.uniform_int(1, skills as i32))]),
+                        one_of: None,
+                        none_of: None,
+                    }),
+                    ..job
+                },
+                _ => job,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan { jobs, relations: None, clustering: None, territories: None })
 }
 
-type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
-
-fn get_location_fn(
+/// Creates a function which returns job location using given distribution inside bounding box or
+/// picks it from the list of locations, if specified.
+pub(crate) fn get_location_fn(
     problem_proto: &Problem,
     locations: Option<Vec<Location>>,
-    area_size: Option<f64>,
+    options: &GenerateOptions,
+    rnd: &GeneratorRandom,
 ) -> Result<LocationFn, GenericError> {
     if let Some(locations) = locations {
-        Ok(Box::new(move |rnd| get_random_item(locations.as_slice(), rnd).cloned().expect("cannot get any location")))
-    } else {
-        let bounding_box = if let Some(area_size) = area_size {
-            if area_size > 0. {
-                get_bounding_box_from_size(&problem_proto.plan, area_size)
-            } else {
-                return Err("area size must be positive".into());
-            }
+        return Ok(Box::new(move |rnd| {
+            get_random_item(locations.as_slice(), rnd).cloned().expect("cannot get any location")
+        }));
+    }
+
+    let bounding_box = if let Some(area_size) = options.area_size {
+        if area_size > 0. {
+            get_bounding_box_from_size(&problem_proto.plan, area_size)
         } else {
-            get_bounding_box_from_plan(&problem_proto.plan)
-        };
+            return Err("area size must be positive".into());
+        }
+    } else {
+        get_bounding_box_from_plan(&problem_proto.plan)
+    };
+
+    let ((lat_min, lng_min), (lat_max, lng_max)) = bounding_box;
+    let (lat_center, lng_center) = ((lat_min + lat_max) / 2., (lng_min + lng_max) / 2.);
+    let (lat_half, lng_half) = ((lat_max - lat_min) / 2., (lng_max - lng_min) / 2.);
+
+    let sample_uniform = move |rnd: &GeneratorRandom| Location::Coordinate {
+        lat: rnd.uniform_real(lat_min, lat_max),
+        lng: rnd.uniform_real(lng_min, lng_max),
+    };
+
+    let create_clustered_fn = |clusters: usize| -> Result<LocationFn, GenericError> {
+        if clusters == 0 {
+            return Err("amount of clusters must be positive".into());
+        }
+
+        let centers = (0..clusters)
+            .map(|_| (rnd.uniform_real(lat_min, lat_max), rnd.uniform_real(lng_min, lng_max)))
+            .collect::<Vec<_>>();
+
         Ok(Box::new(move |rnd| {
-            // TODO allow to configure distribution
-            let lat = rnd.uniform_real((bounding_box.0).0, (bounding_box.1).0);
-            let lng = rnd.uniform_real((bounding_box.0).1, (bounding_box.1).1);
+            let (lat, lng) = *get_random_item(centers.as_slice(), rnd).expect("cannot get any cluster");
 
-            Location::Coordinate { lat, lng }
+            Location::Coordinate {
+                lat: rnd.normal(lat, lat_half * CLUSTER_SPREAD).clamp(lat_min, lat_max),
+                lng: rnd.normal(lng, lng_half * CLUSTER_SPREAD).clamp(lng_min, lng_max),
+            }
         }))
+    };
+
+    Ok(match &options.distribution {
+        LocationDistribution::Uniform => Box::new(sample_uniform),
+        LocationDistribution::Clustered { clusters } => create_clustered_fn(*clusters)?,
+        LocationDistribution::RandomClustered { clusters } => {
+            let clustered_fn = create_clustered_fn(*clusters)?;
+            Box::new(move |rnd| if rnd.is_hit(0.5) { clustered_fn(rnd) } else { sample_uniform(rnd) })
+        }
+        LocationDistribution::Ring => Box::new(move |rnd| {
+            let radius = rnd.uniform_real(RING_INNER_RADIUS, 1.);
+            let angle = rnd.uniform_real(0., 2. * std::f64::consts::PI);
+
+            Location::Coordinate {
+                lat: lat_center + radius * lat_half * angle.sin(),
+                lng: lng_center + radius * lng_half * angle.cos(),
+            }
+        }),
+    })
+}
+
+/// Returns a time horizon of prototype vehicle shifts as unix timestamps.
+fn get_time_horizon(problem_proto: &Problem) -> Result<(f64, f64), GenericError> {
+    let shifts = problem_proto.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).collect::<Vec<_>>();
+
+    let start = shifts
+        .iter()
+        .map(|shift| parse_time(shift.start.earliest.as_str()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .fold(f64::MAX, f64::min);
+
+    let end = shifts
+        .iter()
+        .filter_map(|shift| shift.end.as_ref())
+        .map(|end| parse_time(end.latest.as_str()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .fold(f64::MIN, f64::max);
+
+    if start == f64::MAX {
+        return Err("prototype has no vehicle shifts".into());
     }
+
+    Ok((start, if end > start { end } else { start + DEFAULT_HORIZON }))
+}
+
+fn generate_time_window(width: &TimeWindowWidth, horizon: (f64, f64), rnd: &GeneratorRandom) -> Vec<String> {
+    let (horizon_start, horizon_end) = horizon;
+
+    let width = match width {
+        TimeWindowWidth::Uniform { min, max } => rnd.uniform_real(*min, *max),
+        TimeWindowWidth::Normal { mean, std_dev } => rnd.normal(*mean, *std_dev),
+    }
+    .clamp(MIN_TIME_WINDOW_WIDTH, (horizon_end - horizon_start).max(MIN_TIME_WINDOW_WIDTH));
+
+    let start = rnd.uniform_real(horizon_start, (horizon_end - width).max(horizon_start)).round();
+
+    vec![format_time(start), format_time(start + width.round())]
 }
 
 fn get_bounding_box_from_plan(plan: &Plan) -> ((f64, f64), (f64, f64)) {
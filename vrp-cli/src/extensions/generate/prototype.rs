@@ -14,17 +14,19 @@ use vrp_pragmatic::format::Location;
 pub(crate) fn generate_from_prototype(
     problem: &Problem,
     locations: Option<Vec<Location>>,
-    jobs_size: usize,
-    vehicle_types_size: usize,
-    area_size: Option<f64>,
+    options: &GenerateOptions,
 ) -> Result<Problem, GenericError> {
     if problem.plan.jobs.len() < 3 {
         return Err("at least three jobs should be defined".into());
     }
 
+    let rnd = GeneratorRandom::new(options.seed);
+    let location_fn = get_location_fn(problem, locations, options, &rnd)?;
+    let reload_locations = (0..options.reloads.unwrap_or(0)).map(|_| location_fn(&rnd)).collect::<Vec<_>>();
+
     Ok(Problem {
-        plan: generate_plan(problem, locations, jobs_size, area_size)?,
-        fleet: generate_fleet(problem, vehicle_types_size),
+        plan: generate_plan(problem, &location_fn, options, &rnd)?,
+        fleet: generate_fleet(problem, options, reload_locations.as_slice(), &rnd),
         objectives: problem.objectives.clone(),
     })
 }
@@ -3,140 +3,457 @@
 #[path = "../../../tests/unit/extensions/import/csv_test.rs"]
 mod csv_test;
 
-pub use self::actual::read_csv_problem;
+pub use self::actual::{read_csv_problem, read_csv_problem_with_columns};
+
+use serde::Deserialize;
+
+/// Specifies a separator of multiple values inside one csv cell, e.g. demand dimensions, skills
+/// or time windows.
+pub const CSV_LIST_SEPARATOR: char = ';';
+
+/// Specifies names of csv columns. Allows to import csv files with arbitrary headers.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CsvColumns {
+    /// Job columns.
+    pub jobs: CsvJobColumns,
+    /// Vehicle columns.
+    pub vehicles: CsvVehicleColumns,
+}
+
+/// Specifies names of job csv columns.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CsvJobColumns {
+    /// Job id, rows with the same id are tasks of the same job. Required.
+    pub id: String,
+    /// Task latitude. Required.
+    pub lat: String,
+    /// Task longitude. Required.
+    pub lng: String,
+    /// Task demand, multiple dimensions are separated by `;`.
+    pub demand: String,
+    /// Task type: `pickup`, `delivery` or `service`. When omitted, it is derived from demand sign.
+    pub task_type: String,
+    /// Task duration in seconds.
+    pub duration: String,
+    /// Time window starts, multiple time windows are separated by `;`.
+    pub tw_start: String,
+    /// Time window ends, multiple time windows are separated by `;`.
+    pub tw_end: String,
+    /// Job skills required from vehicle (all of them), separated by `;`.
+    pub skills: String,
+    /// Job value.
+    pub value: String,
+    /// Task order.
+    pub order: String,
+    /// Task tag.
+    pub tag: String,
+}
+
+impl Default for CsvJobColumns {
+    fn default() -> Self {
+        Self {
+            id: "ID".to_string(),
+            lat: "LAT".to_string(),
+            lng: "LNG".to_string(),
+            demand: "DEMAND".to_string(),
+            task_type: "TYPE".to_string(),
+            duration: "DURATION".to_string(),
+            tw_start: "TW_START".to_string(),
+            tw_end: "TW_END".to_string(),
+            skills: "SKILLS".to_string(),
+            value: "VALUE".to_string(),
+            order: "ORDER".to_string(),
+            tag: "TAG".to_string(),
+        }
+    }
+}
+
+/// Specifies names of vehicle csv columns.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CsvVehicleColumns {
+    /// Vehicle type id. Required.
+    pub id: String,
+    /// Depot latitude. Required.
+    pub lat: String,
+    /// Depot longitude. Required.
+    pub lng: String,
+    /// Vehicle capacity, multiple dimensions are separated by `;`. Required.
+    pub capacity: String,
+    /// Shift start time. Required.
+    pub tw_start: String,
+    /// Shift end time. Required.
+    pub tw_end: String,
+    /// Amount of vehicles of this type. Default is 1.
+    pub amount: String,
+    /// Routing profile. Required.
+    pub profile: String,
+    /// Vehicle skills separated by `;`.
+    pub skills: String,
+    /// Break time window starts, multiple breaks are separated by `;`.
+    pub break_tw_start: String,
+    /// Break time window ends, multiple breaks are separated by `;`.
+    pub break_tw_end: String,
+    /// Break durations in seconds, multiple breaks are separated by `;`.
+    pub break_duration: String,
+    /// Reload duration in seconds. When specified, vehicle can reload.
+    pub reload_duration: String,
+    /// Reload place latitude. Default is depot latitude.
+    pub reload_lat: String,
+    /// Reload place longitude. Default is depot longitude.
+    pub reload_lng: String,
+    /// Max traveling distance per tour.
+    pub max_distance: String,
+    /// Max duration per tour.
+    pub max_duration: String,
+    /// Max amount of job activities per tour.
+    pub tour_size: String,
+}
+
+impl Default for CsvVehicleColumns {
+    fn default() -> Self {
+        Self {
+            id: "ID".to_string(),
+            lat: "LAT".to_string(),
+            lng: "LNG".to_string(),
+            capacity: "CAPACITY".to_string(),
+            tw_start: "TW_START".to_string(),
+            tw_end: "TW_END".to_string(),
+            amount: "AMOUNT".to_string(),
+            profile: "PROFILE".to_string(),
+            skills: "SKILLS".to_string(),
+            break_tw_start: "BREAK_TW_START".to_string(),
+            break_tw_end: "BREAK_TW_END".to_string(),
+            break_duration: "BREAK_DURATION".to_string(),
+            reload_duration: "RELOAD_DURATION".to_string(),
+            reload_lat: "RELOAD_LAT".to_string(),
+            reload_lng: "RELOAD_LNG".to_string(),
+            max_distance: "MAX_DISTANCE".to_string(),
+            max_duration: "MAX_DURATION".to_string(),
+            tour_size: "TOUR_SIZE".to_string(),
+        }
+    }
+}
 
 #[cfg(feature = "csv-format")]
 mod actual {
     extern crate csv;
-    extern crate serde;
 
-    use serde::Deserialize;
+    use super::*;
     use std::collections::{HashMap, HashSet};
     use std::error::Error;
     use std::io::{BufReader, Read};
+    use std::str::FromStr;
     use vrp_pragmatic::format::problem::*;
     use vrp_pragmatic::format::{FormatError, Location};
 
-    #[derive(Debug, Deserialize)]
-    #[serde(rename_all = "UPPERCASE")]
-    struct CsvJob {
-        id: String,
-        lat: f64,
-        lng: f64,
-        demand: i32,
-        duration: usize,
-        tw_start: Option<String>,
-        tw_end: Option<String>,
-    }
-
-    #[derive(Debug, Deserialize)]
-    #[serde(rename_all = "UPPERCASE")]
-    struct CsvVehicle {
-        id: String,
-        lat: f64,
-        lng: f64,
-        capacity: i32,
-        tw_start: String,
-        tw_end: String,
-        amount: usize,
-        profile: String,
-    }
-
-    fn read_csv_entries<T, R: Read>(reader: BufReader<R>) -> Result<Vec<T>, Box<dyn Error>>
-    where
-        for<'de> T: Deserialize<'de>,
-    {
-        let mut reader = csv::Reader::from_reader(reader);
-        let mut entries = vec![];
-
-        for entry in reader.deserialize() {
-            entries.push(entry?);
+    type CsvResult<T> = Result<T, Box<dyn Error>>;
+
+    /// Keeps csv records with the mapping from header name to column index.
+    struct CsvTable {
+        headers: HashMap<String, usize>,
+        records: Vec<csv::StringRecord>,
+    }
+
+    impl CsvTable {
+        fn read<R: Read>(reader: BufReader<R>, required: &[&str]) -> CsvResult<Self> {
+            let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+
+            let headers = reader
+                .headers()?
+                .iter()
+                .enumerate()
+                .map(|(idx, header)| (header.to_string(), idx))
+                .collect::<HashMap<_, _>>();
+
+            if let Some(column) = required.iter().find(|column| !headers.contains_key(**column)) {
+                return Err(format!("missing required column '{column}'").into());
+            }
+
+            let records = reader.records().collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Self { headers, records })
         }
 
-        Ok(entries)
+        fn rows(&self) -> impl Iterator<Item = CsvRow<'_>> + '_ {
+            self.records.iter().map(|record| CsvRow {
+                table: self,
+                record,
+                line: record.position().map_or(0, |position| position.line() as usize),
+            })
+        }
+    }
+
+    struct CsvRow<'a> {
+        table: &'a CsvTable,
+        record: &'a csv::StringRecord,
+        line: usize,
+    }
+
+    impl<'a> CsvRow<'a> {
+        /// Returns a non-empty cell value.
+        fn get(&self, column: &str) -> Option<&'a str> {
+            self.table
+                .headers
+                .get(column)
+                .and_then(|idx| self.record.get(*idx))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        }
+
+        fn get_required(&self, column: &str) -> CsvResult<&'a str> {
+            self.get(column).ok_or_else(|| format!("line {}: missing value in column '{column}'", self.line).into())
+        }
+
+        fn parse<T: FromStr>(&self, column: &str) -> CsvResult<Option<T>> {
+            self.get(column).map(|value| self.parse_value(column, value)).transpose()
+        }
+
+        fn parse_required<T: FromStr>(&self, column: &str) -> CsvResult<T> {
+            self.parse_value(column, self.get_required(column)?)
+        }
+
+        fn parse_list<T: FromStr>(&self, column: &str) -> CsvResult<Option<Vec<T>>> {
+            self.get(column)
+                .map(|value| {
+                    value
+                        .split(CSV_LIST_SEPARATOR)
+                        .map(|item| item.trim())
+                        .filter(|item| !item.is_empty())
+                        .map(|item| self.parse_value(column, item))
+                        .collect::<CsvResult<Vec<_>>>()
+                })
+                .transpose()
+        }
+
+        fn parse_value<T: FromStr>(&self, column: &str, value: &str) -> CsvResult<T> {
+            value
+                .parse::<T>()
+                .map_err(|_| format!("line {}: cannot parse '{value}' in column '{column}'", self.line).into())
+        }
+
+        fn parse_time_windows(&self, start: &str, end: &str) -> CsvResult<Option<Vec<Vec<String>>>> {
+            match (self.parse_list::<String>(start)?, self.parse_list::<String>(end)?) {
+                (Some(starts), Some(ends)) if starts.len() == ends.len() => {
+                    Ok(Some(starts.into_iter().zip(ends).map(|(start, end)| vec![start, end]).collect()))
+                }
+                (None, None) => Ok(None),
+                _ => Err(format!("line {}: mismatch of values in columns '{start}' and '{end}'", self.line).into()),
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum TaskType {
+        Pickup,
+        Delivery,
+        Service,
     }
 
-    fn parse_tw(start: Option<String>, end: Option<String>) -> Option<Vec<String>> {
-        match (start, end) {
-            (Some(start), Some(end)) => Some(vec![start, end]),
-            _ => None,
+    fn read_task_type(row: &CsvRow, columns: &CsvJobColumns, demand: Option<&Vec<i32>>) -> CsvResult<TaskType> {
+        match row.get(&columns.task_type).map(|value| value.to_lowercase()) {
+            Some(value) => match value.as_str() {
+                "pickup" => Ok(TaskType::Pickup),
+                "delivery" => Ok(TaskType::Delivery),
+                "service" => Ok(TaskType::Service),
+                _ => Err(format!("line {}: unknown task type '{value}'", row.line).into()),
+            },
+            None => match demand.and_then(|demand| demand.iter().find(|value| **value != 0)) {
+                Some(value) if *value > 0 => Ok(TaskType::Pickup),
+                Some(_) => Ok(TaskType::Delivery),
+                None => Ok(TaskType::Service),
+            },
         }
     }
 
-    fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>> {
-        let get_task = |job: &CsvJob| JobTask {
+    fn read_task(row: &CsvRow, columns: &CsvJobColumns) -> CsvResult<(TaskType, JobTask)> {
+        let demand = row.parse_list::<i32>(&columns.demand)?;
+        let task_type = read_task_type(row, columns, demand.as_ref())?;
+
+        let demand = demand
+            .map(|demand| demand.into_iter().map(|value| value.abs()).collect::<Vec<_>>())
+            .filter(|demand| task_type != TaskType::Service && demand.iter().any(|value| *value != 0));
+
+        let task = JobTask {
             places: vec![JobPlace {
-                location: Location::Coordinate { lat: job.lat, lng: job.lng },
-                duration: job.duration as f64,
-                times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
-                tag: None,
+                location: Location::Coordinate {
+                    lat: row.parse_required(&columns.lat)?,
+                    lng: row.parse_required(&columns.lng)?,
+                },
+                duration: row.parse::<f64>(&columns.duration)?.unwrap_or(0.),
+                times: row.parse_time_windows(&columns.tw_start, &columns.tw_end)?,
+                tag: row.get(&columns.tag).map(|tag| tag.to_string()),
             }],
-            demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
-            order: None,
+            demand,
+            order: row.parse(&columns.order)?,
         };
 
-        let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
-            let tasks = jobs.iter().filter(|j| (filter)(j)).map(|job| get_task(job)).collect::<Vec<_>>();
-            if tasks.is_empty() {
-                None
-            } else {
-                Some(tasks)
+        Ok((task_type, task))
+    }
+
+    fn read_jobs<R: Read>(reader: BufReader<R>, columns: &CsvJobColumns) -> CsvResult<Vec<Job>> {
+        let table = CsvTable::read(reader, &[&columns.id, &columns.lat, &columns.lng])?;
+
+        // NOTE rows with the same id define tasks of the same job, keep order of the first occurrence
+        let mut jobs: Vec<Job> = vec![];
+        let mut job_indices = HashMap::<String, usize>::new();
+
+        for row in table.rows() {
+            let id = row.get_required(&columns.id)?;
+            let (task_type, task) = read_task(&row, columns)?;
+            let skills = row.parse_list::<String>(&columns.skills)?;
+            let value = row.parse::<f64>(&columns.value)?;
+
+            let job_idx = *job_indices.entry(id.to_string()).or_insert_with(|| {
+                jobs.push(Job {
+                    id: id.to_string(),
+                    pickups: None,
+                    deliveries: None,
+                    replacements: None,
+                    services: None,
+                    skills: None,
+                    preferences: None,
+                    value: None,
+                    group: None,
+                    compatibility: None,
+                });
+                jobs.len() - 1
+            });
+
+            let job = &mut jobs[job_idx];
+            let tasks = match task_type {
+                TaskType::Pickup => &mut job.pickups,
+                TaskType::Delivery => &mut job.deliveries,
+                TaskType::Service => &mut job.services,
+            };
+            tasks.get_or_insert_with(Vec::new).push(task);
+
+            if job.skills.is_none() {
+                job.skills = skills.map(|skills| JobSkills { all_of: Some(skills), one_of: None, none_of: None });
+            }
+            job.value = job.value.or(value);
+        }
+
+        Ok(jobs)
+    }
+
+    fn read_breaks(row: &CsvRow, columns: &CsvVehicleColumns) -> CsvResult<Option<Vec<VehicleBreak>>> {
+        let times = row.parse_time_windows(&columns.break_tw_start, &columns.break_tw_end)?;
+        let durations = row.parse_list::<f64>(&columns.break_duration)?;
+
+        match (times, durations) {
+            (Some(times), Some(durations)) if times.len() == durations.len() => Ok(Some(
+                times
+                    .into_iter()
+                    .zip(durations)
+                    .map(|(time, duration)| VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(time),
+                        places: vec![VehicleOptionalBreakPlace { duration, location: None, tag: None }],
+                        policy: None,
+                    })
+                    .collect(),
+            )),
+            (None, None) => Ok(None),
+            _ => Err(format!("line {}: mismatch of break time windows and durations", row.line).into()),
+        }
+    }
+
+    fn read_reloads(
+        row: &CsvRow,
+        columns: &CsvVehicleColumns,
+        depot: &Location,
+    ) -> CsvResult<Option<Vec<VehicleReload>>> {
+        let Some(duration) = row.parse::<f64>(&columns.reload_duration)? else { return Ok(None) };
+
+        let location = match (row.parse::<f64>(&columns.reload_lat)?, row.parse::<f64>(&columns.reload_lng)?) {
+            (Some(lat), Some(lng)) => Location::Coordinate { lat, lng },
+            (None, None) => depot.clone(),
+            _ => {
+                return Err(format!("line {}: reload location should have both latitude and longitude", row.line).into())
             }
         };
 
-        let jobs = read_csv_entries::<CsvJob, _>(reader)?
-            .iter()
-            .fold(HashMap::<_, Vec<_>>::new(), |mut acc, job| {
-                acc.entry(&job.id).or_default().push(job);
-                acc
-            })
-            .into_iter()
-            .map(|(job_id, tasks)| Job {
-                id: job_id.clone(),
-                pickups: get_tasks(&tasks, Box::new(|j| j.demand > 0)),
-                deliveries: get_tasks(&tasks, Box::new(|j| j.demand < 0)),
-                replacements: None,
-                services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
-                skills: None,
-                value: None,
-                group: None,
-                compatibility: None,
-            })
-            .collect();
+        Ok(Some(vec![VehicleReload { location, duration, times: None, tag: None, resource_id: None }]))
+    }
 
-        Ok(jobs)
+    fn read_limits(row: &CsvRow, columns: &CsvVehicleColumns) -> CsvResult<Option<VehicleLimits>> {
+        let max_distance = row.parse(&columns.max_distance)?;
+        let max_duration = row.parse(&columns.max_duration)?;
+        let tour_size = row.parse(&columns.tour_size)?;
+
+        Ok(if max_distance.is_some() || max_duration.is_some() || tour_size.is_some() {
+            Some(VehicleLimits {
+                max_distance,
+                max_duration,
+                tour_size,
+                min_tour_size: None,
+                min_duration: None,
+                min_load: None,
+            })
+        } else {
+            None
+        })
     }
 
-    fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<dyn Error>> {
-        let vehicles = read_csv_entries::<CsvVehicle, _>(reader)?
-            .into_iter()
-            .map(|vehicle| {
-                let depot_location = Location::Coordinate { lat: vehicle.lat, lng: vehicle.lng };
+    fn read_vehicles<R: Read>(reader: BufReader<R>, columns: &CsvVehicleColumns) -> CsvResult<Vec<VehicleType>> {
+        let required = [
+            &columns.id,
+            &columns.lat,
+            &columns.lng,
+            &columns.capacity,
+            &columns.tw_start,
+            &columns.tw_end,
+            &columns.profile,
+        ];
+        let table = CsvTable::read(reader, required.map(|column| column.as_str()).as_slice())?;
 
-                VehicleType {
-                    type_id: vehicle.id.clone(),
-                    vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
-                    profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+        table
+            .rows()
+            .map(|row| {
+                let depot_location = Location::Coordinate {
+                    lat: row.parse_required(&columns.lat)?,
+                    lng: row.parse_required(&columns.lng)?,
+                };
+                let profile = row.get_required(&columns.profile)?.to_string();
+                let amount = row.parse::<usize>(&columns.amount)?.unwrap_or(1);
+
+                Ok(VehicleType {
+                    type_id: row.get_required(&columns.id)?.to_string(),
+                    vehicle_ids: (1..=amount).map(|seq| format!("{profile}_{seq}")).collect(),
+                    profile: VehicleProfile { matrix: profile, scale: None },
+                    costs: VehicleCosts {
+                        fixed: Some(25.),
+                        distance: 0.0002,
+                        time: 0.005,
+                        time_bands: None,
+                        load_factors: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
-                            earliest: vehicle.tw_start,
+                            earliest: row.get_required(&columns.tw_start)?.to_string(),
                             latest: None,
                             location: depot_location.clone(),
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
-                        breaks: None,
-                        reloads: None,
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: row.get_required(&columns.tw_end)?.to_string(),
+                            location: depot_location.clone(),
+                        }),
+                        breaks: read_breaks(&row, columns)?,
+                        reloads: read_reloads(&row, columns, &depot_location)?,
                         recharges: None,
                     }],
-                    capacity: vec![vehicle.capacity],
-                    skills: None,
-                    limits: None,
-                }
+                    capacity: row
+                        .parse_list::<i32>(&columns.capacity)?
+                        .ok_or_else(|| format!("line {}: missing value in column '{}'", row.line, columns.capacity))?,
+                    skills: row.parse_list::<String>(&columns.skills)?,
+                    limits: read_limits(&row, columns)?,
+                    territories: None,
+                })
             })
-            .collect();
-
-        Ok(vehicles)
+            .collect()
     }
 
     fn create_format_error(entity: &str, error: Box<dyn Error>) -> FormatError {
@@ -148,17 +465,27 @@ mod actual {
         )
     }
 
-    /// Reads problem from csv format.
+    /// Reads problem from csv format using default column names.
     pub fn read_csv_problem<R1: Read, R2: Read>(
         jobs_reader: BufReader<R1>,
         vehicles_reader: BufReader<R2>,
     ) -> Result<Problem, FormatError> {
-        let jobs = read_jobs(jobs_reader).map_err(|err| create_format_error("jobs", err))?;
-        let vehicles = read_vehicles(vehicles_reader).map_err(|err| create_format_error("vehicles", err))?;
+        read_csv_problem_with_columns(jobs_reader, vehicles_reader, &CsvColumns::default())
+    }
+
+    /// Reads problem from csv format using given column names.
+    pub fn read_csv_problem_with_columns<R1: Read, R2: Read>(
+        jobs_reader: BufReader<R1>,
+        vehicles_reader: BufReader<R2>,
+        columns: &CsvColumns,
+    ) -> Result<Problem, FormatError> {
+        let jobs = read_jobs(jobs_reader, &columns.jobs).map_err(|err| create_format_error("jobs", err))?;
+        let vehicles =
+            read_vehicles(vehicles_reader, &columns.vehicles).map_err(|err| create_format_error("vehicles", err))?;
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, clustering: None, territories: None },
             fleet: Fleet {
                 vehicles,
                 profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
@@ -171,6 +498,7 @@ mod actual {
 
 #[cfg(not(feature = "csv-format"))]
 mod actual {
+    use super::CsvColumns;
     use std::io::{BufReader, Read};
     use vrp_pragmatic::format::problem::Problem;
     use vrp_pragmatic::format::FormatError;
@@ -182,4 +510,13 @@ mod actual {
     ) -> Result<Problem, FormatError> {
         unreachable!("csv-format feature is not included")
     }
+
+    /// A stub method for reading problem from csv format.
+    pub fn read_csv_problem_with_columns<R1: Read, R2: Read>(
+        _jobs_reader: BufReader<R1>,
+        _vehicles_reader: BufReader<R2>,
+        _columns: &CsvColumns,
+    ) -> Result<Problem, FormatError> {
+        unreachable!("csv-format feature is not included")
+    }
 }
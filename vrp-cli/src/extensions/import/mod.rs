@@ -12,13 +12,24 @@ pub fn import_problem<R: Read>(
     input_format: &str,
     readers: Option<Vec<BufReader<R>>>,
 ) -> Result<Problem, GenericError> {
-    match (input_format, readers) {
-        ("csv", Some(mut readers)) if readers.len() == 2 => {
+    match input_format {
+        "csv" => import_csv_problem(readers, &CsvColumns::default()),
+        _ => Err(format!("unknown format: '{input_format}'").into()),
+    }
+}
+
+/// Imports problem from csv format into pragmatic using given column names.
+pub fn import_csv_problem<R: Read>(
+    readers: Option<Vec<BufReader<R>>>,
+    columns: &CsvColumns,
+) -> Result<Problem, GenericError> {
+    match readers {
+        Some(mut readers) if readers.len() == 2 => {
             let jobs = readers.swap_remove(0);
             let vehicles = readers.swap_remove(0);
-            read_csv_problem(jobs, vehicles).map_err(|err| format!("cannot read csv: {err}").into())
+            read_csv_problem_with_columns(jobs, vehicles, columns)
+                .map_err(|err| format!("cannot read csv: {err}").into())
         }
-        ("csv", _) => Err("csv format expects two files with jobs and vehicles as an input".into()),
-        _ => Err(format!("unknown format: '{input_format}'").into()),
+        _ => Err("csv format expects two files with jobs and vehicles as an input".into()),
     }
 }
@@ -0,0 +1,421 @@
+//! Renders pragmatic solution as a standalone html report which needs no network access: map and
+//! timeline are drawn as inline svg.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/report/html_test.rs"]
+mod html_test;
+
+use std::collections::HashSet;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::problem::{Job, Problem};
+use vrp_pragmatic::format::solution::{Solution, Statistic, Tour};
+use vrp_pragmatic::format::Location;
+
+const PALETTE: &[&str] =
+    &["#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f", "#bcbd22", "#17becf"];
+
+const MAP_WIDTH: f64 = 960.;
+const MAP_HEIGHT: f64 = 600.;
+const MAP_PADDING: f64 = 20.;
+
+const TIMELINE_LABEL_WIDTH: f64 = 160.;
+const TIMELINE_CHART_WIDTH: f64 = 800.;
+const TIMELINE_ROW_HEIGHT: f64 = 24.;
+const TIMELINE_AXIS_HEIGHT: f64 = 20.;
+const TIMELINE_TICKS: usize = 8;
+
+const STYLE: &str = r#"
+body { font-family: sans-serif; margin: 24px; color: #222; }
+table { border-collapse: collapse; margin-bottom: 24px; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: right; }
+th:first-child, td:first-child { text-align: left; }
+svg { border: 1px solid #ccc; background: #fff; }
+.legend span { display: inline-block; margin-right: 16px; }
+.legend i { display: inline-block; width: 12px; height: 12px; margin-right: 4px; vertical-align: middle; }
+"#;
+
+/// Specifies a kind of the timeline segment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SegmentKind {
+    Driving,
+    Serving,
+    Waiting,
+    Break,
+}
+
+impl SegmentKind {
+    fn name(&self) -> &'static str {
+        match self {
+            SegmentKind::Driving => "driving",
+            SegmentKind::Serving => "serving",
+            SegmentKind::Waiting => "waiting",
+            SegmentKind::Break => "break",
+        }
+    }
+
+    fn color(&self) -> &'static str {
+        match self {
+            SegmentKind::Driving => "#4e79a7",
+            SegmentKind::Serving => "#59a14f",
+            SegmentKind::Waiting => "#edc948",
+            SegmentKind::Break => "#e15759",
+        }
+    }
+}
+
+/// A timeline segment with start and end as unix timestamps.
+#[derive(Clone, Debug, PartialEq)]
+struct Segment {
+    kind: SegmentKind,
+    start: i64,
+    end: i64,
+}
+
+/// Creates a standalone html report for given problem and solution in pragmatic format. The report
+/// contains summary statistic, a map with tours, a timeline per tour and a list of unassigned jobs.
+pub fn get_html_report(problem: &Problem, solution: &Solution) -> Result<String, GenericError> {
+    let timeline = render_timeline(solution)?;
+
+    Ok(format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>VRP solution report</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<h1>VRP solution report</h1>
+<h2>Summary</h2>
+{}
+<h2>Map</h2>
+{}
+<h2>Timeline</h2>
+{timeline}
+<h2>Tours</h2>
+{}
+<h2>Unassigned jobs</h2>
+{}
+</body>
+</html>
+"#,
+        render_summary(problem, solution),
+        render_map(problem, solution),
+        render_tours(solution),
+        render_unassigned(solution)
+    ))
+}
+
+fn render_summary(problem: &Problem, solution: &Solution) -> String {
+    let unassigned = solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len());
+    let Statistic { cost, distance, duration, times, .. } = &solution.statistic;
+
+    let rows = [
+        ("Jobs", problem.plan.jobs.len().to_string()),
+        ("Unassigned jobs", unassigned.to_string()),
+        ("Tours", solution.tours.len().to_string()),
+        ("Cost", format!("{cost:.2}")),
+        ("Distance", distance.to_string()),
+        ("Duration", format_duration(*duration)),
+        ("Driving", format_duration(times.driving)),
+        ("Serving", format_duration(times.serving)),
+        ("Waiting", format_duration(times.waiting)),
+        ("Break", format_duration(times.break_time)),
+    ];
+
+    let rows = rows.iter().map(|(name, value)| format!("<tr><th>{name}</th><td>{value}</td></tr>")).collect::<String>();
+
+    format!("<table>{rows}</table>")
+}
+
+fn render_map(problem: &Problem, solution: &Solution) -> String {
+    let tours = solution
+        .tours
+        .iter()
+        .map(|tour| tour.stops.iter().filter_map(|stop| stop.location().and_then(get_coordinate)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let unassigned_ids = solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<HashSet<_>>();
+    let unassigned = problem
+        .plan
+        .jobs
+        .iter()
+        .filter(|job| unassigned_ids.contains(job.id.as_str()))
+        .flat_map(get_job_locations)
+        .filter_map(get_coordinate)
+        .collect::<Vec<_>>();
+
+    let all = tours.iter().flatten().chain(unassigned.iter()).cloned().collect::<Vec<_>>();
+    if all.is_empty() {
+        return "<p>No coordinates to draw a map.</p>".to_string();
+    }
+
+    let project = create_projection(all.as_slice());
+
+    let routes = tours
+        .iter()
+        .enumerate()
+        .filter(|(_, points)| !points.is_empty())
+        .map(|(idx, points)| {
+            let color = PALETTE[idx % PALETTE.len()];
+            let tour = &solution.tours[idx];
+            let title = escape(&format!("{} (shift {})", tour.vehicle_id, tour.shift_index));
+            let path = points
+                .iter()
+                .map(|&point| project(point))
+                .map(|(x, y)| format!("{x:.1},{y:.1}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let (depot_x, depot_y) = project(points[0]);
+            let stops = points
+                .iter()
+                .skip(1)
+                .map(|&point| project(point))
+                .map(|(x, y)| format!(r#"<circle cx="{x:.1}" cy="{y:.1}" r="3" fill="{color}"/>"#))
+                .collect::<String>();
+
+            format!(
+                r##"<g><title>{title}</title><polyline points="{path}" fill="none" stroke="{color}" stroke-width="2"/>{stops}<rect x="{:.1}" y="{:.1}" width="8" height="8" fill="#000"/></g>"##,
+                depot_x - 4.,
+                depot_y - 4.
+            )
+        })
+        .collect::<String>();
+
+    let unassigned = unassigned
+        .iter()
+        .map(|&point| project(point))
+        .map(|(x, y)| {
+            format!(r##"<path d="M{:.1},{:.1} l8,8 m0,-8 l-8,8" stroke="#999" stroke-width="2"/>"##, x - 4., y - 4.)
+        })
+        .collect::<String>();
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{MAP_WIDTH}" height="{MAP_HEIGHT}">{routes}<g><title>unassigned</title>{unassigned}</g></svg>"#
+    )
+}
+
+fn render_timeline(solution: &Solution) -> Result<String, GenericError> {
+    let segments = solution.tours.iter().map(get_segments).collect::<Result<Vec<_>, _>>()?;
+
+    let (start, end) = segments
+        .iter()
+        .flatten()
+        .fold((i64::MAX, i64::MIN), |(start, end), segment| (start.min(segment.start), end.max(segment.end)));
+
+    if start >= end {
+        return Ok("<p>No tours to draw a timeline.</p>".to_string());
+    }
+
+    let scale = TIMELINE_CHART_WIDTH / (end - start) as f64;
+    let get_x = |time: i64| TIMELINE_LABEL_WIDTH + (time - start) as f64 * scale;
+
+    let rows = solution
+        .tours
+        .iter()
+        .zip(segments.iter())
+        .enumerate()
+        .map(|(idx, (tour, segments))| {
+            let y = TIMELINE_AXIS_HEIGHT + idx as f64 * TIMELINE_ROW_HEIGHT;
+            let label = escape(&format!("{} ({})", tour.vehicle_id, tour.shift_index));
+            let bars = segments
+                .iter()
+                .map(|segment| {
+                    format!(
+                        r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"><title>{}: {} - {}</title></rect>"#,
+                        get_x(segment.start),
+                        y + 4.,
+                        (segment.end - segment.start) as f64 * scale,
+                        TIMELINE_ROW_HEIGHT - 8.,
+                        segment.kind.color(),
+                        segment.kind.name(),
+                        format_time(segment.start),
+                        format_time(segment.end)
+                    )
+                })
+                .collect::<String>();
+
+            format!(r#"<text x="4" y="{:.1}" font-size="12">{label}</text>{bars}"#, y + TIMELINE_ROW_HEIGHT / 2. + 4.)
+        })
+        .collect::<String>();
+
+    let ticks = (0..=TIMELINE_TICKS)
+        .map(|tick| start + (end - start) * tick as i64 / TIMELINE_TICKS as i64)
+        .map(|time| {
+            let x = get_x(time);
+            format!(
+                r##"<line x1="{x:.1}" y1="{TIMELINE_AXIS_HEIGHT}" x2="{x:.1}" y2="{:.1}" stroke="#eee"/><text x="{x:.1}" y="12" font-size="10" text-anchor="middle">{}</text>"##,
+                TIMELINE_AXIS_HEIGHT + solution.tours.len() as f64 * TIMELINE_ROW_HEIGHT,
+                format_time(time)
+            )
+        })
+        .collect::<String>();
+
+    let legend = [SegmentKind::Driving, SegmentKind::Serving, SegmentKind::Waiting, SegmentKind::Break]
+        .iter()
+        .map(|kind| format!(r#"<span><i style="background:{}"></i>{}</span>"#, kind.color(), kind.name()))
+        .collect::<String>();
+
+    Ok(format!(
+        r#"<div class="legend">{legend}</div><svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}">{ticks}{rows}</svg>"#,
+        TIMELINE_LABEL_WIDTH + TIMELINE_CHART_WIDTH + MAP_PADDING,
+        TIMELINE_AXIS_HEIGHT + solution.tours.len() as f64 * TIMELINE_ROW_HEIGHT
+    ))
+}
+
+fn render_tours(solution: &Solution) -> String {
+    let rows = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let Statistic { cost, distance, duration, times, .. } = &tour.statistic;
+            let activities = tour.stops.iter().map(|stop| stop.activities().len()).sum::<usize>();
+
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{activities}</td><td>{cost:.2}</td><td>{distance}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(&tour.vehicle_id),
+                tour.shift_index,
+                tour.stops.len(),
+                format_duration(*duration),
+                format_duration(times.driving),
+                format_duration(times.serving),
+                format_duration(times.waiting),
+                format_duration(times.break_time),
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "<table><tr><th>Vehicle</th><th>Shift</th><th>Stops</th><th>Activities</th><th>Cost</th><th>Distance</th>\
+         <th>Duration</th><th>Driving</th><th>Serving</th><th>Waiting</th><th>Break</th></tr>{rows}</table>"
+    )
+}
+
+fn render_unassigned(solution: &Solution) -> String {
+    let rows = solution
+        .unassigned
+        .iter()
+        .flatten()
+        .flat_map(|job| {
+            job.reasons.iter().map(move |reason| {
+                format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    escape(&job.job_id),
+                    escape(&reason.code),
+                    escape(&reason.description)
+                )
+            })
+        })
+        .collect::<String>();
+
+    if rows.is_empty() {
+        "<p>All jobs are assigned.</p>".to_string()
+    } else {
+        format!("<table><tr><th>Job</th><th>Code</th><th>Reason</th></tr>{rows}</table>")
+    }
+}
+
+/// Splits tour schedule into timeline segments. Activity time starts after waiting, so waiting is
+/// a gap between previous activity end and activity start.
+fn get_segments(tour: &Tour) -> Result<Vec<Segment>, GenericError> {
+    let mut segments = Vec::new();
+    let mut last_departure: Option<i64> = None;
+
+    for stop in tour.stops.iter() {
+        let arrival = parse_time(&stop.schedule().arrival)?;
+        let departure = parse_time(&stop.schedule().departure)?;
+
+        if let Some(last_departure) = last_departure {
+            add_segment(&mut segments, SegmentKind::Driving, last_departure, arrival);
+        }
+
+        let mut current = arrival;
+        for activity in stop.activities() {
+            let (start, end) = match &activity.time {
+                Some(time) => (parse_time(&time.start)?, parse_time(&time.end)?),
+                None => (current, departure),
+            };
+
+            // NOTE a vehicle is not actually working before its departure and after its arrival
+            if matches!(activity.activity_type.as_str(), "departure" | "arrival") {
+                current = end;
+                continue;
+            }
+
+            add_segment(&mut segments, SegmentKind::Waiting, current, start);
+
+            let kind = if activity.activity_type == "break" { SegmentKind::Break } else { SegmentKind::Serving };
+            add_segment(&mut segments, kind, start, end);
+
+            current = current.max(end);
+        }
+
+        last_departure = Some(departure);
+    }
+
+    Ok(segments)
+}
+
+fn add_segment(segments: &mut Vec<Segment>, kind: SegmentKind, start: i64, end: i64) {
+    if end > start {
+        segments.push(Segment { kind, start, end });
+    }
+}
+
+fn get_job_locations(job: &Job) -> impl Iterator<Item = &Location> + '_ {
+    [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .flat_map(|task| task.places.iter().map(|place| &place.location))
+}
+
+fn get_coordinate(location: &Location) -> Option<(f64, f64)> {
+    match location {
+        Location::Coordinate { lat, lng } => Some((*lat, *lng)),
+        _ => None,
+    }
+}
+
+/// Creates a function which projects (lat, lng) into svg coordinates fitting all given points.
+fn create_projection(points: &[(f64, f64)]) -> impl Fn((f64, f64)) -> (f64, f64) {
+    // NOTE use equirectangular projection which is good enough for a city or region scale
+    let mean_lat = points.iter().map(|(lat, _)| lat).sum::<f64>() / points.len() as f64;
+    let kx = mean_lat.to_radians().cos();
+
+    let (min_x, max_x, min_y, max_y) =
+        points.iter().fold((f64::MAX, f64::MIN, f64::MAX, f64::MIN), |(min_x, max_x, min_y, max_y), &(lat, lng)| {
+            (min_x.min(lng * kx), max_x.max(lng * kx), min_y.min(lat), max_y.max(lat))
+        });
+
+    let width = (max_x - min_x).max(f64::EPSILON);
+    let height = (max_y - min_y).max(f64::EPSILON);
+    let scale = ((MAP_WIDTH - 2. * MAP_PADDING) / width).min((MAP_HEIGHT - 2. * MAP_PADDING) / height);
+
+    let offset_x = (MAP_WIDTH - width * scale) / 2.;
+    let offset_y = (MAP_HEIGHT - height * scale) / 2.;
+
+    move |(lat, lng)| (offset_x + (lng * kx - min_x) * scale, offset_y + (max_y - lat) * scale)
+}
+
+fn parse_time(time: &str) -> Result<i64, GenericError> {
+    OffsetDateTime::parse(time, &Rfc3339)
+        .map(|time| time.unix_timestamp())
+        .map_err(|err| format!("cannot parse time '{time}': {err}").into())
+}
+
+fn format_time(timestamp: i64) -> String {
+    OffsetDateTime::from_unix_timestamp(timestamp)
+        .map(|time| format!("{:02}:{:02}", time.hour(), time.minute()))
+        .unwrap_or_default()
+}
+
+fn format_duration(seconds: i64) -> String {
+    format!("{}:{:02}:{:02}", seconds / 3600, (seconds % 3600) / 60, seconds % 60)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,4 @@
+//! Report command helpers
+
+mod html;
+pub use self::html::*;
@@ -0,0 +1,4 @@
+//! Serve command helpers
+
+pub mod queue;
+pub mod server;
@@ -0,0 +1,330 @@
+//! Contains a job queue which solves submitted problems using a bounded pool of worker threads.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/queue_test.rs"]
+mod queue_test;
+
+use crate::extensions::solve::config::{create_builder_from_config, Config};
+use crate::{serialize_as_solver_error, serialize_solution};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use vrp_core::models::GoalContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::prelude::*;
+use vrp_core::solver::RefinementContext;
+
+/// Specifies a minimum time between two best known solution snapshots of the same job.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Specifies a job status.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Job is waiting for a free worker.
+    Queued,
+    /// Job is being solved.
+    Running,
+    /// Job is solved, result is available.
+    Completed,
+    /// Solver has failed, error is available.
+    Failed,
+    /// Job was cancelled: result is available if solver has found any solution before cancellation.
+    Cancelled,
+}
+
+/// Keeps job status and progress information.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobInfo {
+    /// Job id.
+    pub id: String,
+    /// Job status.
+    pub status: JobStatus,
+    /// The last generation reported by solver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<usize>,
+    /// A cost of the best known solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+    /// An error description for failed job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A queue which keeps submitted jobs and solves them in background using a fixed amount of
+/// worker threads. An amount of jobs waiting for a free worker is bounded as well as an amount
+/// of kept finished jobs: the oldest ones are evicted when a new job is submitted.
+pub struct JobQueue {
+    jobs: RwLock<HashMap<String, Arc<Job>>>,
+    sender: SyncSender<JobTask>,
+    next_id: AtomicUsize,
+    max_finished: usize,
+}
+
+impl JobQueue {
+    /// Creates a new instance of `JobQueue` with given amount of workers, max amount of
+    /// queued jobs and max amount of kept finished jobs.
+    pub fn new(workers: usize, capacity: usize, max_finished: usize) -> Result<Self, GenericError> {
+        if workers == 0 {
+            return Err("amount of workers should be greater than zero".into());
+        }
+
+        let (sender, receiver) = sync_channel::<JobTask>(capacity);
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        (0..workers).try_for_each(|idx| {
+            let receiver = receiver.clone();
+            thread::Builder::new()
+                .name(format!("job-worker-{idx}"))
+                .spawn(move || run_worker(receiver))
+                .map(|_| ())
+                .map_err(|err| GenericError::from(format!("cannot start job worker: {err}")))
+        })?;
+
+        Ok(Self { jobs: RwLock::new(HashMap::default()), sender, next_id: AtomicUsize::new(1), max_finished })
+    }
+
+    /// Submits a new job and returns its id. Returns error if the queue is full.
+    pub fn submit(&self, problem: Arc<Problem>, config: Config) -> Result<String, GenericError> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+        let job = Arc::new(Job::new(id.clone()));
+
+        self.evict_finished();
+
+        // NOTE register job before sending it to workers, so its status can be updated
+        self.jobs.write().unwrap().insert(id.clone(), job.clone());
+
+        match self.sender.try_send(JobTask { job, problem, config }) {
+            Ok(_) => Ok(id),
+            Err(err) => {
+                self.jobs.write().unwrap().remove(&id);
+                Err(match err {
+                    TrySendError::Full(_) => "job queue is full".into(),
+                    TrySendError::Disconnected(_) => "job workers are not available".into(),
+                })
+            }
+        }
+    }
+
+    /// Returns job information.
+    pub fn get_info(&self, id: &str) -> Option<JobInfo> {
+        self.get_job(id).map(|job| job.state.lock().unwrap().info.clone())
+    }
+
+    /// Returns the best known solution serialized in pragmatic format, if it is already available.
+    pub fn get_best_known(&self, id: &str) -> Option<String> {
+        self.get_job(id).and_then(|job| job.state.lock().unwrap().best_known.clone())
+    }
+
+    /// Returns the final solution serialized in pragmatic format, if the job is finished and
+    /// solver has found a solution.
+    pub fn get_result(&self, id: &str) -> Option<String> {
+        self.get_job(id).and_then(|job| job.state.lock().unwrap().result.clone())
+    }
+
+    /// Cancels queued or running job. Finished job is removed from the queue.
+    /// Returns job information or None if there is no such job.
+    pub fn cancel(&self, id: &str) -> Option<JobInfo> {
+        let job = self.get_job(id)?;
+        job.is_cancelled.store(true, Ordering::Relaxed);
+
+        // NOTE job state lock is released before jobs are modified to keep the same lock order as in eviction
+        let (info, is_finished) = {
+            let mut state = job.state.lock().unwrap();
+            let is_finished = match state.info.status {
+                JobStatus::Queued => {
+                    state.info.status = JobStatus::Cancelled;
+                    state.finished_at = Some(Instant::now());
+                    false
+                }
+                JobStatus::Running => false,
+                JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled => true,
+            };
+
+            (state.info.clone(), is_finished)
+        };
+
+        if is_finished {
+            self.jobs.write().unwrap().remove(id);
+        }
+
+        Some(info)
+    }
+
+    fn get_job(&self, id: &str) -> Option<Arc<Job>> {
+        self.jobs.read().unwrap().get(id).cloned()
+    }
+
+    /// Removes the oldest finished jobs which exceed the retention limit.
+    fn evict_finished(&self) {
+        let mut jobs = self.jobs.write().unwrap();
+
+        let mut finished = jobs
+            .iter()
+            .filter_map(|(id, job)| job.state.lock().unwrap().finished_at.map(|finished_at| (id.clone(), finished_at)))
+            .collect::<Vec<_>>();
+
+        if finished.len() <= self.max_finished {
+            return;
+        }
+
+        finished.sort_by_key(|(_, finished_at)| *finished_at);
+        finished.iter().take(finished.len() - self.max_finished).for_each(|(id, _)| {
+            jobs.remove(id);
+        });
+    }
+}
+
+struct Job {
+    is_cancelled: AtomicBool,
+    state: Mutex<JobState>,
+}
+
+impl Job {
+    fn new(id: String) -> Self {
+        Self {
+            is_cancelled: AtomicBool::new(false),
+            state: Mutex::new(JobState {
+                info: JobInfo { id, status: JobStatus::Queued, generation: None, cost: None, error: None },
+                best_known: None,
+                result: None,
+                finished_at: None,
+            }),
+        }
+    }
+}
+
+struct JobState {
+    info: JobInfo,
+    best_known: Option<String>,
+    result: Option<String>,
+    finished_at: Option<Instant>,
+}
+
+struct JobTask {
+    job: Arc<Job>,
+    problem: Arc<Problem>,
+    config: Config,
+}
+
+fn run_worker(receiver: Arc<Mutex<Receiver<JobTask>>>) {
+    loop {
+        // NOTE lock is released before the task is processed
+        let task = receiver.lock().unwrap().recv();
+        match task {
+            Ok(task) => {
+                let job = task.job.clone();
+                // NOTE keep worker alive if solver panics
+                if panic::catch_unwind(AssertUnwindSafe(|| run_task(task))).is_err() {
+                    let mut state = job.state.lock().unwrap_or_else(|err| err.into_inner());
+                    state.info.status = JobStatus::Failed;
+                    state.info.error = Some("solver has panicked".to_string());
+                    state.finished_at = Some(Instant::now());
+                }
+            }
+            Err(_) => break,
+        }
+    }
+}
+
+fn run_task(task: JobTask) {
+    let JobTask { job, problem, config } = task;
+
+    {
+        let mut state = job.state.lock().unwrap();
+        if job.is_cancelled.load(Ordering::Relaxed) {
+            return;
+        }
+        state.info.status = JobStatus::Running;
+    }
+
+    let result = create_builder_from_config(problem.clone(), Default::default(), &config)
+        .and_then(|builder| builder.build())
+        .map(|mut evolution_config| {
+            evolution_config.termination = Box::new(JobTermination {
+                inner: evolution_config.termination,
+                job: job.clone(),
+                problem: problem.clone(),
+                config: config.clone(),
+                snapshot: Mutex::new(None),
+            });
+            Solver::new(problem.clone(), evolution_config)
+        })
+        .and_then(|solver| solver.solve())
+        .map_err(serialize_as_solver_error)
+        .and_then(|solution| serialize_solution(problem.as_ref(), &solution, &config).map(|result| (solution, result)));
+
+    let mut state = job.state.lock().unwrap();
+    state.info.status =
+        if job.is_cancelled.load(Ordering::Relaxed) { JobStatus::Cancelled } else { JobStatus::Completed };
+    state.finished_at = Some(Instant::now());
+
+    match result {
+        Ok((solution, result)) => {
+            state.info.cost = Some(solution.cost);
+            state.best_known = Some(result.clone());
+            state.result = Some(result);
+        }
+        Err(err) => {
+            state.info.status = JobStatus::Failed;
+            state.info.error = Some(err.to_string());
+        }
+    }
+}
+
+/// A termination which wraps the original one to report job progress and to stop solver when
+/// the job is cancelled.
+struct JobTermination {
+    inner: Box<dyn Termination<Context = RefinementContext, Objective = GoalContext>>,
+    job: Arc<Job>,
+    problem: Arc<Problem>,
+    config: Config,
+    snapshot: Mutex<Option<(Vec<f64>, Instant)>>,
+}
+
+impl JobTermination {
+    fn try_take_snapshot(&self, heuristic_ctx: &RefinementContext) {
+        let Some((insertion_ctx, _)) = heuristic_ctx.ranked().next() else { return };
+
+        let fitness = heuristic_ctx.objective().fitness(insertion_ctx).collect::<Vec<_>>();
+        let mut snapshot = self.snapshot.lock().unwrap();
+        let is_outdated = snapshot.as_ref().is_none_or(|(last_fitness, last_time)| {
+            *last_fitness != fitness && last_time.elapsed() >= SNAPSHOT_INTERVAL
+        });
+
+        if !is_outdated {
+            return;
+        }
+
+        let solution = Solution::from(insertion_ctx.deep_copy());
+        if let Ok(best_known) = serialize_solution(self.problem.as_ref(), &solution, &self.config) {
+            let mut state = self.job.state.lock().unwrap();
+            state.info.cost = Some(solution.cost);
+            state.best_known = Some(best_known);
+        }
+
+        *snapshot = Some((fitness, Instant::now()));
+    }
+}
+
+impl Termination for JobTermination {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        self.job.state.lock().unwrap().info.generation = Some(heuristic_ctx.statistics().generation);
+        self.try_take_snapshot(heuristic_ctx);
+
+        self.job.is_cancelled.load(Ordering::Relaxed) || self.inner.is_termination(heuristic_ctx)
+    }
+
+    fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
+        self.inner.estimate(heuristic_ctx)
+    }
+}
@@ -0,0 +1,180 @@
+//! Contains a simple HTTP server which exposes solver functionality as JSON API:
+//!
+//! - `POST /jobs`: submits a job, body is an object with `problem`, optional `matrices` and `config`
+//!   properties. Returns job information with its id.
+//! - `GET /jobs/{id}`: returns job status and progress.
+//! - `GET /jobs/{id}/best`: returns the best known solution found so far.
+//! - `GET /jobs/{id}/result`: returns the final solution of completed or cancelled job.
+//! - `DELETE /jobs/{id}`: cancels queued or running job, removes finished one.
+//!
+//! Config properties which make solver access local files or network (`island` and `priors` of
+//! dynamic heuristic) are not allowed.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/server_test.rs"]
+mod server_test;
+
+use super::queue::JobQueue;
+use crate::extensions::solve::config::{Config, HyperType};
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use tiny_http::{Header, Method, Request, Response, Server};
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::problem::{Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::FormatError;
+
+/// Specifies solver server parameters.
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    /// An amount of jobs solved in parallel.
+    pub workers: usize,
+    /// A max amount of jobs waiting for a free worker.
+    pub queue_size: usize,
+    /// A max amount of finished jobs kept with their results, the oldest ones are removed first.
+    pub max_finished: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self { workers: 1, queue_size: 16, max_finished: 64 }
+    }
+}
+
+/// A HTTP server which accepts problems in pragmatic format and solves them in background.
+pub struct SolverServer {
+    server: Server,
+    queue: JobQueue,
+}
+
+impl SolverServer {
+    /// Creates a new instance of `SolverServer` listening on given address.
+    pub fn new(address: &str, config: ServerConfig) -> Result<Self, GenericError> {
+        let queue = JobQueue::new(config.workers, config.queue_size, config.max_finished)?;
+        let server = Server::http(address).map_err(|err| format!("cannot start server on '{address}': {err}"))?;
+
+        Ok(Self { server, queue })
+    }
+
+    /// Returns an address the server is listening on.
+    pub fn address(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Runs the server in the current thread processing requests one by one.
+    pub fn run(&self) {
+        for request in self.server.incoming_requests() {
+            self.process(request);
+        }
+    }
+
+    fn process(&self, mut request: Request) {
+        let mut body = String::new();
+        let (status, body) = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => {
+                // NOTE invalid input can trigger a panic in problem reader, server should survive it
+                panic::catch_unwind(AssertUnwindSafe(|| {
+                    handle_request(&self.queue, request.method(), request.url(), body.as_str())
+                }))
+                .unwrap_or_else(|_| (500, serialize_as_error("cannot process request")))
+            }
+            Err(err) => (400, serialize_as_error(&format!("cannot read request body: {err}"))),
+        };
+
+        let header = Header::from_bytes("Content-Type", "application/json").expect("valid header");
+        let response = Response::from_string(body).with_status_code(status).with_header(header);
+
+        // NOTE client might be disconnected already, nothing to do in this case
+        let _ = request.respond(response);
+    }
+}
+
+/// A request to submit a new job.
+#[derive(Deserialize)]
+struct SubmitRequest {
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    config: Option<Config>,
+}
+
+/// Handles request and returns response status code and body.
+fn handle_request(queue: &JobQueue, method: &Method, url: &str, body: &str) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default().trim_end_matches('/');
+    let segments = path.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+
+    match (method, segments.as_slice()) {
+        (Method::Post, ["jobs"]) => submit_job(queue, body),
+        (Method::Get, ["jobs", id]) => queue.get_info(id).map_or_else(not_found, |info| (200, to_json(&info))),
+        (Method::Get, ["jobs", id, "best"]) => get_solution(queue.get_info(id), queue.get_best_known(id)),
+        (Method::Get, ["jobs", id, "result"]) => get_solution(queue.get_info(id), queue.get_result(id)),
+        (Method::Delete, ["jobs", id]) => queue.cancel(id).map_or_else(not_found, |info| (200, to_json(&info))),
+        _ => (404, serialize_as_error(&format!("unknown route: {method} {path}"))),
+    }
+}
+
+fn submit_job(queue: &JobQueue, body: &str) -> (u16, String) {
+    let request = match serde_json::from_str::<SubmitRequest>(body) {
+        Ok(request) => request,
+        Err(err) => {
+            let err = FormatError::new(
+                "E0000".to_string(),
+                "cannot deserialize request".to_string(),
+                format!("check request definition. Error: '{err}'"),
+            );
+            return (400, err.to_json());
+        }
+    };
+
+    if let Some(err) = request.config.as_ref().and_then(get_config_error) {
+        return (400, serialize_as_error(err));
+    }
+
+    let problem = match request.matrices {
+        Some(matrices) if !matrices.is_empty() => (request.problem, matrices).read_pragmatic(),
+        _ => request.problem.read_pragmatic(),
+    };
+
+    let problem = match problem {
+        Ok(problem) => Arc::new(problem),
+        Err(errs) => return (400, errs.to_json()),
+    };
+
+    match queue.submit(problem, request.config.unwrap_or_default()) {
+        Ok(id) => queue.get_info(id.as_str()).map_or_else(not_found, |info| (202, to_json(&info))),
+        Err(err) => (503, serialize_as_error(&err.to_string())),
+    }
+}
+
+/// Returns an error if config contains properties which are not allowed in server mode.
+fn get_config_error(config: &Config) -> Option<&'static str> {
+    if config.island.is_some() {
+        return Some("island model is not supported by server");
+    }
+
+    if let Some(HyperType::DynamicSelective { priors: Some(_) }) = &config.hyper {
+        return Some("dynamic heuristic priors are not supported by server");
+    }
+
+    None
+}
+
+fn get_solution<T>(info: Option<T>, solution: Option<String>) -> (u16, String) {
+    match (info, solution) {
+        (None, _) => not_found(),
+        (Some(_), None) => (409, serialize_as_error("solution is not available yet")),
+        (Some(_), Some(solution)) => (200, solution),
+    }
+}
+
+fn not_found() -> (u16, String) {
+    (404, serialize_as_error("job is not found"))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+fn serialize_as_error(message: &str) -> String {
+    to_json(&serde_json::json!({ "error": message }))
+}
@@ -9,19 +9,26 @@ mod config_test;
 extern crate serde_json;
 
 use serde::Deserialize;
+use std::fs::File;
 use std::io::{BufReader, Read};
+use std::net::TcpListener;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::SingleDimLoad;
-use vrp_core::models::GoalContext;
+use vrp_core::models::{ExtrasBuilder, GoalContext};
 use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::strategies::{AlnsAcceptance, AlnsConfig};
 use vrp_core::rosomaxa::evolution::{InitialOperator, TelemetryMode};
 use vrp_core::rosomaxa::get_default_selection_size;
+use vrp_core::rosomaxa::hyper::{IslandConfig, IslandHeuristic, SearchOperatorStatistics};
 use vrp_core::rosomaxa::prelude::*;
 use vrp_core::rosomaxa::utils::*;
 use vrp_core::solver::search::*;
 use vrp_core::solver::RecreateInitialOperator;
 use vrp_core::solver::*;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+use super::island::{IslandTopology, TcpMigration};
 
 /// An algorithm configuration.
 #[derive(Clone, Default, Deserialize, Debug)]
@@ -30,6 +37,8 @@ pub struct Config {
     pub evolution: Option<EvolutionConfig>,
     /// Specifies hyper heuristic type.
     pub hyper: Option<HyperType>,
+    /// Specifies island model configuration.
+    pub island: Option<IslandModelConfig>,
     /// Specifies algorithm termination configuration.
     pub termination: Option<TerminationConfig>,
     /// Specifies environment configuration.
@@ -46,6 +55,8 @@ pub struct Config {
 pub struct EvolutionConfig {
     pub initial: Option<InitialConfig>,
     pub population: Option<PopulationType>,
+    /// An amount of job's nearest neighbours which legs are considered when insertion is evaluated.
+    pub granularity: Option<usize>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -134,7 +145,76 @@ pub enum HyperType {
     /// A hyper heuristic which selects operator from the predefined list using reinforcement
     /// learning technics.
     #[serde(rename(deserialize = "dynamic-selective"))]
-    DynamicSelective,
+    DynamicSelective {
+        /// A path to solution in pragmatic format which metrics contain search operator statistics
+        /// learned in previous run. They are used as priors for operator selection.
+        priors: Option<String>,
+    },
+
+    /// An adaptive large neighbourhood search which keeps a single current solution and selects
+    /// ruin and recreate operators using adaptive weights.
+    #[serde(rename(deserialize = "alns"))]
+    #[serde(rename_all = "camelCase")]
+    Alns {
+        /// Acceptance criteria. Default is simulated annealing.
+        acceptance: Option<AlnsAcceptanceType>,
+        /// Amount of iterations after which operator weights are updated, must be positive. Default is 100.
+        segment_size: Option<usize>,
+        /// Reaction factor of weights update in [0, 1] range. Default is 0.1.
+        reaction_factor: Option<f64>,
+    },
+}
+
+/// An acceptance criteria used by ALNS.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum AlnsAcceptanceType {
+    /// Simulated annealing with exponentially decreasing temperature.
+    #[serde(rename(deserialize = "simulated-annealing"))]
+    #[serde(rename_all = "camelCase")]
+    SimulatedAnnealing {
+        /// Initial temperature, must not be less than final one.
+        initial_temperature: f64,
+        /// Final temperature, must be positive.
+        final_temperature: f64,
+    },
+
+    /// Record-to-record travel with linearly decreasing threshold.
+    #[serde(rename(deserialize = "record-to-record"))]
+    RecordToRecord {
+        /// Initial relative deviation from the best known solution, must be non-negative.
+        deviation: f64,
+    },
+}
+
+/// An island model configuration: several solver processes run their own populations and
+/// periodically exchange best known solutions over TCP.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IslandModelConfig {
+    /// Addresses of all islands, e.g. `127.0.0.1:4001`.
+    pub islands: Vec<String>,
+    /// An index of the current island in the list of islands. Its address is used to listen for immigrants.
+    pub index: usize,
+    /// Specifies how islands are connected. Default is ring.
+    pub topology: Option<IslandTopologyType>,
+    /// An amount of generations between two migrations. Default is 100.
+    pub migration_interval: Option<usize>,
+    /// A max amount of best known solutions sent on each migration. Default is 1.
+    pub migration_size: Option<usize>,
+}
+
+/// An island model topology.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum IslandTopologyType {
+    /// Each island sends solutions to the next one.
+    #[serde(rename(deserialize = "ring"))]
+    Ring,
+
+    /// Each island sends solutions to all other islands.
+    #[serde(rename(deserialize = "fully-connected"))]
+    FullyConnected,
 }
 
 /// A operator configuration.
@@ -164,6 +244,22 @@ pub enum SearchOperatorType {
         operators: Vec<LocalOperatorType>,
     },
 
+    /// A guided local search which runs local search with objective augmented by penalties of
+    /// frequently used long edges.
+    #[serde(rename(deserialize = "guided-local-search"))]
+    GuidedLocalSearch {
+        /// Probability of operator.
+        probability: OperatorProbabilityType,
+        /// Amount of times one of operators is applied.
+        times: MinMaxConfig,
+        /// Local search operator.
+        operators: Vec<LocalOperatorType>,
+        /// Penalty factor relative to the average edge distance. Default is 0.3.
+        alpha: Option<f64>,
+        /// Decay factor applied to penalties after each search. Default is 0.99.
+        decay: Option<f64>,
+    },
+
     /// A ruin and recreate metaheuristic settings.
     #[serde(rename(deserialize = "ruin-recreate"))]
     RuinRecreate {
@@ -267,6 +363,12 @@ pub enum RuinMethod {
     #[serde(rename(deserialize = "cluster"))]
     #[serde(rename_all = "camelCase")]
     Cluster { probability: f64, min: usize, max: usize, min_items: usize },
+    /// Removes jobs from routes with the least time window slack.
+    #[serde(rename(deserialize = "time-slack"))]
+    TimeSlack { probability: f64, min: usize, max: usize },
+    /// Removes routes which convex hulls overlap other routes the most.
+    #[serde(rename(deserialize = "overlap-route"))]
+    OverlapRoute { probability: f64 },
 }
 
 /// Specifies recreate methods with their probability weight and specific parameters.
@@ -323,6 +425,18 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt-star"))]
+    TwoOptStar { weight: usize },
+
+    #[serde(rename(deserialize = "cross"))]
+    Cross { weight: usize },
+
+    #[serde(rename(deserialize = "or-opt"))]
+    OrOpt { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt"))]
+    TwoOpt { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -432,6 +546,44 @@ pub struct OutputConfig {
     pub include_geojson: Option<bool>,
 }
 
+fn configure_from_granularity(
+    problem: Arc<Problem>,
+    solutions: Vec<InsertionContext>,
+    evolution_config: &Option<EvolutionConfig>,
+) -> Result<(Arc<Problem>, Vec<InsertionContext>), GenericError> {
+    match evolution_config.as_ref().and_then(|config| config.granularity) {
+        Some(neighbours) => {
+            let problem = with_granularity(problem, neighbours)?;
+            let solutions = solutions
+                .into_iter()
+                .map(|insertion_ctx| InsertionContext { problem: problem.clone(), ..insertion_ctx })
+                .collect();
+
+            Ok((problem, solutions))
+        }
+        None => Ok((problem, solutions)),
+    }
+}
+
+/// Returns a problem which uses granular insertion evaluation with given amount of job's nearest neighbours.
+pub fn with_granularity(problem: Arc<Problem>, neighbours: usize) -> Result<Arc<Problem>, GenericError> {
+    if neighbours == 0 {
+        return Err(format!("granularity must be an integer bigger than 0, got '{neighbours}'").into());
+    }
+
+    let extras = ExtrasBuilder::from(problem.extras.as_ref()).with_granularity(neighbours).build()?;
+
+    Ok(Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        goal: problem.goal.clone(),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: Arc::new(extras),
+    }))
+}
+
 fn configure_from_evolution(
     mut builder: ProblemConfigBuilder,
     problem: Arc<Problem>,
@@ -531,32 +683,157 @@ fn configure_from_hyper(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
     hyper_config: &Option<HyperType>,
+    island_config: &Option<IslandModelConfig>,
 ) -> Result<ProblemConfigBuilder, GenericError> {
-    if let Some(config) = hyper_config {
-        match config {
-            HyperType::StaticSelective { operators } => {
-                let static_selective = if let Some(operators) = operators {
-                    let heuristic_group = operators
-                        .iter()
-                        .map(|operator| create_operator(problem.clone(), environment.clone(), operator))
-                        .collect::<Result<Vec<_>, _>>()?;
-                    get_static_heuristic_from_heuristic_group(problem.clone(), environment.clone(), heuristic_group)
-                } else {
-                    get_static_heuristic(problem, environment)
-                };
-
-                builder = builder.with_heuristic(Box::new(static_selective));
-            }
-            HyperType::DynamicSelective => {
-                let dynamic_selective = get_dynamic_heuristic(problem, environment);
-                builder = builder.with_heuristic(Box::new(dynamic_selective));
+    let heuristic: Option<TargetHeuristic> = match hyper_config {
+        Some(HyperType::StaticSelective { operators }) => {
+            let static_selective = if let Some(operators) = operators {
+                let heuristic_group = operators
+                    .iter()
+                    .map(|operator| create_operator(problem.clone(), environment.clone(), operator))
+                    .collect::<Result<Vec<_>, _>>()?;
+                get_static_heuristic_from_heuristic_group(problem.clone(), environment.clone(), heuristic_group)
+            } else {
+                get_static_heuristic(problem.clone(), environment.clone())
+            };
+
+            Some(Box::new(static_selective))
+        }
+        Some(HyperType::DynamicSelective { priors }) => {
+            let dynamic_selective = get_dynamic_heuristic(problem.clone(), environment.clone());
+            let dynamic_selective = if let Some(path) = priors {
+                dynamic_selective.with_priors(read_operator_priors(path)?.as_slice())
+            } else {
+                dynamic_selective
+            };
+
+            Some(Box::new(dynamic_selective))
+        }
+        Some(HyperType::Alns { acceptance, segment_size, reaction_factor }) => {
+            if island_config.is_some() {
+                return Err("island model is not supported with alns".into());
             }
+
+            let config = create_alns_config(acceptance.as_ref(), *segment_size, *reaction_factor)?;
+
+            let alns = get_alns_strategy(problem, environment, config);
+            return Ok(builder.with_strategy(Box::new(alns)));
         }
+        None => None,
+    };
+
+    let heuristic = if let Some(island_config) = island_config {
+        let heuristic = heuristic.unwrap_or_else(|| get_default_heuristic(problem.clone(), environment.clone()));
+        Some(create_island_heuristic(problem, environment, heuristic, island_config)?)
+    } else {
+        heuristic
+    };
+
+    if let Some(heuristic) = heuristic {
+        builder = builder.with_heuristic(heuristic);
     }
 
     Ok(builder)
 }
 
+fn create_alns_config(
+    acceptance: Option<&AlnsAcceptanceType>,
+    segment_size: Option<usize>,
+    reaction_factor: Option<f64>,
+) -> Result<AlnsConfig, GenericError> {
+    let default = AlnsConfig::default();
+
+    let acceptance = match acceptance {
+        Some(AlnsAcceptanceType::SimulatedAnnealing { initial_temperature, final_temperature }) => {
+            let (initial_temperature, final_temperature) = (*initial_temperature, *final_temperature);
+            if final_temperature <= 0. || initial_temperature < final_temperature {
+                return Err(format!(
+                    "temperatures must be positive and initial temperature must not be less than final one. \
+                     Specified: {initial_temperature}, {final_temperature}"
+                )
+                .into());
+            }
+
+            AlnsAcceptance::SimulatedAnnealing { initial_temperature, final_temperature }
+        }
+        Some(AlnsAcceptanceType::RecordToRecord { deviation }) => {
+            if *deviation < 0. {
+                return Err(format!("deviation must be non-negative. Specified: {deviation}").into());
+            }
+
+            AlnsAcceptance::RecordToRecord { deviation: *deviation }
+        }
+        None => default.acceptance,
+    };
+
+    let segment_size = segment_size.unwrap_or(default.segment_size);
+    if segment_size == 0 {
+        return Err("segment size must be positive. Specified: 0".into());
+    }
+
+    let reaction_factor = reaction_factor.unwrap_or(default.reaction_factor);
+    if !(0. ..=1.).contains(&reaction_factor) {
+        return Err(format!("reaction factor must be in [0, 1] range. Specified: {reaction_factor}").into());
+    }
+
+    Ok(AlnsConfig { acceptance, segment_size, reaction_factor, ..default })
+}
+
+fn create_island_heuristic(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    heuristic: TargetHeuristic,
+    config: &IslandModelConfig,
+) -> Result<TargetHeuristic, GenericError> {
+    let address = config
+        .islands
+        .get(config.index)
+        .ok_or_else(|| format!("island index {} is out of range of {} islands", config.index, config.islands.len()))?;
+    let listener = TcpListener::bind(address).map_err(|err| format!("cannot listen on '{address}': {err}"))?;
+
+    let topology = match config.topology {
+        Some(IslandTopologyType::FullyConnected) => IslandTopology::FullyConnected,
+        Some(IslandTopologyType::Ring) | None => IslandTopology::Ring,
+    };
+    let neighbours = topology.get_neighbours(config.islands.as_slice(), config.index);
+
+    let default = IslandConfig::default();
+    let island_config = IslandConfig {
+        migration_interval: config.migration_interval.unwrap_or(default.migration_interval),
+        migration_size: config.migration_size.unwrap_or(default.migration_size),
+    };
+    if island_config.migration_interval == 0 {
+        return Err("island migration interval should be greater than zero".into());
+    }
+
+    let migration = TcpMigration::new(problem, environment, listener, neighbours)?;
+
+    Ok(Box::new(IslandHeuristic::new(heuristic, Box::new(migration), island_config)))
+}
+
+fn read_operator_priors(path: &str) -> Result<Vec<SearchOperatorStatistics>, GenericError> {
+    let file = File::open(path).map_err(|err| format!("cannot open priors file '{path}': {err}"))?;
+    let solution = deserialize_solution(BufReader::new(file))
+        .map_err(|err| format!("cannot read priors from solution '{path}': {err}"))?;
+
+    Ok(solution
+        .extras
+        .and_then(|extras| extras.metrics)
+        .and_then(|metrics| metrics.operators)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|o| SearchOperatorStatistics {
+            state: o.state,
+            name: o.name,
+            alpha: o.alpha,
+            beta: o.beta,
+            mu: o.mu,
+            v: o.v,
+            n: o.n,
+        })
+        .collect())
+}
+
 fn configure_from_termination(
     mut builder: ProblemConfigBuilder,
     termination_config: &Option<TerminationConfig>,
@@ -621,6 +898,22 @@ fn create_operator(
             let operator = create_local_search(times, inners, environment.random.clone());
             (Arc::new(LocalSearch::new(operator)), create_operator_probability(probability, environment.random.clone()))
         }
+        SearchOperatorType::GuidedLocalSearch { probability, times, operators: inners, alpha, decay } => {
+            let alpha = alpha.unwrap_or(0.3);
+            let decay = decay.unwrap_or(0.99);
+            if alpha <= 0. {
+                return Err(format!("alpha must be positive. Specified: {alpha}").into());
+            }
+            if decay <= 0. || decay > 1. {
+                return Err(format!("decay must be in (0, 1] range. Specified: {decay}").into());
+            }
+
+            let operator = create_local_search(times, inners, environment.random.clone());
+            (
+                Arc::new(GuidedLocalSearch::new(Arc::new(LocalSearch::new(operator)), alpha, decay)),
+                create_operator_probability(probability, environment.random.clone()),
+            )
+        }
         SearchOperatorType::Decomposition { routes, repeat, probability } => {
             if *repeat < 1 {
                 return Err(format!("repeat must be greater than 1. Specified: {repeat}").into());
@@ -697,6 +990,10 @@ fn create_ruin_method(
         ),
         RuinMethod::CloseRoute { probability } => (Arc::new(CloseRouteRemoval::new(limits)), *probability),
         RuinMethod::WorstRoute { probability } => (Arc::new(WorstRouteRemoval::new(limits)), *probability),
+        RuinMethod::TimeSlack { probability, min, max } => {
+            (Arc::new(TimeSlackRemoval::new(get_limits(*min, *max))), *probability)
+        }
+        RuinMethod::OverlapRoute { probability } => (Arc::new(OverlapRouteRemoval::new(limits)), *probability),
     }
 }
 
@@ -719,6 +1016,10 @@ fn create_local_search(
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::TwoOptStar { weight } => (Arc::new(ExchangeTwoOptStar::default()), *weight),
+            LocalOperatorType::Cross { weight } => (Arc::new(ExchangeCross::default()), *weight),
+            LocalOperatorType::OrOpt { weight } => (Arc::new(ExchangeOrOpt::default()), *weight),
+            LocalOperatorType::TwoOpt { weight } => (Arc::new(ExchangeTwoOpt::default()), *weight),
         })
         .collect::<Vec<_>>();
 
@@ -816,6 +1117,7 @@ pub fn create_builder_from_config(
     solutions: Vec<InsertionContext>,
     config: &Config,
 ) -> Result<ProblemConfigBuilder, GenericError> {
+    let (problem, solutions) = configure_from_granularity(problem, solutions, &config.evolution)?;
     let environment =
         configure_from_environment(&config.environment, config.termination.as_ref().and_then(|t| t.max_time));
     let telemetry_mode = get_telemetry_mode(environment.clone(), &config.telemetry);
@@ -824,7 +1126,7 @@ pub fn create_builder_from_config(
 
     builder =
         configure_from_evolution(builder, problem.clone(), environment.clone(), telemetry_mode, &config.evolution)?;
-    builder = configure_from_hyper(builder, problem, environment, &config.hyper)?;
+    builder = configure_from_hyper(builder, problem, environment, &config.hyper, &config.island)?;
     builder = configure_from_termination(builder, &config.termination);
 
     Ok(builder)
@@ -0,0 +1,204 @@
+//! Contains an island model implementation which exchanges solutions between solver processes using
+//! TCP sockets and pragmatic format.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/island_test.rs"]
+mod island_test;
+
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::hyper::Migration;
+use vrp_core::rosomaxa::prelude::HeuristicSolution;
+use vrp_pragmatic::format::solution::{read_init_solution, write_pragmatic, PragmaticOutputType};
+
+/// A max size of the received solution payload in bytes.
+const MAX_PAYLOAD_SIZE: u64 = 32 * 1024 * 1024;
+/// A max amount of received solutions which are kept until next immigration.
+const MAX_INBOX_SIZE: usize = 16;
+/// A timeout used for reading and writing solution payloads.
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+/// An interval used by listener to check whether it has to be stopped.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Specifies how islands are connected to each other.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IslandTopology {
+    /// Each island sends solutions only to the next one, the last island sends them to the first.
+    Ring,
+    /// Each island sends solutions to all other islands.
+    FullyConnected,
+}
+
+impl IslandTopology {
+    /// Returns addresses of neighbours of the island with given index.
+    pub fn get_neighbours(&self, islands: &[String], index: usize) -> Vec<String> {
+        match self {
+            _ if islands.len() < 2 => vec![],
+            IslandTopology::Ring => vec![islands[(index + 1) % islands.len()].clone()],
+            IslandTopology::FullyConnected => islands
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| *idx != index)
+                .map(|(_, address)| address.clone())
+                .collect(),
+        }
+    }
+}
+
+/// A migration which sends solutions serialized in pragmatic format to neighbour islands over TCP
+/// and listens for solutions sent by other islands. Each connection carries exactly one solution.
+/// Received payloads are limited in size and amount, the listener is stopped when migration is dropped.
+pub struct TcpMigration {
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    neighbours: Vec<String>,
+    received: Arc<Mutex<Vec<Vec<u8>>>>,
+    is_stopped: Arc<AtomicBool>,
+    listener: Option<JoinHandle<()>>,
+}
+
+impl TcpMigration {
+    /// Creates a new instance of `TcpMigration` which accepts immigrants using given listener
+    /// and sends emigrants to given neighbours.
+    pub fn new(
+        problem: Arc<Problem>,
+        environment: Arc<Environment>,
+        listener: TcpListener,
+        neighbours: Vec<String>,
+    ) -> Result<Self, GenericError> {
+        let received = Arc::new(Mutex::new(Vec::default()));
+        let is_stopped = Arc::new(AtomicBool::new(false));
+
+        // NOTE listener is polled to be able to stop it and release the socket when search is finished
+        listener.set_nonblocking(true).map_err(|err| format!("cannot configure island listener: {err}"))?;
+
+        let logger = environment.logger.clone();
+        let inbox = received.clone();
+        let should_stop = is_stopped.clone();
+        let handle = thread::Builder::new()
+            .name("island-listener".to_string())
+            .spawn(move || {
+                while !should_stop.load(Ordering::Relaxed) {
+                    match listener.accept() {
+                        Ok((stream, _)) => match receive_payload(stream) {
+                            Ok(payload) => {
+                                let mut inbox = inbox.lock().unwrap();
+                                if inbox.len() < MAX_INBOX_SIZE {
+                                    inbox.push(payload)
+                                } else {
+                                    (logger)("cannot receive solution from island: inbox is full")
+                                }
+                            }
+                            Err(err) => (logger)(&format!("cannot receive solution from island: {err}")),
+                        },
+                        Err(err) if err.kind() == ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+                        Err(err) => (logger)(&format!("cannot accept island connection: {err}")),
+                    }
+                }
+            })
+            .map_err(|err| format!("cannot start island listener: {err}"))?;
+
+        Ok(Self { problem, environment, neighbours, received, is_stopped, listener: Some(handle) })
+    }
+}
+
+impl Drop for TcpMigration {
+    fn drop(&mut self) {
+        self.is_stopped.store(true, Ordering::Relaxed);
+
+        if let Some(handle) = self.listener.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Migration for TcpMigration {
+    type Solution = InsertionContext;
+
+    fn emigrate(&self, solutions: Vec<&Self::Solution>) {
+        if self.neighbours.is_empty() {
+            return;
+        }
+
+        let solutions = solutions.into_iter().map(|insertion_ctx| Solution::from(insertion_ctx.deep_copy()));
+        let payloads = solutions
+            .filter_map(|solution| {
+                let mut writer = BufWriter::new(Vec::new());
+                write_pragmatic(self.problem.as_ref(), &solution, PragmaticOutputType::OnlyPragmatic, &mut writer)
+                    .ok()
+                    .and_then(|_| writer.into_inner().ok())
+            })
+            .collect::<Vec<_>>();
+
+        let neighbours = self.neighbours.clone();
+        let logger = self.environment.logger.clone();
+
+        // NOTE send in background as neighbours can be slow or temporary unavailable
+        thread::spawn(move || {
+            neighbours.iter().for_each(|address| {
+                payloads.iter().for_each(|payload| {
+                    if let Err(err) = send_payload(address, payload) {
+                        (logger)(&format!("cannot send solution to island '{address}': {err}"))
+                    }
+                })
+            })
+        });
+    }
+
+    fn immigrate(&self) -> Vec<Self::Solution> {
+        let payloads = std::mem::take(&mut *self.received.lock().unwrap());
+
+        payloads
+            .into_iter()
+            .filter_map(|payload| {
+                let random = self.environment.random.clone();
+                match read_init_solution(BufReader::new(payload.as_slice()), self.problem.clone(), random) {
+                    Ok(solution) => Some(InsertionContext::new_from_solution(
+                        self.problem.clone(),
+                        (solution, None),
+                        self.environment.clone(),
+                    )),
+                    Err(err) => {
+                        (self.environment.logger)(&format!("cannot read solution from island: {err}"));
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+fn receive_payload(stream: TcpStream) -> std::io::Result<Vec<u8>> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+
+    let mut payload = Vec::default();
+    BufReader::new(stream).take(MAX_PAYLOAD_SIZE + 1).read_to_end(&mut payload)?;
+
+    if payload.len() as u64 > MAX_PAYLOAD_SIZE {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            format!("payload exceeds the limit of {MAX_PAYLOAD_SIZE} bytes"),
+        ));
+    }
+
+    Ok(payload)
+}
+
+fn send_payload(address: &str, payload: &[u8]) -> std::io::Result<()> {
+    let address = address
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| std::io::Error::new(ErrorKind::InvalidInput, "cannot resolve address"))?;
+
+    let mut stream = TcpStream::connect_timeout(&address, IO_TIMEOUT)?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    stream.write_all(payload)?;
+    stream.flush()
+}
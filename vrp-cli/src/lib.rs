@@ -39,7 +39,7 @@ use crate::extensions::solve::config::{create_builder_from_config, Config};
 use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
-use vrp_core::prelude::{GenericError, Solver};
+use vrp_core::prelude::{GenericError, Solution, Solver};
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::{write_pragmatic, PragmaticOutputType};
 use vrp_pragmatic::format::FormatError;
@@ -516,23 +516,25 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
         .and_then(|builder| builder.build())
         .map(|config| Solver::new(problem.clone(), config))
         .and_then(|solver| solver.solve())
-        .map_err(|err| {
-            FormatError::new(
-                "E0003".to_string(),
-                "cannot find any solution".to_string(),
-                format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
-            )
-            .to_json()
-        })?;
-
-    let output_type = if config.output.and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
+        .map_err(serialize_as_solver_error)?;
+
+    serialize_solution(problem.as_ref(), &solution, &config)
+}
+
+/// Serializes solution in pragmatic format using output settings from config.
+pub(crate) fn serialize_solution(
+    problem: &CoreProblem,
+    solution: &Solution,
+    config: &Config,
+) -> Result<String, GenericError> {
+    let output_type = if config.output.as_ref().and_then(|output_cfg| output_cfg.include_geojson).unwrap_or(false) {
         PragmaticOutputType::Combined
     } else {
         Default::default()
     };
 
     let mut writer = BufWriter::new(Vec::new());
-    write_pragmatic(problem.as_ref(), &solution, output_type, &mut writer)?;
+    write_pragmatic(problem, solution, output_type, &mut writer)?;
 
     let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
     let result = String::from_utf8(bytes).map_err(|err| format!("{err}"))?;
@@ -540,6 +542,16 @@ pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Res
     Ok(result)
 }
 
+pub(crate) fn serialize_as_solver_error(err: GenericError) -> GenericError {
+    FormatError::new(
+        "E0003".to_string(),
+        "cannot find any solution".to_string(),
+        format!("please submit a bug and share original problem and routing matrix. Error: '{err}'"),
+    )
+    .to_json()
+    .into()
+}
+
 fn serialize_as_config_error(err: &str) -> String {
     FormatError::new(
         "E0004".to_string(),
@@ -19,7 +19,10 @@ mod cli {
     use crate::commands::analyze::{get_analyze_app, run_analyze};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::create_write_buffer;
+    use crate::commands::diff::{get_diff_app, run_diff};
     use crate::commands::generate::{get_generate_app, run_generate};
+    use crate::commands::report::{get_report_app, run_report};
+    use crate::commands::serve::{get_serve_app, run_serve};
     use clap::{ArgMatches, Command};
     use std::process;
 
@@ -36,7 +39,10 @@ mod cli {
             .subcommand(get_solve_app())
             .subcommand(get_import_app())
             .subcommand(get_check_app())
+            .subcommand(get_diff_app())
             .subcommand(get_generate_app())
+            .subcommand(get_report_app())
+            .subcommand(get_serve_app())
     }
 
     pub fn run_subcommand(arg_matches: ArgMatches) {
@@ -45,7 +51,10 @@ mod cli {
             Some(("solve", solve_matches)) => run_solve(solve_matches, create_write_buffer),
             Some(("import", import_matches)) => run_import(import_matches),
             Some(("check", check_matches)) => run_check(check_matches),
+            Some(("diff", diff_matches)) => run_diff(diff_matches),
             Some(("generate", generate_matches)) => run_generate(generate_matches),
+            Some(("report", report_matches)) => run_report(report_matches, create_write_buffer),
+            Some(("serve", serve_matches)) => run_serve(serve_matches),
             _ => {
                 eprintln!("no subcommand was used. Use -h to print help information.");
                 process::exit(1);
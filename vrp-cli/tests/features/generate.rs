@@ -1,4 +1,4 @@
-use crate::extensions::generate::generate_problem;
+use crate::extensions::generate::{generate_problem, GenerateOptions};
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::BufReader;
@@ -9,8 +9,9 @@ use vrp_pragmatic::validation::ValidationContext;
 #[test]
 fn can_generate_problem_from_simple_prototype() {
     let reader = BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap());
-    let problem =
-        generate_problem("pragmatic", Some(vec![reader]), None, 50, 4, None).map_err(|err| panic!("{}", err)).unwrap();
+    let problem = generate_problem("pragmatic", Some(vec![reader]), None, &GenerateOptions::new(50, 4))
+        .map_err(|err| panic!("{}", err))
+        .unwrap();
     let coord_index = CoordIndex::new(&problem);
 
     ValidationContext::new(&problem, None, &coord_index).validate().map_err(|errs| panic!("{errs}")).unwrap();
@@ -28,8 +29,13 @@ fn can_generate_problem_with_locations_file() {
     let locations =
         deserialize_locations(get_location_reader()).expect("cannot get locations").into_iter().collect::<HashSet<_>>();
 
-    let problem = generate_problem("pragmatic", Some(vec![problem_reader]), Some(get_location_reader()), 50, 4, None)
-        .expect("cannot generate problem");
+    let problem = generate_problem(
+        "pragmatic",
+        Some(vec![problem_reader]),
+        Some(get_location_reader()),
+        &GenerateOptions::new(50, 4),
+    )
+    .expect("cannot generate problem");
 
     assert!(problem.plan.jobs.iter().all(|job| {
         job.pickups
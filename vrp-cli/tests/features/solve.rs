@@ -1,7 +1,9 @@
-use crate::extensions::solve::config::{create_builder_from_config, read_config};
+use crate::extensions::solve::config::*;
 use std::fs::File;
 use std::io::BufReader;
+use std::net::TcpListener;
 use std::sync::Arc;
+use std::thread;
 use vrp_core::prelude::Solver;
 use vrp_pragmatic::format::problem::PragmaticProblem;
 
@@ -34,3 +36,53 @@ fn can_solve_problem_using_full_config() {
 
     assert!(!solution.routes.is_empty())
 }
+
+#[test]
+fn can_solve_problem_using_island_model() {
+    let problem = Arc::new(
+        BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap())
+            .read_pragmatic()
+            .unwrap(),
+    );
+    let islands =
+        (0..2).map(|_| TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().to_string()).collect::<Vec<_>>();
+
+    let solutions = thread::scope(|scope| {
+        let handles = (0..islands.len())
+            .map(|index| {
+                let problem = problem.clone();
+                let islands = islands.clone();
+                scope.spawn(move || {
+                    let config = Config {
+                        island: Some(IslandModelConfig {
+                            islands,
+                            index,
+                            topology: Some(IslandTopologyType::FullyConnected),
+                            migration_interval: Some(1),
+                            migration_size: Some(1),
+                        }),
+                        termination: Some(TerminationConfig {
+                            max_time: None,
+                            max_generations: Some(10),
+                            variation: None,
+                        }),
+                        ..Config::default()
+                    };
+
+                    create_builder_from_config(problem.clone(), Default::default(), &config)
+                        .unwrap()
+                        .build()
+                        .map(|config| Solver::new(problem.clone(), config))
+                        .unwrap()
+                        .solve()
+                        .unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<_>>()
+    });
+
+    assert_eq!(solutions.len(), 2);
+    assert!(solutions.iter().all(|solution| !solution.routes.is_empty()));
+}
@@ -9,6 +9,7 @@ pub fn create_empty_job() -> Job {
         replacements: None,
         services: None,
         skills: None,
+        preferences: None,
         value: None,
         group: None,
         compatibility: None,
@@ -24,7 +25,7 @@ pub fn create_empty_job_place() -> JobPlace {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, territories: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -32,7 +33,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+        costs: VehicleCosts { fixed: None, distance: 1., time: 0., time_bands: None, load_factors: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
@@ -47,6 +48,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         capacity: vec![10],
         skills: None,
         limits: None,
+        territories: None,
     }
 }
 
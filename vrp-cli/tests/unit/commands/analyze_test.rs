@@ -1,6 +1,8 @@
 use super::*;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
 
 struct DummyWrite {}
 
@@ -36,3 +38,58 @@ fn can_detect_wrong_argument() {
 
     assert!(get_analyze_app().try_get_matches_from(args).is_err());
 }
+
+#[test]
+fn can_run_analyze_unassigned() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "analyze",
+        "unassigned",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--time-step",
+        "600",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
+
+#[test]
+fn can_reject_invalid_unassigned_options() {
+    let args = vec![
+        "analyze",
+        "unassigned",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--max-steps",
+        "0",
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    assert!(run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).is_err());
+}
+
+#[test]
+fn can_run_analyze_feasibility() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "analyze",
+        "feasibility",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+#[test]
+fn can_compare_solutions_from_args() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "diff",
+        "pragmatic",
+        "--solutions",
+        PRAGMATIC_SOLUTION_PATH,
+        PRAGMATIC_SOLUTION_PATH,
+        "--problems",
+        PRAGMATIC_PROBLEM_PATH,
+        PRAGMATIC_PROBLEM_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_diff_app().try_get_matches_from(args).unwrap();
+
+    run_diff(&matches).unwrap();
+
+    let diff: serde_json::Value = serde_json::from_reader(BufReader::new(tmpfile.as_file())).unwrap();
+    assert_eq!(diff["cost"]["delta"], 0.);
+    assert_eq!(diff["tours"][0]["change"], "unchanged");
+    assert_eq!(diff["problem"]["jobs"]["changed"], serde_json::json!([]));
+}
+
+#[test]
+fn can_require_two_solutions() {
+    let args = vec!["diff", "pragmatic", "--solutions", PRAGMATIC_SOLUTION_PATH];
+
+    assert!(get_diff_app().try_get_matches_from(args).is_err());
+}
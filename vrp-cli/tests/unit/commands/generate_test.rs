@@ -26,3 +26,42 @@ fn can_generate_problem_from_args() {
     assert_eq!(problem.jobs.size(), 100);
     assert_eq!(problem.fleet.vehicles.len(), 10);
 }
+
+#[test]
+fn can_generate_problem_with_distribution_and_seed() {
+    let args = vec![
+        "generate",
+        "pragmatic",
+        "--prototypes",
+        PRAGMATIC_PROBLEM_PATH,
+        "--jobs-size",
+        "50",
+        "--vehicles-size",
+        "3",
+        "--distribution",
+        "random-clustered",
+        "--clusters",
+        "4",
+        "--tw-width",
+        "uniform:1800,7200",
+        "--pickup-delivery-ratio",
+        "0.3",
+        "--skills",
+        "2",
+        "--breaks",
+        "--reloads",
+        "2",
+        "--seed",
+        "42",
+    ];
+    let matches = get_generate_app().try_get_matches_from(args).unwrap();
+
+    let (problem, _) = generate_problem_from_args(&matches).unwrap();
+
+    assert_eq!(problem.plan.jobs.len(), 50);
+    assert_eq!(problem.fleet.vehicles.len(), 3);
+    assert!(problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).all(|shift| {
+        shift.breaks.as_ref().is_some_and(|breaks| breaks.len() == 1)
+            && shift.reloads.as_ref().is_some_and(|reloads| reloads.len() == 2)
+    }));
+}
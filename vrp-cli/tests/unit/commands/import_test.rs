@@ -24,3 +24,50 @@ fn can_import_csv_problem_from_args() {
     assert_eq!(problem.jobs.size(), 3);
     assert_eq!(problem.fleet.vehicles.len(), 30);
 }
+
+#[test]
+fn can_import_csv_problem_with_custom_columns_from_args() {
+    let columns_file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(columns_file.path(), r#"{ "jobs": { "demand": "WEIGHT" } }"#).unwrap();
+    let jobs_file = tempfile::NamedTempFile::new().unwrap();
+    std::fs::write(jobs_file.path(), "ID,LAT,LNG,WEIGHT,DURATION\njob1,52.52599,13.45413,-2,300\n").unwrap();
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "import",
+        "csv",
+        "--input-files",
+        jobs_file.path().to_str().unwrap(),
+        VEHICLES_JOBS_PATH,
+        "--csv-columns",
+        columns_file.path().to_str().unwrap(),
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_import_app().try_get_matches_from(args).unwrap();
+
+    run_import(&matches).unwrap();
+
+    let problem = BufReader::new(tmpfile.as_file()).read_pragmatic().unwrap();
+    assert_eq!(problem.jobs.size(), 1);
+}
+
+#[test]
+fn can_convert_solution_to_csv_from_args() {
+    let dir = tempfile::tempdir().unwrap();
+    let args = vec![
+        "import",
+        "solution",
+        "--input-files",
+        "../examples/data/pragmatic/simple.basic.solution.json",
+        "--out-result",
+        dir.path().to_str().unwrap(),
+    ];
+    let matches = get_import_app().try_get_matches_from(args).unwrap();
+
+    run_import(&matches).unwrap();
+
+    let activities = std::fs::read_to_string(dir.path().join("activities.csv")).unwrap();
+    assert!(activities.starts_with("VEHICLE_ID"));
+    assert!(dir.path().join("tours.csv").exists());
+    assert!(dir.path().join("unassigned.csv").exists());
+}
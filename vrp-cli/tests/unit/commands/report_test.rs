@@ -0,0 +1,33 @@
+use super::*;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+#[test]
+fn can_render_report_from_args() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "report",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_report_app().try_get_matches_from(args).unwrap();
+
+    run_report(&matches, |data| BufWriter::new(Box::new(data.unwrap()))).unwrap();
+
+    let report = std::fs::read_to_string(tmpfile.path()).unwrap();
+    assert!(report.starts_with("<!DOCTYPE html>"));
+    assert!(report.contains("vehicle_1"));
+}
+
+#[test]
+fn can_require_solution_file() {
+    let args = vec!["report", "pragmatic", "--problem-file", PRAGMATIC_PROBLEM_PATH];
+
+    assert!(get_report_app().try_get_matches_from(args).is_err());
+}
@@ -0,0 +1,19 @@
+use super::*;
+
+#[test]
+fn can_create_server_from_args() {
+    let args = vec!["serve", "--address", "127.0.0.1:0", "--workers", "2", "--queue-size", "4", "--max-finished", "8"];
+    let matches = get_serve_app().try_get_matches_from(args).unwrap();
+
+    let server = create_server(&matches).unwrap();
+
+    assert!(server.address().is_some());
+}
+
+#[test]
+fn can_return_error_for_invalid_workers() {
+    let args = vec!["serve", "--address", "127.0.0.1:0", "--workers", "0"];
+    let matches = get_serve_app().try_get_matches_from(args).unwrap();
+
+    assert!(create_server(&matches).is_err());
+}
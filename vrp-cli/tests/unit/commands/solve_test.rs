@@ -1,6 +1,7 @@
 use super::*;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
 const SOLOMON_PROBLEM_PATH: &str = "../examples/data/scientific/solomon/C101.25.txt";
 const LILIM_PROBLEM_PATH: &str = "../examples/data/scientific/lilim/LC101.txt";
 
@@ -47,6 +48,90 @@ fn can_solve_solomon_problem_with_generation_limit() {
     run_solve_with_out_writer(&get_solomon_matches(&["--max-generations", "1"]));
 }
 
+#[test]
+fn can_solve_pragmatic_problem_with_csv_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let out_dir = dir.path().to_str().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "1",
+        "--out-format",
+        "csv",
+        "--out-result",
+        out_dir,
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let activities = std::fs::read_to_string(dir.path().join("activities.csv")).unwrap();
+    assert_eq!(activities.lines().count(), 7);
+}
+
+#[test]
+fn can_reject_csv_output_for_non_pragmatic_format() {
+    let matches = get_solomon_matches(&["--max-generations", "1", "--out-format", "csv", "--out-result", "out"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err());
+}
+
+fn write_problem_with_objectives(dir: &std::path::Path, objectives: serde_json::Value) -> String {
+    let mut problem: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap()).unwrap();
+    problem["objectives"] = objectives;
+
+    let path = dir.join("problem.json");
+    std::fs::write(&path, problem.to_string()).unwrap();
+
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_reference_solution() {
+    let dir = tempfile::tempdir().unwrap();
+    let objectives = serde_json::json!([
+        [{ "type": "minimize-unassigned" }],
+        [{ "type": "minimize-changes" }],
+        [{ "type": "minimize-cost" }]
+    ]);
+    let problem_path = write_problem_with_objectives(dir.path(), objectives);
+    let args = vec![
+        "solve",
+        "pragmatic",
+        problem_path.as_str(),
+        "--max-generations",
+        "1",
+        "--reference-solution",
+        PRAGMATIC_SOLUTION_PATH,
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_reject_reference_solution_without_minimize_changes_objective() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "1",
+        "--reference-solution",
+        PRAGMATIC_SOLUTION_PATH,
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err());
+}
+
 #[test]
 fn can_require_problem_path() {
     for format in &["pragmatic", "solomon", "lilim", "tsplib"] {
@@ -131,6 +216,27 @@ fn can_use_init_size() {
     }
 }
 
+#[test]
+fn can_use_granularity() {
+    for (params, result) in [
+        (vec!["--granularity", "10"], Ok(Some(10))),
+        (vec!["--granularity", "0"], Err("granularity must be an integer bigger than 0, got '0'".into())),
+        (vec!["--granularity", "a"], Err("granularity must be an integer bigger than 0, got 'a'".into())),
+        (vec![], Ok(None)),
+    ] {
+        let matches = get_solomon_matches(params.as_slice());
+
+        let granularity = get_granularity(&matches);
+
+        assert_eq!(granularity, result);
+    }
+}
+
+#[test]
+fn can_solve_solomon_problem_with_granularity() {
+    run_solve_with_out_writer(&get_solomon_matches(&["--max-generations", "1", "--granularity", "5"]));
+}
+
 #[test]
 fn can_specify_cv() {
     for (params, result) in vec![
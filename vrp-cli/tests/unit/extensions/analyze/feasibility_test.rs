@@ -0,0 +1,112 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+
+fn get_problem() -> Problem {
+    deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap()
+}
+
+fn get_matrix() -> Matrix {
+    deserialize_matrix(BufReader::new(File::open(PRAGMATIC_MATRIX_PATH).unwrap())).unwrap()
+}
+
+fn create_delivery_job(id: &str, demand: i32, times: Option<(&str, &str)>, skill: Option<&str>) -> Job {
+    let mut place = serde_json::json!({ "location": { "lat": 52.5225, "lng": 13.4095 }, "duration": 300 });
+    if let Some((start, end)) = times {
+        place["times"] = serde_json::json!([[start, end]]);
+    }
+
+    let mut job = serde_json::json!({ "id": id, "deliveries": [{ "places": [place], "demand": [demand] }] });
+    if let Some(skill) = skill {
+        job["skills"] = serde_json::json!({ "allOf": [skill] });
+    }
+
+    serde_json::from_value(job).unwrap()
+}
+
+fn get_job<'a>(analysis: &'a FeasibilityAnalysis, job_id: &str) -> &'a JobFeasibility {
+    analysis.jobs.iter().find(|job| job.job_id == job_id).unwrap()
+}
+
+#[test]
+fn can_analyze_feasible_problem() {
+    let analysis = get_feasibility_analysis(&get_problem(), None).unwrap();
+
+    assert_eq!(analysis.jobs.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job1", "job2", "job3"]);
+    assert!(analysis.jobs.iter().all(|job| job.vehicles == 1 && job.reasons.is_empty()));
+    assert_eq!(analysis.infeasible_jobs().count(), 0);
+    assert_eq!(analysis.unreachable_entries, 0);
+    assert_eq!(analysis.vehicles, VehicleBound { available: 1, required: 1, capacity: Some(1), duration: Some(1) });
+}
+
+#[test]
+fn can_detect_infeasible_jobs() {
+    let mut problem = get_problem();
+    problem.plan.jobs.extend([
+        create_delivery_job("big", 11, None, None),
+        create_delivery_job("late", 1, Some(("2019-07-04T18:30:00Z", "2019-07-04T19:00:00Z")), None),
+        create_delivery_job("skilled", 1, None, Some("unknown")),
+    ]);
+
+    let analysis = get_feasibility_analysis(&problem, None).unwrap();
+
+    assert_eq!(analysis.infeasible_jobs().count(), 3);
+    assert_eq!(get_job(&analysis, "big").reasons, vec!["CAPACITY_CONSTRAINT".to_string()]);
+    assert_eq!(get_job(&analysis, "late").reasons, vec!["TIME_WINDOW_CONSTRAINT".to_string()]);
+    assert_eq!(get_job(&analysis, "skilled").reasons, vec!["SKILL_CONSTRAINT".to_string()]);
+    assert!(analysis.to_string().contains("jobs: 6, infeasible: 3"));
+}
+
+#[test]
+fn can_detect_unreachable_locations() {
+    let mut matrix = get_matrix();
+    matrix.error_codes = Some((0..16).map(|idx| if idx % 5 == 0 { 0 } else { 1 }).collect());
+
+    let analysis = get_feasibility_analysis(&get_problem(), Some(vec![matrix])).unwrap();
+
+    assert_eq!(analysis.unreachable_entries, 12);
+    assert_eq!(analysis.infeasible_jobs().count(), 3);
+    // NOTE job1 has time window which is checked before reachability
+    assert_eq!(get_job(&analysis, "job2").reasons, vec!["REACHABLE_CONSTRAINT".to_string()]);
+    assert_eq!(get_job(&analysis, "job3").reasons, vec!["REACHABLE_CONSTRAINT".to_string()]);
+    assert_eq!(analysis.vehicles.required, 0);
+}
+
+#[test]
+fn can_estimate_required_vehicles() {
+    let mut problem = get_problem();
+    problem.fleet.vehicles[0].vehicle_ids = vec!["v1".to_string(), "v2".to_string()];
+    problem.plan.jobs.extend((0..3).map(|idx| create_delivery_job(&format!("job{}", idx + 4), 8, None, None)));
+
+    let analysis = get_feasibility_analysis(&problem, None).unwrap();
+
+    assert_eq!(analysis.infeasible_jobs().count(), 0);
+    assert_eq!(analysis.vehicles.available, 2);
+    // NOTE total static delivery is 1 + 3 * 8 = 25 with capacity 10
+    assert_eq!(analysis.vehicles.capacity, Some(3));
+    assert_eq!(analysis.vehicles.required, 3);
+    assert!(analysis.to_string().contains("warning: not enough vehicles"));
+}
+
+#[test]
+fn can_estimate_required_vehicles_for_tight_pickups_and_deliveries() {
+    let mut problem = get_problem();
+    problem
+        .plan
+        .jobs
+        .iter_mut()
+        .flat_map(|job| job.pickups.iter_mut().chain(job.deliveries.iter_mut()).flatten())
+        .for_each(|task| {
+            task.demand = Some(vec![10]);
+        });
+
+    let analysis = get_feasibility_analysis(&problem, None).unwrap();
+
+    assert_eq!(analysis.vehicles.capacity, Some(1));
+    assert_eq!(analysis.vehicles.required, 1);
+    assert!(!analysis.to_string().contains("warning: not enough vehicles"));
+}
@@ -0,0 +1,135 @@
+use super::*;
+use std::fs::File;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::{deserialize_solution, UnassignedJob, UnassignedJobReason};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn get_problem_and_solution_with_job(job: serde_json::Value) -> (Problem, Solution) {
+    let mut problem = deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+    let mut solution = deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap();
+
+    let job: Job = serde_json::from_value(job).unwrap();
+    solution.unassigned = Some(vec![UnassignedJob {
+        job_id: job.id.clone(),
+        reasons: vec![UnassignedJobReason {
+            code: "NO_REASON_FOUND".to_string(),
+            description: "unknown".to_string(),
+            details: None,
+        }],
+    }]);
+    problem.plan.jobs.push(job);
+
+    (problem, solution)
+}
+
+fn create_delivery_job(demand: i32, times: Option<(&str, &str)>, skills: Option<&str>) -> serde_json::Value {
+    let mut place = serde_json::json!({ "location": { "lat": 52.5225, "lng": 13.4095 }, "duration": 300 });
+    if let Some((start, end)) = times {
+        place["times"] = serde_json::json!([[start, end]]);
+    }
+
+    let mut job = serde_json::json!({ "id": "job4", "deliveries": [{ "places": [place], "demand": [demand] }] });
+    if let Some(skill) = skills {
+        job["skills"] = serde_json::json!({ "allOf": [skill] });
+    }
+
+    job
+}
+
+fn get_relaxations(job: serde_json::Value) -> Vec<RelaxationResult> {
+    let (problem, solution) = get_problem_and_solution_with_job(job);
+
+    let analysis = get_unassigned_analysis(&problem, &solution, None, &RelaxationOptions::default()).unwrap();
+
+    assert_eq!(analysis.jobs.len(), 1);
+    assert_eq!(analysis.jobs[0].job_id, "job4");
+    assert_eq!(analysis.jobs[0].reasons, vec!["NO_REASON_FOUND".to_string()]);
+
+    analysis.jobs.into_iter().next().unwrap().relaxations
+}
+
+#[test]
+fn can_detect_job_insertable_without_relaxation() {
+    let relaxations = get_relaxations(create_delivery_job(1, None, None));
+
+    assert_eq!(relaxations.len(), 1);
+    assert_eq!(relaxations[0].relaxation, Relaxation::None);
+    assert!(relaxations[0].cost > 0.);
+}
+
+#[test]
+fn can_find_time_window_relaxation() {
+    let relaxations =
+        get_relaxations(create_delivery_job(1, Some(("2019-07-04T18:30:00Z", "2019-07-04T19:00:00Z")), None));
+
+    assert_eq!(relaxations.len(), 1);
+    match &relaxations[0].relaxation {
+        Relaxation::TimeWindow { seconds } => assert!(*seconds >= 1800. && *seconds <= 7200.),
+        relaxation => unreachable!("unexpected relaxation: {relaxation:?}"),
+    }
+}
+
+#[test]
+fn can_find_capacity_relaxation() {
+    let relaxations = get_relaxations(create_delivery_job(12, None, None));
+
+    assert_eq!(relaxations.len(), 1);
+    match &relaxations[0].relaxation {
+        Relaxation::Capacity { extra } => assert!(extra[0] >= 2 && extra[0] <= 12),
+        relaxation => unreachable!("unexpected relaxation: {relaxation:?}"),
+    }
+}
+
+#[test]
+fn can_find_skills_relaxation() {
+    let relaxations = get_relaxations(create_delivery_job(1, None, Some("unknown")));
+
+    assert_eq!(relaxations.len(), 1);
+    assert_eq!(relaxations[0].relaxation, Relaxation::Skills);
+}
+
+#[test]
+fn can_report_no_relaxation_found() {
+    let relaxations = get_relaxations(create_delivery_job(
+        12,
+        Some(("2019-07-04T18:30:00Z", "2019-07-04T19:00:00Z")),
+        Some("unknown"),
+    ));
+
+    assert!(relaxations.is_empty());
+}
+
+#[test]
+fn can_find_minimal_level() {
+    let levels = (1..=8).collect::<Vec<i32>>();
+
+    let result = find_minimal_level(levels.as_slice(), |level| Ok((*level >= 3).then_some(*level as Cost))).unwrap();
+    assert_eq!(result, Some((&3, 3.)));
+
+    let result = find_minimal_level(levels.as_slice(), |_| Ok(None)).unwrap();
+    assert_eq!(result, None);
+}
+
+#[test]
+fn can_serialize_analysis() {
+    let analysis = UnassignedAnalysis {
+        jobs: vec![UnassignedJobAnalysis {
+            job_id: "job1".to_string(),
+            reasons: vec!["SKILL_CONSTRAINT".to_string()],
+            relaxations: vec![
+                RelaxationResult { relaxation: Relaxation::TimeWindow { seconds: 900. }, cost: 1.5 },
+                RelaxationResult { relaxation: Relaxation::Vehicle { type_id: "vehicle".to_string() }, cost: 2. },
+            ],
+        }],
+    };
+
+    let json = serde_json::to_string(&analysis).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"jobs":[{"jobId":"job1","reasons":["SKILL_CONSTRAINT"],"relaxations":[{"type":"time-window","seconds":900.0,"cost":1.5},{"type":"vehicle","typeId":"vehicle","cost":2.0}]}]}"#
+    );
+    assert!(analysis.to_string().contains("widen time windows by 900s: cost +1.50"));
+}
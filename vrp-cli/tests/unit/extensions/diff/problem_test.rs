@@ -0,0 +1,41 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn get_problem() -> Problem {
+    deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap()
+}
+
+#[test]
+fn can_compare_same_problems() {
+    let problem = get_problem();
+
+    let diff = get_problem_diff(&problem, &problem);
+
+    assert!(diff.jobs.is_empty());
+    assert!(diff.vehicles.is_empty());
+}
+
+#[test]
+fn can_detect_job_and_vehicle_changes() {
+    let before = get_problem();
+    let mut after = before.clone();
+    let removed = after.plan.jobs.remove(0);
+    let mut added = removed.clone();
+    added.id = "new_job".to_string();
+    after.plan.jobs.push(added);
+    after.plan.jobs[0].deliveries = after.plan.jobs[0].pickups.take();
+    let mut vehicle = after.fleet.vehicles[0].clone();
+    vehicle.type_id = "new_type".to_string();
+    after.fleet.vehicles.push(vehicle);
+
+    let diff = get_problem_diff(&before, &after);
+
+    assert_eq!(diff.jobs.added, vec!["new_job".to_string()]);
+    assert_eq!(diff.jobs.removed, vec![removed.id]);
+    assert_eq!(diff.jobs.changed.len(), 1);
+    assert_eq!(diff.vehicles, EntityDiff { added: vec!["new_type".to_string()], removed: vec![], changed: vec![] });
+}
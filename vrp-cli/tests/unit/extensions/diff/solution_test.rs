@@ -0,0 +1,105 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn get_solution() -> Solution {
+    deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap()
+}
+
+fn get_key(vehicle_id: &str) -> TourKey {
+    TourKey { vehicle_id: vehicle_id.to_string(), shift_index: 0 }
+}
+
+#[test]
+fn can_compare_same_solutions() {
+    let solution = get_solution();
+
+    let diff = get_solution_diff(&solution, &solution, None);
+
+    assert_eq!(diff.cost.delta, 0.);
+    assert_eq!(diff.tours.len(), 1);
+    assert_eq!(diff.tours[0].change, TourChange::Unchanged);
+    assert!(diff.moved_jobs.is_empty());
+}
+
+#[test]
+fn can_detect_changed_order() {
+    let before = get_solution();
+    let mut after = before.clone();
+    after.tours[0].stops.swap(1, 2);
+
+    let diff = get_solution_diff(&before, &after, None);
+
+    assert_eq!(diff.tours[0].change, TourChange::Changed);
+    assert!(diff.tours[0].order_changed);
+    assert!(diff.tours[0].added_jobs.is_empty());
+    assert!(diff.tours[0].removed_jobs.is_empty());
+    assert!(diff.moved_jobs.is_empty());
+}
+
+#[test]
+fn can_detect_job_moved_to_another_tour() {
+    let before = get_solution();
+    let mut after = before.clone();
+    let mut new_tour = after.tours[0].clone();
+    new_tour.vehicle_id = "vehicle_2".to_string();
+    new_tour.stops = vec![new_tour.stops[0].clone(), new_tour.stops[1].clone(), new_tour.stops.last().unwrap().clone()];
+    new_tour.statistic.cost = 10.;
+    after.tours[0].stops.remove(1);
+    after.tours.push(new_tour);
+
+    let diff = get_solution_diff(&before, &after, None);
+
+    assert_eq!(diff.tours.len(), 2);
+    assert_eq!(diff.tours[0].change, TourChange::Changed);
+    assert_eq!(diff.tours[0].removed_jobs, vec!["job1".to_string()]);
+    assert!(!diff.tours[0].order_changed);
+    assert_eq!(diff.tours[1].change, TourChange::Added);
+    assert_eq!(diff.tours[1].added_jobs, vec!["job1".to_string()]);
+    assert_eq!(diff.tours[1].cost, ValueDiff { before: 0., after: 10., delta: 10. });
+    assert_eq!(
+        diff.moved_jobs,
+        vec![MovedJob {
+            job_id: "job1".to_string(),
+            before: Some(get_key("vehicle_1")),
+            after: Some(get_key("vehicle_2"))
+        }]
+    );
+}
+
+#[test]
+fn can_detect_unassigned_job() {
+    let before = get_solution();
+    let mut after = before.clone();
+    after.tours[0].stops.remove(2);
+    after.statistic.cost -= 1.;
+
+    let diff = get_solution_diff(&before, &after, None);
+
+    assert_eq!(diff.cost.delta, -1.);
+    assert_eq!(
+        diff.moved_jobs,
+        vec![MovedJob { job_id: "job2".to_string(), before: Some(get_key("vehicle_1")), after: None }]
+    );
+
+    let summary = diff.to_string();
+    assert!(summary.contains("tours: 1 changed, 0 added, 0 removed, 0 unchanged"));
+    assert!(summary.contains("vehicle_1/0 changed"));
+    assert!(summary.contains("removed jobs: job2"));
+    assert!(summary.contains("job2: vehicle_1/0 -> unassigned"));
+}
+
+#[test]
+fn can_serialize_diff_as_json() {
+    let solution = get_solution();
+
+    let json = serde_json::to_value(get_solution_diff(&solution, &solution, None)).unwrap();
+
+    assert_eq!(json["tours"][0]["vehicleId"], "vehicle_1");
+    assert_eq!(json["tours"][0]["change"], "unchanged");
+    assert_eq!(json["movedJobs"], serde_json::json!([]));
+    assert!(json.get("problem").is_none());
+}
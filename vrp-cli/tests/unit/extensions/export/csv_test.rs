@@ -0,0 +1,54 @@
+use super::*;
+use std::io::BufReader;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn get_solution() -> Solution {
+    deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap()
+}
+
+fn get_lines(buffer: Vec<u8>) -> Vec<String> {
+    String::from_utf8(buffer).unwrap().lines().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn can_write_csv_solution() {
+    let solution = get_solution();
+    let (mut activities, mut tours, mut unassigned) = (Vec::new(), Vec::new(), Vec::new());
+
+    write_csv_solution(&solution, &mut activities, &mut tours, &mut unassigned).unwrap();
+
+    let activities = get_lines(activities);
+    assert_eq!(activities.len(), 7);
+    assert_eq!(
+        activities[0],
+        "VEHICLE_ID,SHIFT_INDEX,SEQUENCE,JOB_ID,ACTIVITY_TYPE,ARRIVAL,DEPARTURE,LAT,LNG,LOCATION_INDEX,LOAD,DISTANCE,TAG"
+    );
+    assert_eq!(
+        activities[1],
+        "vehicle_1,0,0,departure,departure,2019-07-04T09:00:00Z,2019-07-04T09:51:29Z,52.5316,13.3884,,1,0,"
+    );
+    assert_eq!(
+        activities[2],
+        "vehicle_1,0,1,job1,delivery,2019-07-04T10:07:17Z,2019-07-04T10:12:17Z,52.52599,13.45413,,0,5112,"
+    );
+
+    let tours = get_lines(tours);
+    assert_eq!(tours.len(), 2);
+    assert!(tours[1].starts_with("vehicle_1,vehicle,0,"));
+
+    assert_eq!(get_lines(unassigned), vec!["JOB_ID,CODE,DESCRIPTION".to_string()]);
+}
+
+#[test]
+fn can_write_csv_solution_to_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let out_dir = dir.path().join("out");
+
+    write_csv_solution_to_dir(&get_solution(), out_dir.as_path()).unwrap();
+
+    [CSV_ACTIVITIES_FILE_NAME, CSV_TOURS_FILE_NAME, CSV_UNASSIGNED_FILE_NAME]
+        .iter()
+        .for_each(|name| assert!(out_dir.join(name).exists(), "no file: {name}"));
+}
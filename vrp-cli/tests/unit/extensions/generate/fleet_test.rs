@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::generate::{create_empty_plan, create_test_vehicle_type};
+use std::collections::HashSet;
 use vrp_pragmatic::format::problem::MatrixProfile;
 
 #[test]
@@ -14,8 +15,31 @@ fn can_generate_fleet_of_specific_size() {
         objectives: None,
     };
 
-    let generated = generate_fleet(&prototype, 2);
+    let generated = generate_fleet(&prototype, &GenerateOptions::new(0, 2), &[], &GeneratorRandom::new(None));
 
     assert_eq!(generated.vehicles.len(), 2);
     assert_eq!(generated.profiles.len(), 1);
 }
+
+#[test]
+fn can_generate_fleet_with_skills_breaks_and_reloads() {
+    let prototype = Problem {
+        plan: create_empty_plan(),
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
+            resources: None,
+        },
+        objectives: None,
+    };
+    let options = GenerateOptions { skills: Some(5), breaks: true, ..GenerateOptions::new(0, 2) };
+    let reload_locations = vec![Location::Coordinate { lat: 1., lng: 1. }];
+
+    let generated = generate_fleet(&prototype, &options, reload_locations.as_slice(), &GeneratorRandom::new(Some(0)));
+
+    let skills = generated.vehicles.iter().flat_map(|vehicle| vehicle.skills.iter().flatten()).collect::<HashSet<_>>();
+    assert_eq!(skills.len(), 5);
+    let shifts = generated.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).collect::<Vec<_>>();
+    assert!(shifts.iter().all(|shift| shift.breaks.as_ref().is_some_and(|breaks| breaks.len() == 1)));
+    assert!(shifts.iter().all(|shift| shift.reloads.as_ref().is_some_and(|reloads| reloads.len() == 1)));
+}
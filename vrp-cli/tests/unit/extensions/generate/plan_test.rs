@@ -1,5 +1,5 @@
 use super::*;
-use crate::helpers::generate::create_test_job;
+use crate::helpers::generate::{create_empty_plan, create_test_job};
 
 #[test]
 fn can_generate_bounding_box() {
@@ -7,6 +7,7 @@ fn can_generate_bounding_box() {
         jobs: vec![create_test_job(-1., 1.), create_test_job(1., 0.), create_test_job(3., 1.), create_test_job(1., 2.)],
         relations: None,
         clustering: None,
+        territories: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_plan(&plan);
@@ -23,6 +24,7 @@ fn can_get_bounding_box_from_size() {
         jobs: vec![create_test_job(0., 1.), create_test_job(1., 0.), create_test_job(0., 0.), create_test_job(1., 1.)],
         relations: None,
         clustering: None,
+        territories: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_size(&plan, 100.);
@@ -32,3 +34,48 @@ fn can_get_bounding_box_from_size() {
     assert!((max_lat - 0.500898).abs() < 1E-6);
     assert!((max_lng - 0.500898).abs() < 1E-6);
 }
+
+parameterized_test! {can_generate_locations_with_distribution, distribution, {
+    can_generate_locations_with_distribution_impl(distribution);
+}}
+
+can_generate_locations_with_distribution! {
+    case01_uniform: LocationDistribution::Uniform,
+    case02_clustered: LocationDistribution::Clustered { clusters: 3 },
+    case03_random_clustered: LocationDistribution::RandomClustered { clusters: 3 },
+    case04_ring: LocationDistribution::Ring,
+}
+
+fn can_generate_locations_with_distribution_impl(distribution: LocationDistribution) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_test_job(0., 0.), create_test_job(2., 2.), create_test_job(0., 2.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
+        objectives: None,
+    };
+    let options = GenerateOptions { distribution: distribution.clone(), ..GenerateOptions::new(100, 1) };
+    let rnd = GeneratorRandom::new(Some(0));
+
+    let location_fn = get_location_fn(&problem, None, &options, &rnd).unwrap();
+
+    (0..100).map(|_| location_fn(&rnd).to_lat_lng()).for_each(|(lat, lng)| {
+        assert!((0. ..=2.).contains(&lat) && (0. ..=2.).contains(&lng));
+        if distribution == LocationDistribution::Ring {
+            let radius = ((lat - 1.).powi(2) + (lng - 1.).powi(2)).sqrt();
+            assert!((0.74..=1.01).contains(&radius));
+        }
+    });
+}
+
+#[test]
+fn can_generate_time_window_within_horizon() {
+    let rnd = GeneratorRandom::new(Some(0));
+    let horizon = (0., 3600.);
+
+    (0..10).for_each(|_| {
+        let normal = generate_time_window(&TimeWindowWidth::Normal { mean: 7200., std_dev: 60. }, horizon, &rnd);
+        assert_eq!(normal, vec!["1970-01-01T00:00:00Z".to_string(), "1970-01-01T01:00:00Z".to_string()]);
+    });
+}
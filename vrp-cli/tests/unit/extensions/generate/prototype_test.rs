@@ -1,10 +1,11 @@
 use super::*;
 use crate::helpers::generate::*;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
 use vrp_pragmatic::format::problem::*;
 
-#[test]
-fn can_generate_jobs_with_time_windows() {
-    let problem = Problem {
+fn create_test_problem() -> Problem {
+    Problem {
         plan: Plan {
             jobs: vec![
                 create_test_job(-1., 1.),
@@ -20,10 +21,24 @@ fn can_generate_jobs_with_time_windows() {
             resources: None,
         },
         objectives: None,
-    };
+    }
+}
+
+fn get_places(problem: &Problem) -> impl Iterator<Item = &JobPlace> + '_ {
+    problem.plan.jobs.iter().flat_map(|job| {
+        [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+            .into_iter()
+            .flatten()
+            .flat_map(|tasks| tasks.iter().flat_map(|task| task.places.iter()))
+    })
+}
+
+#[test]
+fn can_generate_jobs_with_time_windows() {
+    let problem = create_test_problem();
 
-    let result =
-        generate_from_prototype(&problem, None, 10, 2, None).unwrap_or_else(|err| panic!("cannot generate: '{err}'"));
+    let result = generate_from_prototype(&problem, None, &GenerateOptions::new(10, 2))
+        .unwrap_or_else(|err| panic!("cannot generate: '{err}'"));
 
     assert_eq!(result.plan.jobs.len(), 10);
     assert_eq!(
@@ -44,3 +59,61 @@ fn can_generate_jobs_with_time_windows() {
         Some(vec![create_test_time_window()])
     )
 }
+
+#[test]
+fn can_generate_same_problem_with_same_seed() {
+    let problem = create_test_problem();
+    let options = GenerateOptions {
+        distribution: LocationDistribution::RandomClustered { clusters: 3 },
+        seed: Some(42),
+        ..GenerateOptions::new(20, 2)
+    };
+    let generate = |options: &GenerateOptions| {
+        let result = generate_from_prototype(&problem, None, options).expect("cannot generate");
+        serde_json::to_string(&result).unwrap()
+    };
+
+    assert_eq!(generate(&options), generate(&options));
+    assert_ne!(generate(&options), generate(&GenerateOptions { seed: Some(43), ..options.clone() }));
+}
+
+#[test]
+fn can_generate_pickup_delivery_jobs_with_time_windows_of_given_width() {
+    let problem = create_test_problem();
+    let options = GenerateOptions {
+        time_window_width: Some(TimeWindowWidth::Uniform { min: 1800., max: 3600. }),
+        pickup_delivery_ratio: Some(1.),
+        seed: Some(0),
+        ..GenerateOptions::new(10, 1)
+    };
+
+    let result = generate_from_prototype(&problem, None, &options).expect("cannot generate");
+
+    assert!(result.plan.jobs.iter().all(|job| {
+        job.pickups.as_ref().is_some_and(|tasks| tasks.len() == 1)
+            && job.deliveries.as_ref().is_some_and(|tasks| tasks.len() == 1)
+    }));
+    assert!(get_places(&result).all(|place| {
+        let times = place.times.as_ref().expect("no time windows");
+        let parse = |time: &str| OffsetDateTime::parse(time, &Rfc3339).unwrap();
+        let width = (parse(times[0][1].as_str()) - parse(times[0][0].as_str())).whole_seconds();
+
+        // NOTE prototype shift has no end, so default horizon is used
+        times.len() == 1 && (1800..=3600).contains(&width) && times[0][0].as_str() >= "2020-05-01T09:00:00Z"
+    }));
+}
+
+#[test]
+fn can_parse_time_window_width() {
+    assert_eq!(
+        "uniform:1800,3600".parse::<TimeWindowWidth>().ok(),
+        Some(TimeWindowWidth::Uniform { min: 1800., max: 3600. })
+    );
+    assert_eq!(
+        "normal:3600,600".parse::<TimeWindowWidth>().ok(),
+        Some(TimeWindowWidth::Normal { mean: 3600., std_dev: 600. })
+    );
+    assert!("uniform:3600,1800".parse::<TimeWindowWidth>().is_err());
+    assert!("exponential:3600".parse::<TimeWindowWidth>().is_err());
+    assert!("uniform".parse::<TimeWindowWidth>().is_err());
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate::extensions::import::import_problem;
+use std::fs::File;
 use std::io::BufReader;
 
 #[test]
@@ -72,3 +73,97 @@ job1,52.5225,13.4095,1,3,,
 
     assert_eq!(result, "csv format expects two files with jobs and vehicles as an input");
 }
+
+#[test]
+fn can_read_rich_csv_problem() {
+    let jobs = BufReader::new(File::open("../examples/data/csv/jobs.rich.csv").unwrap());
+    let vehicles = BufReader::new(File::open("../examples/data/csv/vehicles.rich.csv").unwrap());
+
+    let problem = read_csv_problem(jobs, vehicles).expect("cannot read csv");
+
+    let jobs = &problem.plan.jobs;
+    assert_eq!(jobs.iter().map(|job| job.id.as_str()).collect::<Vec<_>>(), vec!["job1", "job2", "job3"]);
+    let delivery = &jobs[0].deliveries.as_ref().unwrap()[0];
+    assert_eq!(delivery.demand, Some(vec![2, 1]));
+    assert_eq!(delivery.places[0].times.as_ref().unwrap().len(), 2);
+    assert_eq!(delivery.places[0].tag.as_deref(), Some("shop"));
+    assert_eq!(jobs[0].skills.as_ref().unwrap().all_of, Some(vec!["fridge".to_string()]));
+    assert_eq!(jobs[0].value, Some(10.));
+    assert_eq!(jobs[1].pickups.as_ref().unwrap()[0].order, Some(1));
+    assert_eq!(jobs[1].deliveries.as_ref().unwrap()[0].demand, Some(vec![1, 0]));
+    assert!(jobs[2].services.as_ref().unwrap()[0].demand.is_none());
+    assert_eq!(jobs[2].skills.as_ref().unwrap().all_of.as_ref().unwrap().len(), 2);
+
+    let vehicles = &problem.fleet.vehicles;
+    assert_eq!(vehicles[0].capacity, vec![10, 5]);
+    assert_eq!(vehicles[0].vehicle_ids.len(), 2);
+    assert_eq!(vehicles[0].skills.as_ref().unwrap().len(), 2);
+    assert_eq!(vehicles[0].shifts[0].breaks.as_ref().unwrap().len(), 1);
+    assert_eq!(vehicles[0].shifts[0].reloads.as_ref().unwrap()[0].duration, 600.);
+    let limits = vehicles[0].limits.as_ref().unwrap();
+    assert_eq!((limits.max_distance, limits.max_duration, limits.tour_size), (Some(100000.), None, Some(20)));
+    assert!(vehicles[1].shifts[0].breaks.is_none());
+    assert!(vehicles[1].shifts[0].reloads.is_none());
+    assert_eq!(vehicles[1].limits.as_ref().unwrap().max_duration, Some(36000.));
+}
+
+#[test]
+fn can_read_csv_problem_with_custom_columns() {
+    let jobs_csv = r"
+Order,Latitude,Longitude,Weight,Service time
+job1,52.52599,13.45413,-2,300
+";
+    let vehicles_csv = r"
+Vehicle,LAT,LNG,Max weight,TW_START,TW_END,PROFILE
+vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,car
+";
+    let columns: CsvColumns = serde_json::from_str(
+        r#"{
+          "jobs": { "id": "Order", "lat": "Latitude", "lng": "Longitude", "demand": "Weight", "duration": "Service time" },
+          "vehicles": { "id": "Vehicle", "capacity": "Max weight" }
+        }"#,
+    )
+    .unwrap();
+
+    let problem = read_csv_problem_with_columns(
+        BufReader::new(jobs_csv.as_bytes()),
+        BufReader::new(vehicles_csv.as_bytes()),
+        &columns,
+    )
+    .expect("cannot read csv");
+
+    let delivery = &problem.plan.jobs[0].deliveries.as_ref().unwrap()[0];
+    assert_eq!(delivery.demand, Some(vec![2]));
+    assert_eq!(delivery.places[0].duration, 300.);
+    assert_eq!(problem.fleet.vehicles[0].type_id, "vehicle1");
+    assert_eq!(problem.fleet.vehicles[0].capacity, vec![40]);
+    assert_eq!(problem.fleet.vehicles[0].vehicle_ids, vec!["car_1".to_string()]);
+}
+
+parameterized_test! {can_return_error_for_invalid_jobs, (jobs_csv, expected), {
+    can_return_error_for_invalid_jobs_impl(jobs_csv, expected);
+}}
+
+can_return_error_for_invalid_jobs! {
+    case01_missing_column: ("ID,LAT\njob1,52.5", "missing required column 'LNG'"),
+    case02_missing_value: ("ID,LAT,LNG\njob1,,13.4", "line 2: missing value in column 'LAT'"),
+    case03_invalid_demand: ("ID,LAT,LNG,DEMAND\njob1,52.5,13.4,1;a", "line 2: cannot parse 'a' in column 'DEMAND'"),
+    case04_unknown_type: ("ID,LAT,LNG,TYPE\njob1,52.5,13.4,drop", "line 2: unknown task type 'drop'"),
+    case05_tw_mismatch: (
+        "ID,LAT,LNG,TW_START,TW_END\njob1,52.5,13.4,2020-07-04T08:00:00Z;2020-07-04T14:00:00Z,2020-07-04T12:00:00Z",
+        "line 2: mismatch of values in columns 'TW_START' and 'TW_END'"
+    ),
+}
+
+fn can_return_error_for_invalid_jobs_impl(jobs_csv: &str, expected: &str) {
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE
+vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,10,car
+";
+
+    let result = read_csv_problem(BufReader::new(jobs_csv.as_bytes()), BufReader::new(vehicles_csv.as_bytes()))
+        .expect_err("Should return error!");
+
+    assert_eq!(result.cause, "cannot read jobs");
+    assert_eq!(result.details.as_deref(), Some(expected));
+}
@@ -0,0 +1,50 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn get_problem_and_solution() -> (Problem, Solution) {
+    let problem = deserialize_problem(BufReader::new(File::open(PRAGMATIC_PROBLEM_PATH).unwrap())).unwrap();
+    let solution = deserialize_solution(BufReader::new(File::open(PRAGMATIC_SOLUTION_PATH).unwrap())).unwrap();
+
+    (problem, solution)
+}
+
+#[test]
+fn can_create_html_report() {
+    let (problem, solution) = get_problem_and_solution();
+
+    let report = get_html_report(&problem, &solution).unwrap();
+
+    assert!(report.starts_with("<!DOCTYPE html>"));
+    assert_eq!(report.matches("<svg").count(), 2);
+    assert_eq!(report.matches("<polyline").count(), solution.tours.len());
+    assert!(report.contains("All jobs are assigned."));
+    assert!(!report.contains("<script"));
+    assert!(!report.contains("src="));
+}
+
+#[test]
+fn can_get_tour_segments() {
+    let (_, solution) = get_problem_and_solution();
+    let tour = solution.tours.first().unwrap();
+
+    let segments = get_segments(tour).unwrap();
+
+    let get_total = |kind: SegmentKind| {
+        segments.iter().filter(|segment| segment.kind == kind).map(|segment| segment.end - segment.start).sum::<i64>()
+    };
+    assert_eq!(get_total(SegmentKind::Driving), tour.statistic.times.driving);
+    assert_eq!(get_total(SegmentKind::Serving), tour.statistic.times.serving);
+    assert_eq!(get_total(SegmentKind::Waiting), tour.statistic.times.waiting);
+    assert!(segments.windows(2).all(|pair| pair[0].end <= pair[1].start));
+}
+
+#[test]
+fn can_escape_text() {
+    assert_eq!(escape(r#"<a href="x">&</a>"#), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+}
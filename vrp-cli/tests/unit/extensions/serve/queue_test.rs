@@ -0,0 +1,99 @@
+use super::*;
+use crate::extensions::solve::config::read_config;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn create_problem() -> Arc<Problem> {
+    Arc::new(
+        BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap())
+            .read_pragmatic()
+            .unwrap(),
+    )
+}
+
+fn create_config(termination: &str) -> Config {
+    read_config(BufReader::new(format!(r#"{{ "termination": {termination} }}"#).as_bytes())).unwrap()
+}
+
+fn wait_for_status(queue: &JobQueue, id: &str, status: JobStatus) -> JobInfo {
+    let start = Instant::now();
+    loop {
+        let info = queue.get_info(id).unwrap();
+        if info.status == status || start.elapsed() > Duration::from_secs(60) {
+            return info;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn can_solve_submitted_job() {
+    let queue = JobQueue::new(1, 1, 16).unwrap();
+
+    let id = queue.submit(create_problem(), create_config(r#"{ "maxGenerations": 10 }"#)).unwrap();
+    let info = wait_for_status(&queue, id.as_str(), JobStatus::Completed);
+
+    assert_eq!(info.status, JobStatus::Completed);
+    assert!(info.cost.is_some());
+    assert!(info.error.is_none());
+    assert!(queue.get_result(id.as_str()).unwrap().contains("\"tours\""));
+    assert_eq!(queue.get_best_known(id.as_str()), queue.get_result(id.as_str()));
+}
+
+#[test]
+fn can_reject_job_when_queue_is_full_and_cancel_jobs() {
+    let queue = JobQueue::new(1, 1, 16).unwrap();
+    let config = create_config(r#"{ "maxTime": 300 }"#);
+
+    let running = queue.submit(create_problem(), config.clone()).unwrap();
+    wait_for_status(&queue, running.as_str(), JobStatus::Running);
+    let queued = queue.submit(create_problem(), config.clone()).unwrap();
+    let rejected = queue.submit(create_problem(), config);
+
+    assert!(rejected.is_err());
+    assert_eq!(queue.get_info(queued.as_str()).unwrap().status, JobStatus::Queued);
+
+    assert_eq!(queue.cancel(queued.as_str()).unwrap().status, JobStatus::Cancelled);
+    queue.cancel(running.as_str()).unwrap();
+    let info = wait_for_status(&queue, running.as_str(), JobStatus::Cancelled);
+
+    assert_eq!(info.status, JobStatus::Cancelled);
+    assert!(queue.get_result(running.as_str()).is_some());
+    assert_eq!(queue.get_info(queued.as_str()).unwrap().status, JobStatus::Cancelled);
+    assert!(queue.get_result(queued.as_str()).is_none());
+}
+
+#[test]
+fn can_remove_finished_job() {
+    let queue = JobQueue::new(1, 1, 16).unwrap();
+    let id = queue.submit(create_problem(), create_config(r#"{ "maxGenerations": 1 }"#)).unwrap();
+    wait_for_status(&queue, id.as_str(), JobStatus::Completed);
+
+    let info = queue.cancel(id.as_str()).unwrap();
+
+    assert_eq!(info.status, JobStatus::Completed);
+    assert!(queue.get_info(id.as_str()).is_none());
+    assert!(queue.cancel(id.as_str()).is_none());
+}
+
+#[test]
+fn can_evict_the_oldest_finished_jobs() {
+    let queue = JobQueue::new(1, 1, 1).unwrap();
+    let config = create_config(r#"{ "maxGenerations": 1 }"#);
+
+    let first = queue.submit(create_problem(), config.clone()).unwrap();
+    wait_for_status(&queue, first.as_str(), JobStatus::Completed);
+    let second = queue.submit(create_problem(), config.clone()).unwrap();
+    wait_for_status(&queue, second.as_str(), JobStatus::Completed);
+    let third = queue.submit(create_problem(), config).unwrap();
+
+    assert!(queue.get_info(first.as_str()).is_none());
+    assert!(queue.get_info(second.as_str()).is_some());
+    assert!(queue.get_info(third.as_str()).is_some());
+}
+
+#[test]
+fn can_return_error_for_zero_workers() {
+    assert!(JobQueue::new(0, 1, 16).is_err());
+}
@@ -0,0 +1,135 @@
+use super::*;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn create_submit_body(termination: &str) -> String {
+    let problem = fs::read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let matrix = fs::read_to_string("../examples/data/pragmatic/simple.basic.matrix.json").unwrap();
+
+    format!(r#"{{ "problem": {problem}, "matrices": [{matrix}], "config": {{ "termination": {termination} }} }}"#)
+}
+
+fn send_request(address: &SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let status = response.split_whitespace().nth(1).unwrap().parse().unwrap();
+    let body = response.split_once("\r\n\r\n").map(|(_, body)| body.to_string()).unwrap_or_default();
+
+    (status, body)
+}
+
+fn get_status(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body).unwrap()["status"].as_str().unwrap().to_string()
+}
+
+parameterized_test! {can_handle_invalid_requests, (method, url, body, expected_status, expected_body), {
+    can_handle_invalid_requests_impl(method, url, body, expected_status, expected_body);
+}}
+
+can_handle_invalid_requests! {
+    case01_unknown_route: (Method::Get, "/unknown", "", 404, "unknown route"),
+    case02_unknown_job: (Method::Get, "/jobs/42", "", 404, "job is not found"),
+    case03_unknown_job_result: (Method::Get, "/jobs/42/result", "", 404, "job is not found"),
+    case04_unknown_job_cancel: (Method::Delete, "/jobs/42", "", 404, "job is not found"),
+    case05_invalid_json: (Method::Post, "/jobs", "{", 400, "E0000"),
+}
+
+fn can_handle_invalid_requests_impl(method: Method, url: &str, body: &str, expected_status: u16, expected_body: &str) {
+    let queue = JobQueue::new(1, 1, 16).unwrap();
+
+    let (status, body) = handle_request(&queue, &method, url, body);
+
+    assert_eq!(status, expected_status);
+    assert!(body.contains(expected_body), "unexpected body: {body}");
+}
+
+parameterized_test! {can_reject_unsupported_config, (config, expected), {
+    can_reject_unsupported_config_impl(config, expected);
+}}
+
+can_reject_unsupported_config! {
+    case01_island: (r#"{ "island": { "islands": ["127.0.0.1:0"], "index": 0 } }"#, "island model"),
+    case02_priors: (r#"{ "hyper": { "type": "dynamic-selective", "priors": "solution.json" } }"#, "priors"),
+}
+
+fn can_reject_unsupported_config_impl(config: &str, expected: &str) {
+    let queue = JobQueue::new(1, 1, 16).unwrap();
+    let problem = fs::read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let body = format!(r#"{{ "problem": {problem}, "config": {config} }}"#);
+
+    let (status, body) = handle_request(&queue, &Method::Post, "/jobs", body.as_str());
+
+    assert_eq!(status, 400);
+    assert!(body.contains(expected), "unexpected body: {body}");
+    assert!(queue.get_info("1").is_none());
+}
+
+#[test]
+fn can_return_error_for_invalid_problem() {
+    let queue = JobQueue::new(1, 1, 16).unwrap();
+    let problem = fs::read_to_string("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let mut problem = serde_json::from_str::<serde_json::Value>(problem.as_str()).unwrap();
+    let job = problem["plan"]["jobs"][0].clone();
+    problem["plan"]["jobs"].as_array_mut().unwrap().push(job);
+    let body = format!(r#"{{ "problem": {problem} }}"#);
+
+    let (status, body) = handle_request(&queue, &Method::Post, "/jobs", body.as_str());
+
+    assert_eq!(status, 400);
+    assert!(body.contains("E1100"), "unexpected body: {body}");
+    assert!(queue.get_info("1").is_none());
+}
+
+#[test]
+fn can_reject_job_when_queue_is_full() {
+    let queue = JobQueue::new(1, 0, 16).unwrap();
+    let body = create_submit_body(r#"{ "maxTime": 300 }"#);
+
+    let statuses = (0..3).map(|_| handle_request(&queue, &Method::Post, "/jobs", body.as_str()).0).collect::<Vec<_>>();
+
+    assert!(statuses.contains(&503));
+    (1..=3).for_each(|id| {
+        queue.cancel(id.to_string().as_str());
+    });
+}
+
+#[test]
+fn can_solve_problem_using_server() {
+    let server = SolverServer::new("127.0.0.1:0", ServerConfig::default()).unwrap();
+    let address = server.address().unwrap();
+    thread::spawn(move || server.run());
+
+    let (status, body) =
+        send_request(&address, "POST", "/jobs", create_submit_body(r#"{ "maxGenerations": 10 }"#).as_str());
+    assert_eq!(status, 202);
+    let id = serde_json::from_str::<serde_json::Value>(body.as_str()).unwrap()["id"].as_str().unwrap().to_string();
+
+    let start = Instant::now();
+    let mut status = get_status(send_request(&address, "GET", format!("/jobs/{id}").as_str(), "").1.as_str());
+    while status != "completed" && start.elapsed() < Duration::from_secs(60) {
+        thread::sleep(Duration::from_millis(50));
+        status = get_status(send_request(&address, "GET", format!("/jobs/{id}").as_str(), "").1.as_str());
+    }
+    assert_eq!(status, "completed");
+
+    let (status, body) = send_request(&address, "GET", format!("/jobs/{id}/result").as_str(), "");
+    assert_eq!(status, 200);
+    assert!(body.contains("\"tours\""));
+
+    let (status, body) = send_request(&address, "DELETE", format!("/jobs/{id}").as_str(), "");
+    assert_eq!(status, 200);
+    assert_eq!(get_status(body.as_str()), "completed");
+    assert_eq!(send_request(&address, "GET", format!("/jobs/{id}").as_str(), "").0, 404);
+}
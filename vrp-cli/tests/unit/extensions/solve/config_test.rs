@@ -55,7 +55,7 @@ fn can_read_full_config() {
     match hyper_config {
         HyperType::StaticSelective { operators } => {
             let operators = operators.expect("cannot get operators");
-            assert_eq!(operators.len(), 4);
+            assert_eq!(operators.len(), 5);
             match operators.first().unwrap() {
                 SearchOperatorType::Decomposition { routes, repeat, probability } => {
                     assert_eq!(*repeat, 4);
@@ -77,7 +77,7 @@ fn can_read_full_config() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.05);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 8);
                 }
                 _ => unreachable!(),
             }
@@ -85,13 +85,13 @@ fn can_read_full_config() {
             match operators.get(2).unwrap() {
                 SearchOperatorType::RuinRecreate { probability, ruins, recreates } => {
                     assert_eq!(as_scalar_probability(probability), 1.);
-                    assert_eq!(ruins.len(), 7);
+                    assert_eq!(ruins.len(), 9);
                     assert_eq!(recreates.len(), 12);
                 }
                 _ => unreachable!(),
             }
 
-            match operators.last().unwrap() {
+            match operators.get(3).unwrap() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
@@ -99,8 +99,19 @@ fn can_read_full_config() {
                 }
                 _ => unreachable!(),
             }
+
+            match operators.last().unwrap() {
+                SearchOperatorType::GuidedLocalSearch { probability, times, operators: inners, alpha, decay } => {
+                    assert_eq!(as_scalar_probability(probability), 0.01);
+                    assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
+                    assert_eq!(inners.len(), 3);
+                    assert_eq!(*alpha, Some(0.3));
+                    assert_eq!(*decay, Some(0.99));
+                }
+                _ => unreachable!(),
+            }
         }
-        HyperType::DynamicSelective => unreachable!(),
+        HyperType::DynamicSelective { .. } | HyperType::Alns { .. } => unreachable!(),
     }
 
     let termination = config.termination.expect("no termination config");
@@ -137,6 +148,7 @@ fn can_configure_telemetry_metrics() {
     let config = Config {
         evolution: None,
         hyper: None,
+        island: None,
         termination: Some(TerminationConfig { max_time: None, max_generations: Some(100), variation: None }),
         environment: None,
         telemetry: Some(TelemetryConfig {
@@ -155,6 +167,97 @@ fn can_configure_telemetry_metrics() {
     let metrics = solution.telemetry.expect("no metrics");
     assert_eq!(metrics.generations, 100);
     assert_eq!(metrics.evolution.len(), 10 + 1);
+    assert!(!metrics.operators.is_empty());
+}
+
+#[test]
+fn can_configure_alns_strategy() {
+    let config = r#"{
+        "hyper": {
+            "type": "alns",
+            "acceptance": { "type": "record-to-record", "deviation": 0.05 },
+            "segmentSize": 10
+        },
+        "termination": { "maxGenerations": 50 }
+    }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+    match config.hyper.as_ref() {
+        Some(HyperType::Alns {
+            acceptance: Some(AlnsAcceptanceType::RecordToRecord { deviation }),
+            segment_size,
+            ..
+        }) => {
+            assert_eq!(*deviation, 0.05);
+            assert_eq!(*segment_size, Some(10));
+        }
+        _ => unreachable!(),
+    }
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert!(!solution.routes.is_empty());
+}
+
+#[test]
+fn can_configure_dynamic_heuristic_with_priors() {
+    let file = File::open("../examples/data/pragmatic/simple.basic.solution.json").unwrap();
+    let mut solution = deserialize_solution(BufReader::new(file)).unwrap();
+    solution.extras = Some(vrp_pragmatic::format::solution::Extras {
+        metrics: Some(vrp_pragmatic::format::solution::Metrics {
+            duration: 1,
+            generations: 1,
+            speed: 1.,
+            evolution: vec![],
+            operators: Some(vec![vrp_pragmatic::format::solution::OperatorStatistic {
+                state: "best".to_string(),
+                name: "local_two_opt".to_string(),
+                alpha: 2.,
+                beta: 3.,
+                mu: 4.,
+                v: 5.,
+                n: 6,
+            }]),
+        }),
+        quality: None,
+        features: None,
+    });
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    serde_json::to_writer(tmpfile.as_file(), &solution).unwrap();
+    let path = tmpfile.path().to_str().unwrap().to_string();
+
+    let priors = read_operator_priors(path.as_str()).unwrap();
+    let config = Config {
+        hyper: Some(HyperType::DynamicSelective { priors: Some(path) }),
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        ..Config::default()
+    };
+    let result = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build());
+
+    assert_eq!(
+        priors,
+        vec![SearchOperatorStatistics {
+            state: "best".to_string(),
+            name: "local_two_opt".to_string(),
+            alpha: 2.,
+            beta: 3.,
+            mu: 4.,
+            v: 5.,
+            n: 6
+        }]
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn can_return_error_for_missing_priors_file() {
+    let result = read_operator_priors("non-existing-priors.json");
+
+    assert!(result.is_err());
 }
 
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
@@ -163,3 +266,100 @@ fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_return_error_for_invalid_island_index() {
+    let config = Config {
+        island: Some(IslandModelConfig {
+            islands: vec!["127.0.0.1:0".to_string()],
+            index: 1,
+            topology: None,
+            migration_interval: None,
+            migration_size: None,
+        }),
+        ..Config::default()
+    };
+
+    let result = create_builder_from_config(create_example_problem(), Vec::default(), &config);
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("island index 1 is out of range of 1 islands".to_string())
+    );
+}
+
+parameterized_test! {can_return_error_for_invalid_alns_config, (hyper, expected), {
+    can_return_error_for_invalid_alns_config_impl(hyper, expected);
+}}
+
+can_return_error_for_invalid_alns_config! {
+    case01_zero_segment_size: (r#"{ "type": "alns", "segmentSize": 0 }"#, "segment size must be positive"),
+    case02_negative_reaction_factor: (r#"{ "type": "alns", "reactionFactor": -0.1 }"#, "reaction factor must be in [0, 1] range"),
+    case03_big_reaction_factor: (r#"{ "type": "alns", "reactionFactor": 1.5 }"#, "reaction factor must be in [0, 1] range"),
+    case04_zero_temperature: (
+        r#"{ "type": "alns", "acceptance": { "type": "simulated-annealing", "initialTemperature": 1, "finalTemperature": 0 } }"#,
+        "temperatures must be positive"
+    ),
+    case05_inverted_temperatures: (
+        r#"{ "type": "alns", "acceptance": { "type": "simulated-annealing", "initialTemperature": 0.1, "finalTemperature": 1 } }"#,
+        "temperatures must be positive"
+    ),
+    case06_negative_deviation: (
+        r#"{ "type": "alns", "acceptance": { "type": "record-to-record", "deviation": -0.05 } }"#,
+        "deviation must be non-negative"
+    ),
+}
+
+fn can_return_error_for_invalid_alns_config_impl(hyper: &str, expected: &str) {
+    let config = format!(r#"{{ "hyper": {hyper}, "termination": {{ "maxGenerations": 10 }} }}"#);
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let result = create_builder_from_config(create_example_problem(), Vec::default(), &config);
+
+    assert!(result.err().map(|err| err.to_string()).is_some_and(|err| err.starts_with(expected)));
+}
+
+#[test]
+fn can_return_error_for_invalid_guided_local_search_decay() {
+    let operator = SearchOperatorType::GuidedLocalSearch {
+        probability: OperatorProbabilityType::Scalar { scalar: 1. },
+        times: MinMaxConfig { min: 1, max: 1 },
+        operators: vec![LocalOperatorType::TwoOpt { weight: 1 }],
+        alpha: None,
+        decay: Some(1.5),
+    };
+
+    let result = create_operator(create_example_problem(), Arc::new(Environment::default()), &operator);
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("decay must be in (0, 1] range. Specified: 1.5".to_string())
+    );
+}
+
+#[test]
+fn can_configure_granularity() {
+    let config = r#"{
+        "evolution": { "granularity": 5 },
+        "termination": { "maxGenerations": 10 }
+    }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert!(!solution.routes.is_empty());
+}
+
+#[test]
+fn can_return_error_for_zero_granularity() {
+    let result = with_granularity(create_example_problem(), 0);
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("granularity must be an integer bigger than 0, got '0'".to_string())
+    );
+}
@@ -0,0 +1,120 @@
+use super::*;
+use std::fs::File;
+use std::time::Instant;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+fn create_problem_and_solution(environment: Arc<Environment>) -> (Arc<Problem>, InsertionContext) {
+    let problem = Arc::new(
+        BufReader::new(File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap())
+            .read_pragmatic()
+            .unwrap(),
+    );
+    let solution = read_init_solution(
+        BufReader::new(File::open("../examples/data/pragmatic/simple.basic.solution.json").unwrap()),
+        problem.clone(),
+        environment.random.clone(),
+    )
+    .unwrap();
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+
+    (problem, insertion_ctx)
+}
+
+fn immigrate_with_timeout(migration: &TcpMigration) -> Vec<InsertionContext> {
+    let start = Instant::now();
+    loop {
+        let immigrants = migration.immigrate();
+        if !immigrants.is_empty() || start.elapsed() > Duration::from_secs(10) {
+            return immigrants;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn to_strings(addresses: &[&str]) -> Vec<String> {
+    addresses.iter().map(|address| address.to_string()).collect()
+}
+
+parameterized_test! {can_get_neighbours, (topology, islands, index, expected), {
+    can_get_neighbours_impl(topology, to_strings(islands), index, to_strings(expected));
+}}
+
+can_get_neighbours! {
+    case01_ring_first: (IslandTopology::Ring, &["a", "b", "c"], 0, &["b"]),
+    case02_ring_last: (IslandTopology::Ring, &["a", "b", "c"], 2, &["a"]),
+    case03_full: (IslandTopology::FullyConnected, &["a", "b", "c"], 1, &["a", "c"]),
+    case04_single: (IslandTopology::FullyConnected, &["a"], 0, &[]),
+    case05_single: (IslandTopology::Ring, &["a"], 0, &[]),
+}
+
+fn can_get_neighbours_impl(topology: IslandTopology, islands: Vec<String>, index: usize, expected: Vec<String>) {
+    assert_eq!(topology.get_neighbours(islands.as_slice(), index), expected);
+}
+
+#[test]
+fn can_exchange_solutions_between_islands() {
+    let environment = Arc::new(Environment::default());
+    let (problem, insertion_ctx) = create_problem_and_solution(environment.clone());
+    let first_listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let second_listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let second_address = second_listener.local_addr().unwrap().to_string();
+    let first = TcpMigration::new(problem.clone(), environment.clone(), first_listener, vec![second_address]).unwrap();
+    let second = TcpMigration::new(problem, environment, second_listener, vec![]).unwrap();
+
+    first.emigrate(vec![&insertion_ctx]);
+    let immigrants = immigrate_with_timeout(&second);
+
+    assert_eq!(immigrants.len(), 1);
+    assert_eq!(immigrants[0].solution.routes.len(), insertion_ctx.solution.routes.len());
+    assert_eq!(immigrants[0].solution.unassigned.len(), insertion_ctx.solution.unassigned.len());
+    assert!(first.immigrate().is_empty());
+}
+
+#[test]
+fn can_skip_invalid_payload() {
+    let environment = Arc::new(Environment::default());
+    let (problem, _) = create_problem_and_solution(environment.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let migration = TcpMigration::new(problem, environment, listener, vec![]).unwrap();
+
+    send_payload(address.as_str(), b"not a solution").unwrap();
+    let start = Instant::now();
+    while migration.received.lock().unwrap().is_empty() && start.elapsed() < Duration::from_secs(10) {
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert!(migration.immigrate().is_empty());
+    assert!(migration.received.lock().unwrap().is_empty());
+}
+
+#[test]
+fn can_release_address_when_dropped() {
+    let environment = Arc::new(Environment::default());
+    let (problem, _) = create_problem_and_solution(environment.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let migration = TcpMigration::new(problem, environment, listener, vec![]).unwrap();
+
+    drop(migration);
+
+    assert!(TcpListener::bind(address.as_str()).is_ok());
+}
+
+#[test]
+fn can_limit_inbox_size() {
+    let environment = Arc::new(Environment::default());
+    let (problem, _) = create_problem_and_solution(environment.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let migration = TcpMigration::new(problem, environment, listener, vec![]).unwrap();
+
+    (0..MAX_INBOX_SIZE + 2).for_each(|_| send_payload(address.as_str(), b"not a solution").unwrap());
+    let start = Instant::now();
+    while migration.received.lock().unwrap().len() < MAX_INBOX_SIZE && start.elapsed() < Duration::from_secs(10) {
+        thread::sleep(Duration::from_millis(10));
+    }
+    thread::sleep(Duration::from_millis(200));
+
+    assert_eq!(migration.received.lock().unwrap().len(), MAX_INBOX_SIZE);
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/geometry/convex_test.rs"]
+mod convex_test;
+
+use super::Point;
+use std::cmp::Ordering;
+
+/// Builds a convex hull of given points using monotone chain algorithm. Returned points are
+/// ordered counter-clockwise without repeating the first one, collinear points are omitted.
+pub fn get_convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then_with(|| a.y.total_cmp(&b.y)));
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let build_chain = |points: &mut dyn Iterator<Item = &Point>| {
+        points.fold(Vec::<Point>::new(), |mut chain, point| {
+            while chain.len() >= 2
+                && Point::cross_product(&chain[chain.len() - 2], &chain[chain.len() - 1], point) <= 0.
+            {
+                chain.pop();
+            }
+            chain.push(point.clone());
+
+            chain
+        })
+    };
+
+    let mut lower = build_chain(&mut points.iter());
+    let mut upper = build_chain(&mut points.iter().rev());
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+
+    lower
+}
+
+/// Returns an area of the polygon defined by the given ring.
+pub fn get_polygon_area(ring: &[Point]) -> f64 {
+    if ring.len() < 3 {
+        return 0.;
+    }
+
+    let double_area = ring.iter().zip(ring.iter().cycle().skip(1)).map(|(a, b)| a.x * b.y - b.x * a.y).sum::<f64>();
+
+    double_area.abs() / 2.
+}
+
+/// Returns an intersection of two convex polygons using Sutherland–Hodgman algorithm. Both polygons
+/// are expected to be ordered counter-clockwise as returned by `get_convex_hull`.
+pub fn get_convex_intersection(subject: &[Point], clip: &[Point]) -> Vec<Point> {
+    if subject.len() < 3 || clip.len() < 3 {
+        return Vec::default();
+    }
+
+    clip.iter().zip(clip.iter().cycle().skip(1)).fold(subject.to_vec(), |input, (edge_start, edge_end)| {
+        if input.is_empty() {
+            return input;
+        }
+
+        let is_inside = |point: &Point| Point::cross_product(edge_start, edge_end, point) >= 0.;
+
+        input.iter().zip(input.iter().cycle().skip(1)).fold(Vec::new(), |mut output, (current, next)| {
+            match (is_inside(current), is_inside(next)) {
+                (true, true) => output.push(next.clone()),
+                (true, false) => output.extend(get_line_intersection(current, next, edge_start, edge_end)),
+                (false, true) => {
+                    output.extend(get_line_intersection(current, next, edge_start, edge_end));
+                    output.push(next.clone());
+                }
+                (false, false) => {}
+            }
+
+            output
+        })
+    })
+}
+
+/// Returns an area of intersection of two convex polygons.
+pub fn get_convex_overlap_area(a: &[Point], b: &[Point]) -> f64 {
+    get_polygon_area(get_convex_intersection(a, b).as_slice())
+}
+
+fn get_line_intersection(a: &Point, b: &Point, c: &Point, d: &Point) -> Option<Point> {
+    let denominator = (a.x - b.x) * (c.y - d.y) - (a.y - b.y) * (c.x - d.x);
+
+    match denominator.abs().partial_cmp(&f64::EPSILON) {
+        Some(Ordering::Greater) => {
+            let t = ((a.x - c.x) * (c.y - d.y) - (a.y - c.y) * (c.x - d.x)) / denominator;
+
+            Some(Point::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y)))
+        }
+        _ => None,
+    }
+}
@@ -1,4 +1,10 @@
 //! A module with geometry primitives.
 
+mod convex;
+pub use self::convex::*;
+
 mod point;
 pub use self::point::Point;
+
+mod polygon;
+pub use self::polygon::Polygon;
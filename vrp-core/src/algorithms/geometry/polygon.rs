@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/geometry/polygon_test.rs"]
+mod polygon_test;
+
+use super::Point;
+
+/// Represents a polygon in 2D space defined by a list of rings. The first ring is an outer
+/// boundary, others are holes. Rings are not required to be closed explicitly.
+#[derive(Clone, Debug)]
+pub struct Polygon {
+    rings: Vec<Vec<Point>>,
+}
+
+impl Polygon {
+    /// Creates a new instance of `Polygon`.
+    pub fn new(rings: Vec<Vec<Point>>) -> Self {
+        Self { rings }
+    }
+
+    /// Returns polygon rings.
+    pub fn rings(&self) -> &[Vec<Point>] {
+        self.rings.as_slice()
+    }
+
+    /// Checks whether the point is inside the polygon using even-odd rule. Points inside holes
+    /// are considered as outside of the polygon.
+    pub fn contains(&self, point: &Point) -> bool {
+        self.rings.iter().filter(|ring| is_inside_ring(ring.as_slice(), point)).count() % 2 == 1
+    }
+}
+
+fn is_inside_ring(ring: &[Point], point: &Point) -> bool {
+    if ring.len() < 3 {
+        return false;
+    }
+
+    let mut is_inside = false;
+    let mut j = ring.len() - 1;
+
+    for i in 0..ring.len() {
+        let (a, b) = (&ring[i], &ring[j]);
+
+        if (a.y > point.y) != (b.y > point.y) && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x {
+            is_inside = !is_inside;
+        }
+
+        j = i;
+    }
+
+    is_inside
+}
@@ -13,6 +13,7 @@ pub fn create_minimize_tours_feature(name: &str) -> Result<Feature, GenericError
         .with_objective(FleetUsageObjective {
             route_estimate_fn: Box::new(|route_ctx| if route_ctx.route().tour.job_count() == 0 { 1. } else { 0. }),
             solution_estimate_fn: Box::new(|solution_ctx| solution_ctx.routes.iter().len() as Cost),
+            kind: ObjectiveKind::MinTours,
         })
         .build()
 }
@@ -24,6 +25,7 @@ pub fn create_maximize_tours_feature(name: &str) -> Result<Feature, GenericError
         .with_objective(FleetUsageObjective {
             route_estimate_fn: Box::new(|route_ctx| if route_ctx.route().tour.job_count() == 0 { -1. } else { 0. }),
             solution_estimate_fn: Box::new(|solution_ctx| -1. * solution_ctx.routes.iter().len() as Cost),
+            kind: ObjectiveKind::Other,
         })
         .build()
 }
@@ -48,6 +50,7 @@ pub fn create_minimize_arrival_time_feature(name: &str) -> Result<Feature, Gener
                     total / solution_ctx.routes.len() as f64
                 }
             }),
+            kind: ObjectiveKind::Other,
         })
         .build()
 }
@@ -55,6 +58,7 @@ pub fn create_minimize_arrival_time_feature(name: &str) -> Result<Feature, Gener
 struct FleetUsageObjective {
     route_estimate_fn: Box<dyn Fn(&RouteContext) -> Cost + Send + Sync>,
     solution_estimate_fn: Box<dyn Fn(&SolutionContext) -> Cost + Send + Sync>,
+    kind: ObjectiveKind,
 }
 
 impl Objective for FleetUsageObjective {
@@ -72,4 +76,8 @@ impl FeatureObjective for FleetUsageObjective {
             _ => Cost::default(),
         }
     }
+
+    fn kind(&self) -> ObjectiveKind {
+        self.kind
+    }
 }
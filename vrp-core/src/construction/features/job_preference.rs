@@ -0,0 +1,41 @@
+//! Provides a way to penalize job assignments which do not match job preferences (soft skills).
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_preference_test.rs"]
+mod job_preference_test;
+
+use super::*;
+use crate::models::problem::Actor;
+
+/// Specifies a function which returns a penalty of assigning the job to the given actor.
+/// Zero means that all job preferences are satisfied by the actor.
+pub type JobPreferencePenaltyFn = Arc<dyn Fn(&Actor, &Job) -> Cost + Send + Sync>;
+
+/// Creates a job preference feature as soft constraint: it minimizes the total penalty of unsatisfied preferences.
+pub fn create_job_preference_feature(name: &str, penalty_fn: JobPreferencePenaltyFn) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_objective(JobPreferenceObjective { penalty_fn }).build()
+}
+
+struct JobPreferenceObjective {
+    penalty_fn: JobPreferencePenaltyFn,
+}
+
+impl Objective for JobPreferenceObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().fold(Cost::default(), |acc, route_ctx| {
+            let actor = route_ctx.route().actor.as_ref();
+            route_ctx.route().tour.jobs().fold(acc, |acc, job| acc + (self.penalty_fn)(actor, job))
+        })
+    }
+}
+
+impl FeatureObjective for JobPreferenceObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => (self.penalty_fn)(route_ctx.route().actor.as_ref(), job),
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
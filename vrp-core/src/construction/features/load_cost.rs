@@ -0,0 +1,145 @@
+//! Provides a way to model load dependent travel costs, e.g. fuel consumption of heavy vehicles.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/load_cost_test.rs"]
+mod load_cost_test;
+
+use super::*;
+use crate::models::problem::{Actor, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+
+/// Specifies a function which returns a travel cost factor for the given actor and load ratio.
+/// Load ratio is a fraction of the vehicle capacity occupied on the leg, in range [0, 1].
+/// A factor of one means that travel cost is not affected by the load.
+pub type LoadCostFactorFn = Arc<dyn Fn(&Actor, f64) -> f64 + Send + Sync>;
+
+/// Extends a travel cost feature (e.g. created by `create_minimize_transport_costs_feature`) with load
+/// dependent travel costs: each leg of the tour is penalized by extra travel cost proportional to the
+/// vehicle load on that leg. This extra cost is added to fitness and insertion estimate of the given
+/// feature's objective. The load is taken from the activity state maintained by the capacity feature,
+/// so it has to be used together with it.
+/// NOTE: it affects costs only, a schedule of the tour is not changed.
+pub fn create_load_dependent_cost_feature<T: LoadOps>(
+    cost_feature: Feature,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    factor_fn: LoadCostFactorFn,
+    current_capacity_key: StateKey,
+) -> Result<Feature, GenericError> {
+    let inner = cost_feature.objective.clone().ok_or_else(|| GenericError::from("cost feature has no objective"))?;
+
+    FeatureBuilder::from_feature(cost_feature)
+        .with_objective(LoadCostObjective::<T> {
+            inner,
+            transport,
+            factor_fn,
+            current_capacity_key,
+            phantom: Default::default(),
+        })
+        .build()
+}
+
+struct LoadCostObjective<T: LoadOps> {
+    inner: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    factor_fn: LoadCostFactorFn,
+    current_capacity_key: StateKey,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: LoadOps> LoadCostObjective<T> {
+    fn get_route_cost(&self, route_ctx: &RouteContext) -> Cost {
+        let route = route_ctx.route();
+        let loads = route_ctx.state().get_activity_states::<T>(self.current_capacity_key);
+
+        route.tour.legs().fold(Cost::default(), |acc, (items, idx)| match items {
+            [from, to] => {
+                let load = loads.and_then(|loads| loads.get(idx)).copied().unwrap_or_default();
+                acc + self.get_leg_cost(route, from, to.place.location, from.schedule.departure, load)
+            }
+            _ => acc,
+        })
+    }
+
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+
+        let prev_load = route_ctx
+            .state()
+            .get_activity_state::<T>(self.current_capacity_key, activity_ctx.index)
+            .copied()
+            .unwrap_or_default();
+        let demand: Option<&Demand<T>> = target.job.as_ref().and_then(|job| job.dimens.get_demand());
+
+        // NOTE static delivery is carried from the tour start, static pickup until the tour end: here we
+        //      consider only their impact on adjusted legs to keep estimation cheap.
+        let (left_load, right_load) = demand.map_or((prev_load, prev_load), |demand| {
+            let left_load = prev_load + demand.delivery.0;
+            (left_load, left_load + demand.change())
+        });
+
+        let departure = prev.schedule.departure;
+        let left_cost = self.get_leg_cost(route, prev, target.place.location, departure, left_load);
+
+        let Some(next) = next else {
+            return left_cost;
+        };
+
+        let target_departure = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            )
+            + target.place.duration;
+
+        let right_cost = self.get_leg_cost(route, target, next.place.location, target_departure, right_load);
+        let old_cost = self.get_leg_cost(route, prev, next.place.location, departure, prev_load);
+
+        left_cost + right_cost - old_cost
+    }
+
+    fn get_leg_cost(&self, route: &Route, from: &Activity, to: Location, departure: Timestamp, load: T) -> Cost {
+        let ratio = route
+            .actor
+            .vehicle
+            .dimens
+            .get_capacity()
+            .map(|capacity: &T| load.ratio(capacity))
+            .filter(|ratio| ratio.is_finite())
+            .unwrap_or_default()
+            .clamp(0., 1.);
+
+        let factor = (self.factor_fn)(route.actor.as_ref(), ratio);
+
+        if (factor - 1.).abs() < f64::EPSILON {
+            return Cost::default();
+        }
+
+        (factor - 1.) * self.transport.cost(route, from.place.location, to, TravelTime::Departure(departure))
+    }
+}
+
+impl<T: LoadOps> Objective for LoadCostObjective<T> {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .fold(self.inner.fitness(solution), |acc, route_ctx| acc + self.get_route_cost(route_ctx))
+    }
+}
+
+impl<T: LoadOps> FeatureObjective for LoadCostObjective<T> {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        let extra_cost = match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
+        };
+
+        self.inner.estimate(move_ctx) + extra_cost
+    }
+}
@@ -0,0 +1,126 @@
+//! Provides a way to keep a solution close to a reference one: it penalizes jobs assigned to another
+//! actor and changes of job sequence comparing to the reference solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_changes_test.rs"]
+mod minimize_changes_test;
+
+use super::*;
+use crate::models::problem::Actor;
+use crate::models::solution::Activity;
+
+/// Specifies a job position in the reference solution.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReferencePosition {
+    /// An index of the reference tour.
+    pub tour: usize,
+    /// A job rank within the reference tour.
+    pub rank: usize,
+}
+
+/// Specifies a function which returns a job position in the reference solution or None if the job
+/// is not present there.
+pub type ReferenceJobFn = Arc<dyn Fn(&Job) -> Option<ReferencePosition> + Send + Sync>;
+
+/// Specifies a function which returns an index of the reference tour served by the actor or None
+/// if there is no such tour.
+pub type ReferenceActorFn = Arc<dyn Fn(&Actor) -> Option<usize> + Send + Sync>;
+
+/// Specifies penalties of the minimize changes feature.
+#[derive(Clone, Copy, Debug)]
+pub struct MinimizeChangesWeights {
+    /// A penalty for each job assigned to a different actor than in the reference solution.
+    pub reassignment: Cost,
+    /// A penalty for each pair of consecutive jobs served in the reverse order comparing to the
+    /// reference tour.
+    pub sequence: Cost,
+}
+
+/// Creates a feature which minimizes deviation from the reference solution as soft constraint.
+/// Jobs which are not present in the reference solution are not penalized.
+pub fn create_minimize_changes_feature(
+    name: &str,
+    job_fn: ReferenceJobFn,
+    actor_fn: ReferenceActorFn,
+    weights: MinimizeChangesWeights,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeChangesObjective { job_fn, actor_fn, weights })
+        .build()
+}
+
+struct MinimizeChangesObjective {
+    job_fn: ReferenceJobFn,
+    actor_fn: ReferenceActorFn,
+    weights: MinimizeChangesWeights,
+}
+
+impl MinimizeChangesObjective {
+    /// Returns a job rank if the job is served by the same tour in the reference solution.
+    fn get_rank(&self, activity: &Activity, tour_idx: Option<usize>) -> Option<usize> {
+        activity
+            .retrieve_job()
+            .and_then(|job| (self.job_fn)(&job))
+            .filter(|position| Some(position.tour) == tour_idx)
+            .map(|position| position.rank)
+    }
+}
+
+impl Objective for MinimizeChangesObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().fold(Cost::default(), |acc, route_ctx| {
+            let tour = &route_ctx.route().tour;
+            let tour_idx = (self.actor_fn)(route_ctx.route().actor.as_ref());
+
+            let reassigned = tour
+                .jobs()
+                .filter_map(|job| (self.job_fn)(job))
+                .filter(|position| Some(position.tour) != tour_idx)
+                .count();
+
+            // NOTE activities of the same job (e.g. pickup and delivery) follow each other with the same rank
+            let (breaks, _) = tour.all_activities().filter_map(|activity| self.get_rank(activity, tour_idx)).fold(
+                (0, None),
+                |(breaks, prev), rank| match prev {
+                    Some(prev) if prev > rank => (breaks + 1, Some(rank)),
+                    _ => (breaks, Some(rank)),
+                },
+            );
+
+            acc + reassigned as Cost * self.weights.reassignment + breaks as Cost * self.weights.sequence
+        })
+    }
+}
+
+impl FeatureObjective for MinimizeChangesObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let tour_idx = (self.actor_fn)(route_ctx.route().actor.as_ref());
+                match (self.job_fn)(job) {
+                    Some(position) if Some(position.tour) != tour_idx => self.weights.reassignment,
+                    _ => Cost::default(),
+                }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let tour_idx = (self.actor_fn)(route_ctx.route().actor.as_ref());
+                let Some(target) = self.get_rank(activity_ctx.target, tour_idx) else { return Cost::default() };
+
+                // NOTE only direct neighbours are considered, so it is an approximation of the fitness change
+                let prev = self.get_rank(activity_ctx.prev, tour_idx);
+                let next = activity_ctx.next.and_then(|next| self.get_rank(next, tour_idx));
+                let is_break = |lhs: Option<usize>, rhs: Option<usize>| match (lhs, rhs) {
+                    (Some(lhs), Some(rhs)) if lhs > rhs => 1.,
+                    _ => 0.,
+                };
+
+                let breaks = is_break(prev, Some(target)) + is_break(Some(target), next) - is_break(prev, next);
+
+                breaks * self.weights.sequence
+            }
+        }
+    }
+}
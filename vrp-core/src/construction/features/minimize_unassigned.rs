@@ -57,6 +57,10 @@ impl FeatureObjective for MinimizeUnassignedObjective {
             MoveContext::Activity { .. } => Cost::default(),
         }
     }
+
+    fn kind(&self) -> ObjectiveKind {
+        ObjectiveKind::MinUnassigned
+    }
 }
 
 /// Checks the edge case when at least one solution has no routes and amount of unassigned is
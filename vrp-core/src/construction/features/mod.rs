@@ -17,9 +17,18 @@ pub use self::fast_service::*;
 mod fleet_usage;
 pub use self::fleet_usage::*;
 
+mod job_preference;
+pub use self::job_preference::*;
+
+mod load_cost;
+pub use self::load_cost::*;
+
 mod locked_jobs;
 pub use self::locked_jobs::*;
 
+mod minimize_changes;
+pub use self::minimize_changes::*;
+
 mod minimize_unassigned;
 pub use self::minimize_unassigned::*;
 
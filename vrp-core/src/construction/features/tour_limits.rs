@@ -6,14 +6,53 @@ mod tour_limits_test;
 
 use super::*;
 use crate::construction::enablers::{calculate_travel_delta, ScheduleKeys};
-use crate::models::common::{Distance, Duration};
+use crate::models::common::{Demand, DemandDimension, Distance, Duration, LoadOps};
 use crate::models::problem::{Actor, TransportCost};
+use crate::models::solution::Activity;
 
 /// A function which returns activity size limit for given actor.
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 /// A function to resolve travel limit.
 pub type TravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
 
+/// A function to resolve minimum tour limits.
+pub type MinTourLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<MinTourLimits<T>> + Send + Sync>;
+
+/// Specifies minimum limits which should be met by a tour once the vehicle is used.
+#[derive(Clone, Debug, Default)]
+pub struct MinTourLimits<T: LoadOps> {
+    /// Minimum amount of job activities in the tour.
+    pub tour_size: Option<usize>,
+    /// Minimum tour duration.
+    pub duration: Option<Duration>,
+    /// Minimum total load handled in the tour.
+    pub load: Option<T>,
+}
+
+impl<T: LoadOps> MinTourLimits<T> {
+    /// Returns a deficit of given tour measurements comparing to the limits. A zero value means that all limits
+    /// are met. Tour size and duration deficits are relative to their limits, load deficit is either zero or one.
+    pub fn get_deficit(&self, tour_size: usize, duration: Duration, load: T) -> f64 {
+        let (tour_size_ok, duration_ok, load_ok) = self.check(tour_size, duration, load);
+
+        let tour_size_deficit =
+            self.tour_size.filter(|_| !tour_size_ok).map_or(0., |limit| (limit - tour_size) as f64 / limit as f64);
+        let duration_deficit = self.duration.filter(|_| !duration_ok).map_or(0., |limit| (limit - duration) / limit);
+        let load_deficit = if load_ok { 0. } else { 1. };
+
+        tour_size_deficit + duration_deficit + load_deficit
+    }
+
+    /// Checks whether tour size, duration and load limits are met respectively.
+    pub fn check(&self, tour_size: usize, duration: Duration, load: T) -> (bool, bool, bool) {
+        (
+            self.tour_size.is_none_or(|limit| tour_size >= limit),
+            self.duration.is_none_or(|limit| duration >= limit),
+            self.load.is_none_or(|limit| load.can_fit(&limit)),
+        )
+    }
+}
+
 /// Combines all keys needed for tour limits feature usage.
 #[derive(Clone)]
 pub struct TourLimitKeys {
@@ -63,6 +102,130 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates a feature which penalizes used tours not meeting minimum limits: tour size, duration and load.
+/// As such limits cannot be checked on insertion, this is a soft constraint evaluated on solution level.
+pub fn create_min_tour_limit_feature<T: LoadOps>(
+    name: &str,
+    limit_fn: MinTourLimitFn<T>,
+    duration_key: StateKey,
+    load_key: StateKey,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinTourLimitObjective { limit_fn: limit_fn.clone(), duration_key, load_key })
+        .with_state(MinTourLimitState::<T> { limit_fn, load_key, state_keys: vec![load_key] })
+        .build()
+}
+
+/// Returns total load handled in the tour: static pickups, static and dynamic deliveries.
+pub fn get_tour_load<'a, T: LoadOps>(activities: impl Iterator<Item = &'a Activity>) -> T {
+    activities
+        .filter_map(|activity| activity.job.as_ref())
+        .filter_map(|job| job.dimens.get_demand())
+        .fold(T::default(), |acc, demand: &Demand<T>| acc + get_demand_load(demand))
+}
+
+fn get_demand_load<T: LoadOps>(demand: &Demand<T>) -> T {
+    demand.pickup.0 + demand.delivery.0 + demand.delivery.1
+}
+
+struct MinTourLimitObjective<T: LoadOps> {
+    limit_fn: MinTourLimitFn<T>,
+    duration_key: StateKey,
+    load_key: StateKey,
+}
+
+impl<T: LoadOps> MinTourLimitObjective<T> {
+    fn get_route_deficit(&self, route_ctx: &RouteContext, limits: &MinTourLimits<T>) -> f64 {
+        if route_ctx.route().tour.has_jobs() {
+            let (tour_size, duration, load) = self.get_route_measurements(route_ctx);
+            limits.get_deficit(tour_size, duration, load)
+        } else {
+            0.
+        }
+    }
+
+    fn get_route_measurements(&self, route_ctx: &RouteContext) -> (usize, Duration, T) {
+        let state = route_ctx.state();
+
+        (
+            route_ctx.route().tour.job_activity_count(),
+            state.get_route_state::<Duration>(self.duration_key).cloned().unwrap_or_default(),
+            state.get_route_state::<T>(self.load_key).cloned().unwrap_or_default(),
+        )
+    }
+}
+
+impl<T: LoadOps> Objective for MinTourLimitObjective<T> {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| (self.limit_fn)(route_ctx.route().actor.as_ref()).map(|limits| (route_ctx, limits)))
+            .map(|(route_ctx, limits)| self.get_route_deficit(route_ctx, &limits))
+            .sum()
+    }
+}
+
+impl<T: LoadOps> FeatureObjective for MinTourLimitObjective<T> {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                (self.limit_fn)(route_ctx.route().actor.as_ref()).map_or(Cost::default(), |limits| {
+                    let (tour_size, duration, load) = self.get_route_measurements(route_ctx);
+                    let (job_size, job_load) = match job {
+                        Job::Single(single) => (1, single.dimens.get_demand().map(get_demand_load).unwrap_or_default()),
+                        Job::Multi(multi) => (
+                            multi.jobs.len(),
+                            multi
+                                .jobs
+                                .iter()
+                                .filter_map(|single| single.dimens.get_demand())
+                                .fold(T::default(), |acc, demand| acc + get_demand_load(demand)),
+                        ),
+                    };
+
+                    let new_deficit = limits.get_deficit(tour_size + job_size, duration, load + job_load);
+                    let old_deficit = self.get_route_deficit(route_ctx, &limits);
+
+                    new_deficit - old_deficit
+                })
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct MinTourLimitState<T: LoadOps> {
+    limit_fn: MinTourLimitFn<T>,
+    load_key: StateKey,
+    state_keys: Vec<StateKey>,
+}
+
+impl<T: LoadOps> FeatureState for MinTourLimitState<T> {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if (self.limit_fn)(route_ctx.route().actor.as_ref()).is_some() {
+            let load: T = get_tour_load(route_ctx.route().tour.all_activities());
+            route_ctx.state_mut().put_route_state(self.load_key, load);
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+
+    fn state_keys(&self) -> Iter<'_, StateKey> {
+        self.state_keys.iter()
+    }
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -29,6 +29,7 @@ pub fn create_minimize_transport_costs_feature(
         feature_keys,
         time_window_code,
         Box::new(|insertion_ctx| insertion_ctx.get_total_cost().unwrap_or_default()),
+        ObjectiveKind::MinCost,
     )
 }
 
@@ -53,6 +54,7 @@ pub fn create_minimize_duration_feature(
                 acc + route_ctx.state().get_route_state::<f64>(total_duration_key).cloned().unwrap_or(0.)
             })
         }),
+        ObjectiveKind::Other,
     )
 }
 
@@ -77,6 +79,7 @@ pub fn create_minimize_distance_feature(
                 acc + route_ctx.state().get_route_state::<f64>(total_distance_key).cloned().unwrap_or(0.)
             })
         }),
+        ObjectiveKind::Other,
     )
 }
 
@@ -87,6 +90,7 @@ fn create_feature(
     feature_keys: ScheduleKeys,
     time_window_code: ViolationCode,
     fitness_fn: Box<dyn Fn(&InsertionContext) -> f64 + Send + Sync>,
+    kind: ObjectiveKind,
 ) -> Result<Feature, GenericError> {
     FeatureBuilder::default()
         .with_name(name)
@@ -97,7 +101,7 @@ fn create_feature(
             time_window_code,
         })
         .with_state(TransportState::new(transport.clone(), activity.clone(), feature_keys.clone()))
-        .with_objective(TransportObjective { activity, transport, fitness_fn, feature_keys })
+        .with_objective(TransportObjective { activity, transport, fitness_fn, feature_keys, kind })
         .build()
 }
 
@@ -235,6 +239,7 @@ struct TransportObjective {
     transport: Arc<dyn TransportCost + Send + Sync>,
     fitness_fn: Box<dyn Fn(&InsertionContext) -> f64 + Send + Sync>,
     feature_keys: ScheduleKeys,
+    kind: ObjectiveKind,
 }
 
 impl TransportObjective {
@@ -322,6 +327,10 @@ impl FeatureObjective for TransportObjective {
             MoveContext::Activity { route_ctx, activity_ctx } => self.estimate_activity(route_ctx, activity_ctx),
         }
     }
+
+    fn kind(&self) -> ObjectiveKind {
+        self.kind
+    }
 }
 
 struct TransportState {
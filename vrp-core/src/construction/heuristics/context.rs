@@ -229,6 +229,7 @@ impl From<(InsertionContext, Option<TelemetryMetrics>)> for Solution {
                 .chain(solution_ctx.required.iter().map(|job| (job.clone(), UnassignmentInfo::Unknown)))
                 .collect(),
             telemetry,
+            is_final: false,
         }
     }
 }
@@ -274,8 +274,14 @@ impl InsertionHeuristic {
             let jobs = job_selector.select(&insertion_ctx).collect::<Vec<_>>();
             let routes = route_selector.select(&insertion_ctx, jobs.as_slice()).collect::<Vec<_>>();
 
-            let result =
-                self.insertion_evaluator.evaluate_all(&insertion_ctx, &jobs, &routes, leg_selection, result_selector);
+            let evaluate = |leg_selection: &LegSelection| {
+                self.insertion_evaluator.evaluate_all(&insertion_ctx, &jobs, &routes, leg_selection, result_selector)
+            };
+
+            let result = match (evaluate(leg_selection), leg_selection.fallback()) {
+                (InsertionResult::Failure(_), Some(fallback)) => evaluate(fallback),
+                (result, _) => result,
+            };
 
             match result {
                 InsertionResult::Success(success) => {
@@ -3,8 +3,10 @@
 mod selectors_test;
 
 use crate::construction::heuristics::*;
-use crate::models::problem::Job;
+use crate::models::common::Timestamp;
+use crate::models::problem::{Job, Jobs};
 use crate::models::solution::Leg;
+use crate::models::Problem;
 use crate::utils::*;
 use rand::prelude::*;
 use rosomaxa::utils::{map_reduce, parallel_collect, Random};
@@ -409,9 +411,37 @@ pub enum LegSelection {
     Stochastic(Arc<dyn Random + Send + Sync>),
     /// Exhaustive mode: all legs are selected.
     Exhaustive,
+    /// Granular mode: only legs adjacent to the given amount of job's nearest neighbours are selected.
+    /// When no feasible insertion is found, the fallback mode should be used instead.
+    Granular {
+        /// Jobs with precomputed neighbourhood.
+        jobs: Arc<Jobs>,
+        /// Amount of nearest neighbours to consider.
+        neighbours: usize,
+        /// A fallback leg selection mode.
+        fallback: Box<LegSelection>,
+    },
 }
 
 impl LegSelection {
+    /// Wraps leg selection into granular mode if granularity is specified in the problem's extras.
+    pub fn with_granularity(self, problem: &Problem) -> Self {
+        match (self, problem.extras.get_granularity()) {
+            (selection @ Self::Granular { .. }, _) | (selection, None) => selection,
+            (selection, Some(neighbours)) => {
+                Self::Granular { jobs: problem.jobs.clone(), neighbours, fallback: Box::new(selection) }
+            }
+        }
+    }
+
+    /// Returns a fallback mode which should be used when granular mode has not found any feasible insertion.
+    pub fn fallback(&self) -> Option<&LegSelection> {
+        match self {
+            Self::Granular { fallback, .. } => Some(fallback.as_ref()),
+            _ => None,
+        }
+    }
+
     /// Selects a best leg for insertion.
     pub(crate) fn sample_best<R, FM, FC>(
         &self,
@@ -427,6 +457,13 @@ impl LegSelection {
         FM: FnMut(Leg, R) -> ControlFlow<R, R>,
         FC: Fn(&R, &R) -> bool,
     {
+        if let Self::Granular { jobs, neighbours, .. } = self {
+            return Self::get_granular_legs(route_ctx, jobs, job, *neighbours, skip)
+                .into_iter()
+                .try_fold(init, |acc, leg| map_fn(leg, acc))
+                .unwrap_value();
+        }
+
         if let Some((sample_size, random)) = self.get_sample_data(route_ctx, job, skip) {
             route_ctx
                 .route()
@@ -446,6 +483,42 @@ impl LegSelection {
         }
     }
 
+    /// Returns legs adjacent to the job's nearest neighbours. An empty tour has no neighbours, so its legs
+    /// are returned as is.
+    fn get_granular_legs<'a>(
+        route_ctx: &'a RouteContext,
+        jobs: &Jobs,
+        job: &Job,
+        neighbours: usize,
+        skip: usize,
+    ) -> Vec<Leg<'a>> {
+        let tour = &route_ctx.route().tour;
+
+        if tour.job_count() == 0 {
+            return tour.legs().skip(skip).collect();
+        }
+
+        // NOTE tour keeps index of its jobs, so neighbours served by other routes are skipped cheaply
+        let profile = &route_ctx.route().actor.vehicle.profile;
+        let neighbours = jobs
+            .neighbors(profile, job, Timestamp::default())
+            .take(neighbours)
+            .filter(|(job, _)| tour.contains(job))
+            .map(|(job, _)| job)
+            .collect::<Vec<_>>();
+
+        if neighbours.is_empty() {
+            return vec![];
+        }
+
+        tour.legs()
+            .skip(skip)
+            .filter(|(items, _)| {
+                items.iter().filter_map(|activity| activity.retrieve_job()).any(|job| neighbours.contains(&&job))
+            })
+            .collect()
+    }
+
     /// Returns a sample data for stochastic mode.
     fn get_sample_data(
         &self,
@@ -476,7 +549,7 @@ impl LegSelection {
                     ))
                 }
             }
-            Self::Exhaustive => None,
+            Self::Exhaustive | Self::Granular { .. } => None,
         }
     }
 }
@@ -60,6 +60,10 @@ pub struct Solution {
 
     /// An optional telemetry metrics if available.
     pub telemetry: Option<TelemetryMetrics>,
+
+    /// Specifies whether the solution is a final result of the solver, not an intermediate or
+    /// externally provided one.
+    pub is_final: bool,
 }
 
 /// An enumeration which specifies how jobs should be ordered in tour.
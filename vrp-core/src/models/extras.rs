@@ -16,6 +16,11 @@ impl Extras {
     pub fn get_value_raw<T: 'static + Send + Sync>(&self, key: &str) -> Option<Arc<T>> {
         self.index.get(key).cloned().and_then(|any| any.downcast::<T>().ok())
     }
+
+    /// Returns an amount of job's nearest neighbours used to restrict insertion evaluation, if specified.
+    pub fn get_granularity(&self) -> Option<usize> {
+        self.get_value::<usize>("granularity").copied()
+    }
 }
 
 /// Provide the safe way to construct instance of `Extras`.
@@ -64,6 +69,13 @@ impl ExtrasBuilder {
         self
     }
 
+    /// Adds granularity: an amount of job's nearest neighbours which legs are considered when insertion is
+    /// evaluated. Exhaustive evaluation is used only when no feasible insertion is found.
+    pub fn with_granularity(&mut self, neighbours: usize) -> &mut Self {
+        self.0.set_value("granularity", neighbours);
+        self
+    }
+
     /// Adds a custom key-value pair to extras.
     pub fn with_custom_key<T: 'static + Sync + Send>(&mut self, key: &str, value: Arc<T>) -> &mut Self {
         self.0.index.insert(key.to_string(), value);
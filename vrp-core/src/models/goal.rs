@@ -185,6 +185,34 @@ impl GoalContext {
     pub fn constraints(&self) -> impl Iterator<Item = Arc<dyn FeatureConstraint + Send + Sync>> + '_ {
         self.constraints.iter().cloned()
     }
+
+    /// Returns kinds of global objectives in their hierarchical order.
+    pub fn objective_kinds(&self) -> Vec<Vec<ObjectiveKind>> {
+        self.global_objectives.iter().map(|objectives| objectives.iter().map(|o| o.kind()).collect()).collect()
+    }
+
+    /// Creates a new instance of `GoalContext` where given objective is added to the objectives of
+    /// the lowest priority (typically, a cost minimization): its fitness is added to fitness of the
+    /// last global objective and its estimate is summed with local objectives of the last level.
+    pub fn with_augmented_objective(
+        &self,
+        objective: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>,
+    ) -> Self {
+        let mut global_objectives = self.global_objectives.clone();
+        if let Some(last) = global_objectives.last_mut().and_then(|objectives| objectives.last_mut()) {
+            *last = Arc::new(AugmentedObjective { inner: last.clone(), augment: objective.clone() });
+        }
+
+        let mut local_objectives = self.local_objectives.clone();
+        match local_objectives.last_mut() {
+            Some(objectives) => objectives.push(objective),
+            None => local_objectives.push(vec![objective]),
+        }
+
+        let flatten_objectives = global_objectives.iter().flat_map(|inners| inners.iter()).cloned().collect();
+
+        Self { global_objectives, flatten_objectives, local_objectives, ..self.clone() }
+    }
 }
 
 impl Debug for GoalContext {
@@ -369,10 +397,29 @@ pub trait FeatureConstraint {
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode>;
 }
 
+/// Specifies a well-known kind of the feature objective which allows to reason about its properties
+/// without evaluating it, e.g. to decide whether exact search is applicable.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ObjectiveKind {
+    /// Minimizes amount of unassigned jobs.
+    MinUnassigned,
+    /// Minimizes amount of tours.
+    MinTours,
+    /// Minimizes total cost of the solution as returned by `InsertionContext::get_total_cost`.
+    MinCost,
+    /// Any other objective.
+    Other,
+}
+
 /// Defines feature objective behavior.
 pub trait FeatureObjective: Objective {
     /// Estimates a cost of insertion.
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost;
+
+    /// Returns a kind of the objective. Default implementation returns `ObjectiveKind::Other`.
+    fn kind(&self) -> ObjectiveKind {
+        ObjectiveKind::Other
+    }
 }
 
 impl MultiObjective for GoalContext {
@@ -511,3 +558,23 @@ impl GoalContext {
             .collect()
     }
 }
+
+/// An objective which adds fitness and estimate of another objective to the inner one.
+struct AugmentedObjective {
+    inner: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>,
+    augment: Arc<dyn FeatureObjective<Solution = InsertionContext> + Send + Sync>,
+}
+
+impl Objective for AugmentedObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.inner.fitness(solution) + self.augment.fitness(solution)
+    }
+}
+
+impl FeatureObjective for AugmentedObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        self.inner.estimate(move_ctx) + self.augment.estimate(move_ctx)
+    }
+}
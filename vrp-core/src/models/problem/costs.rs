@@ -3,6 +3,7 @@
 mod costs_test;
 
 use crate::models::common::*;
+use crate::models::problem::{TimeBand, TimeBandDimension};
 use crate::models::solution::{Activity, Route};
 use hashbrown::HashMap;
 use rosomaxa::prelude::GenericError;
@@ -78,6 +79,155 @@ pub trait TransportCost {
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance;
 }
 
+const SECONDS_IN_DAY: f64 = 86400.;
+
+/// Returns a duration of given time interval weighted by actor's time bands: time spent within a band is
+/// multiplied by the band's factor. When bands overlap, the max factor is used.
+pub fn get_time_band_duration(route: &Route, time: &TimeWindow) -> Duration {
+    let actor = route.actor.as_ref();
+    let duration = time.duration();
+
+    let get_bands =
+        || actor.vehicle.dimens.get_time_bands().into_iter().chain(actor.driver.dimens.get_time_bands()).flatten();
+
+    if duration <= 0. || get_bands().next().is_none() {
+        return duration;
+    }
+
+    let tour_start = route.tour.start().map_or(time.start, |start| start.schedule.departure);
+
+    let mut breakpoints = get_bands()
+        .flat_map(|band| match band {
+            TimeBand::Duration { start, .. } => vec![tour_start + *start],
+            TimeBand::TimeOfDay { start, end, .. } => {
+                let first_day = (time.start / SECONDS_IN_DAY).floor() as i64;
+                let last_day = (time.end / SECONDS_IN_DAY).floor() as i64;
+
+                (first_day..=last_day)
+                    .flat_map(|day| {
+                        let midnight = day as f64 * SECONDS_IN_DAY;
+                        [midnight + *start, midnight + *end]
+                    })
+                    .collect()
+            }
+        })
+        .filter(|&breakpoint| breakpoint > time.start && breakpoint < time.end)
+        .chain([time.start, time.end])
+        .collect::<Vec<_>>();
+
+    breakpoints.sort_by(|a, b| a.total_cmp(b));
+    breakpoints.dedup();
+
+    breakpoints
+        .windows(2)
+        .map(|interval| {
+            let (start, end) = (interval[0], interval[1]);
+            let middle = start + (end - start) / 2.;
+
+            let factor = get_bands()
+                .filter(|band| match band {
+                    TimeBand::Duration { start, .. } => middle >= tour_start + *start,
+                    TimeBand::TimeOfDay { start, end, .. } => {
+                        let time_of_day = middle.rem_euclid(SECONDS_IN_DAY);
+                        if start <= end {
+                            time_of_day >= *start && time_of_day < *end
+                        } else {
+                            time_of_day >= *start || time_of_day < *end
+                        }
+                    }
+                })
+                .map(|band| match band {
+                    TimeBand::Duration { factor, .. } | TimeBand::TimeOfDay { factor, .. } => *factor,
+                })
+                .max_by(|a, b| a.total_cmp(b))
+                .unwrap_or(1.);
+
+            (end - start) * factor
+        })
+        .sum()
+}
+
+/// Applies actor's time bands on top of inner activity costs: waiting and service time spent within
+/// a band is charged with the band's factor. Should be used only when some vehicle has time bands.
+pub struct TimeBandActivityCost {
+    inner: Arc<dyn ActivityCost + Send + Sync>,
+}
+
+impl TimeBandActivityCost {
+    /// Creates a new instance of `TimeBandActivityCost`.
+    pub fn new(inner: Arc<dyn ActivityCost + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+impl ActivityCost for TimeBandActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        let actor = route.actor.as_ref();
+        let cost = self.inner.cost(route, activity, arrival);
+
+        let service_start = arrival.max(activity.place.time.start);
+        let get_extra_duration = |time: TimeWindow| get_time_band_duration(route, &time) - time.duration();
+        let waiting = get_extra_duration(TimeWindow::new(arrival, service_start));
+        let service = get_extra_duration(TimeWindow::new(service_start, service_start + activity.place.duration));
+
+        cost + waiting * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time)
+            + service * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
+    }
+
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        self.inner.estimate_departure(route, activity, arrival)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        self.inner.estimate_arrival(route, activity, departure)
+    }
+}
+
+/// Applies actor's time bands on top of inner transport costs: driving time spent within a band is
+/// charged with the band's factor. Should be used only when some vehicle has time bands.
+pub struct TimeBandTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TimeBandTransportCost {
+    /// Creates a new instance of `TimeBandTransportCost`.
+    pub fn new(inner: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self { inner }
+    }
+}
+
+impl TransportCost for TimeBandTransportCost {
+    fn cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        let actor = route.actor.as_ref();
+        let cost = self.inner.cost(route, from, to, travel_time);
+
+        let duration = self.inner.duration(route, from, to, travel_time);
+        let time = match travel_time {
+            TravelTime::Arrival(arrival) => TimeWindow::new(arrival - duration, arrival),
+            TravelTime::Departure(departure) => TimeWindow::new(departure, departure + duration),
+        };
+        let extra_duration = get_time_band_duration(route, &time) - duration;
+
+        cost + extra_duration * (actor.driver.costs.per_driving_time + actor.vehicle.costs.per_driving_time)
+    }
+
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration(route, from, to, travel_time)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, travel_time)
+    }
+}
+
 /// Contains matrix routing data for specific profile and, optionally, time.
 pub struct MatrixData {
     /// A routing profile index.
@@ -25,6 +25,48 @@ pub struct Costs {
     pub per_service_time: f64,
 }
 
+/// Specifies a time band which multiplies time costs within its interval, e.g. overtime or night premium.
+#[derive(Clone, Debug)]
+pub enum TimeBand {
+    /// A band which starts once given duration since tour start is elapsed.
+    Duration {
+        /// A duration since tour start.
+        start: Duration,
+        /// A time cost factor.
+        factor: f64,
+    },
+    /// A daily recurring band specified by offsets since midnight. When `start` is greater than `end`,
+    /// the band wraps over midnight.
+    TimeOfDay {
+        /// A band start offset since midnight.
+        start: Timestamp,
+        /// A band end offset since midnight.
+        end: Timestamp,
+        /// A time cost factor.
+        factor: f64,
+    },
+}
+
+/// Provides a way to get or set time bands which change time costs (driving, waiting and service)
+/// of vehicle or driver within their intervals.
+pub trait TimeBandDimension {
+    /// Sets time bands.
+    fn set_time_bands(&mut self, time_bands: Vec<TimeBand>) -> &mut Self;
+    /// Gets time bands if present.
+    fn get_time_bands(&self) -> Option<&Vec<TimeBand>>;
+}
+
+impl TimeBandDimension for Dimensions {
+    fn set_time_bands(&mut self, time_bands: Vec<TimeBand>) -> &mut Self {
+        self.set_value("time_bands", time_bands);
+        self
+    }
+
+    fn get_time_bands(&self) -> Option<&Vec<TimeBand>> {
+        self.get_value("time_bands")
+    }
+}
+
 /// Represents driver detail (reserved for future use).
 #[derive(Clone, Hash, Eq, PartialEq)]
 pub struct DriverDetail {}
@@ -0,0 +1,265 @@
+//! Provides fast lower bounds on primary objectives which can be used to estimate how far
+//! a solution is from optimal.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/bounds_test.rs"]
+mod bounds_test;
+
+use crate::models::common::*;
+use crate::models::problem::{Actor, Job, Single, TimeBand, TimeBandDimension};
+use crate::models::Problem;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// Specifies lower bounds on primary objectives for a given set of jobs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LowerBounds {
+    /// A lower bound on total distance: a minimum spanning tree which connects job locations with
+    /// vehicle depots or, when there is a single vehicle, a 1-tree like bound.
+    pub distance: Distance,
+    /// A lower bound on total driving duration estimated the same way as distance.
+    pub duration: Duration,
+    /// A lower bound on amount of tours: LP relaxation of bin packing on total static demand and the
+    /// biggest vehicle capacity. It is not defined when vehicle capacity is not a limit.
+    pub tours: Option<usize>,
+    /// A lower bound on total cost: combines other bounds with the cheapest vehicle costs.
+    pub cost: Cost,
+}
+
+impl LowerBounds {
+    /// Estimates lower bounds on serving given jobs with problem's fleet. Routing costs are assumed
+    /// to satisfy the triangle inequality. `load_factor` is the minimum load dependent travel cost
+    /// factor among vehicles (see `create_load_dependent_cost_feature`): use one if load costs are not used.
+    pub fn new<T: LoadOps>(problem: &Problem, jobs: &[Job], use_capacity: bool, load_factor: f64) -> Self {
+        if jobs.is_empty() {
+            return Self { distance: 0., duration: 0., tours: Some(0), cost: 0. };
+        }
+
+        let singles = jobs
+            .iter()
+            .flat_map(|job| match job {
+                Job::Single(single) => vec![single.clone()],
+                Job::Multi(multi) => multi.jobs.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let actors = problem.fleet.actors.as_slice();
+        let locations = get_job_locations(singles.as_slice());
+        let transport = problem.transport.as_ref();
+
+        let distance = get_routing_bound(actors, locations.as_slice(), |from, to| {
+            problem
+                .fleet
+                .profiles
+                .iter()
+                .map(|profile| transport.distance_approx(profile, from, to))
+                .fold(Distance::MAX, |acc, distance| acc.min(distance.max(0.)))
+        });
+
+        let duration = get_routing_bound(actors, locations.as_slice(), |from, to| {
+            problem
+                .fleet
+                .profiles
+                .iter()
+                .map(|profile| transport.duration_approx(profile, from, to))
+                .fold(Duration::MAX, |acc, duration| acc.min(duration.max(0.)))
+        });
+
+        let tours = if use_capacity { get_tours_bound::<T>(actors, jobs) } else { None };
+
+        let service = singles
+            .iter()
+            .map(|single| single.places.iter().map(|place| place.duration).fold(Duration::MAX, Duration::min))
+            .filter(|duration| *duration < Duration::MAX)
+            .sum::<Duration>();
+
+        let cost = get_cost_bound(actors, tours.unwrap_or(1), distance, duration, service, load_factor);
+
+        Self { distance, duration, tours, cost }
+    }
+}
+
+/// Returns a relative gap between the value and its lower bound.
+pub fn get_relative_gap(value: f64, bound: f64) -> f64 {
+    if value > 0. {
+        ((value - bound) / value).max(0.)
+    } else {
+        0.
+    }
+}
+
+/// Returns unique job locations. Jobs with alternative places are skipped: the bound stays valid
+/// as serving less locations cannot be more expensive when the triangle inequality holds.
+fn get_job_locations(singles: &[Arc<Single>]) -> Vec<Location> {
+    singles
+        .iter()
+        .filter_map(|single| match single.places.as_slice() {
+            [place] => place.location,
+            _ => None,
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn get_routing_bound<F>(actors: &[Arc<Actor>], locations: &[Location], weight_fn: F) -> f64
+where
+    F: Fn(Location, Location) -> f64,
+{
+    if locations.is_empty() {
+        return 0.;
+    }
+
+    let weights = locations
+        .iter()
+        .map(|&from| locations.iter().map(|&to| weight_fn(from, to).min(weight_fn(to, from))).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    match actors {
+        // NOTE single tour is a path from start through all jobs to optional end: the path over jobs is
+        //      a spanning tree itself, so its cost is not less than the minimum one
+        [actor] => {
+            let get_min_edge = |location: Option<Location>, is_start: bool| {
+                location.map_or(0., |depot| {
+                    locations
+                        .iter()
+                        .map(|&job| if is_start { weight_fn(depot, job) } else { weight_fn(job, depot) })
+                        .fold(f64::MAX, f64::min)
+                })
+            };
+
+            let start = actor.detail.start.as_ref().map(|place| place.location);
+            let end = actor.detail.end.as_ref().map(|place| place.location);
+
+            get_min_spanning_tree(&weights) + get_min_edge(start, true) + get_min_edge(end, false)
+        }
+        // NOTE each tour connects its jobs with some depot, so all depots can be contracted into one node
+        _ => {
+            let depots = actors
+                .iter()
+                .flat_map(|actor| {
+                    actor
+                        .detail
+                        .start
+                        .iter()
+                        .map(|place| place.location)
+                        .chain(actor.detail.end.iter().map(|p| p.location))
+                })
+                .collect::<HashSet<_>>();
+
+            let depot_weights = locations
+                .iter()
+                .map(|&job| {
+                    depots
+                        .iter()
+                        .map(|&depot| weight_fn(depot, job).min(weight_fn(job, depot)))
+                        .fold(f64::MAX, f64::min)
+                })
+                .chain(std::iter::once(0.))
+                .collect::<Vec<_>>();
+
+            let weights = weights
+                .into_iter()
+                .zip(depot_weights.iter())
+                .map(|(mut row, depot_weight)| {
+                    row.push(*depot_weight);
+                    row
+                })
+                .chain(std::iter::once(depot_weights.clone()))
+                .collect::<Vec<_>>();
+
+            get_min_spanning_tree(&weights)
+        }
+    }
+}
+
+/// Calculates weight of minimum spanning tree using Prim's algorithm on dense symmetric weights.
+fn get_min_spanning_tree(weights: &[Vec<f64>]) -> f64 {
+    let size = weights.len();
+    if size == 0 {
+        return 0.;
+    }
+
+    let mut in_tree = vec![false; size];
+    let mut min_edges = vec![f64::MAX; size];
+    min_edges[0] = 0.;
+
+    (0..size).fold(0., |total, _| {
+        let (next, weight) = min_edges
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !in_tree[*idx])
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(idx, weight)| (idx, *weight))
+            .expect("no node left");
+
+        in_tree[next] = true;
+        weights[next].iter().enumerate().filter(|(idx, _)| !in_tree[*idx]).for_each(|(idx, weight)| {
+            min_edges[idx] = min_edges[idx].min(*weight);
+        });
+
+        total + weight
+    })
+}
+
+fn get_tours_bound<T: LoadOps>(actors: &[Arc<Actor>], jobs: &[Job]) -> Option<usize> {
+    let capacity = actors.iter().try_fold(T::default(), |acc, actor| {
+        actor.vehicle.dimens.get_capacity().map(|capacity: &T| acc.max_load(*capacity))
+    })?;
+
+    // NOTE static delivery is loaded at the tour start and static pickup is kept till its end, so each of them
+    //      cannot exceed vehicle capacity within one tour. Dynamic demand of pickup and delivery jobs is not
+    //      considered: such jobs can be served one after another within the same tour.
+    let (pickup, delivery) = jobs
+        .iter()
+        .flat_map(|job| match job {
+            Job::Single(single) => std::slice::from_ref(single),
+            Job::Multi(multi) => multi.jobs.as_slice(),
+        })
+        .filter_map(|single| single.dimens.get_demand())
+        .fold((T::default(), T::default()), |(pickup, delivery), demand: &Demand<T>| {
+            (pickup + demand.pickup.0, delivery + demand.delivery.0)
+        });
+
+    let demand = pickup.max_load(delivery);
+
+    if !demand.is_not_empty() {
+        return Some(1);
+    }
+
+    let ratio = demand.ratio(&capacity);
+
+    ratio.is_finite().then(|| (ratio.ceil() as usize).max(1))
+}
+
+fn get_cost_bound(
+    actors: &[Arc<Actor>],
+    tours: usize,
+    distance: Distance,
+    duration: Duration,
+    service: Duration,
+    load_factor: f64,
+) -> Cost {
+    let get_min_cost = |cost_fn: fn(&Actor) -> f64| actors.iter().map(|actor| cost_fn(actor)).fold(f64::MAX, f64::min);
+
+    // NOTE time bands can make time cheaper than its base cost
+    let time_factor = actors
+        .iter()
+        .flat_map(|actor| actor.vehicle.dimens.get_time_bands().into_iter().chain(actor.driver.dimens.get_time_bands()))
+        .flatten()
+        .map(|band| match band {
+            TimeBand::Duration { factor, .. } | TimeBand::TimeOfDay { factor, .. } => *factor,
+        })
+        .fold(1_f64, f64::min)
+        .max(0.);
+
+    let fixed = get_min_cost(|actor| actor.vehicle.costs.fixed + actor.driver.costs.fixed);
+    let per_distance = get_min_cost(|actor| actor.vehicle.costs.per_distance + actor.driver.costs.per_distance);
+    let per_driving = get_min_cost(|actor| actor.vehicle.costs.per_driving_time + actor.driver.costs.per_driving_time);
+    let per_service = get_min_cost(|actor| actor.vehicle.costs.per_service_time + actor.driver.costs.per_service_time);
+
+    // NOTE load factor scales travel costs only
+    let travel = (distance * per_distance + duration * per_driving * time_factor) * load_factor.max(0.);
+
+    (tours as f64 * fixed + travel + service * per_service * time_factor).max(0.)
+}
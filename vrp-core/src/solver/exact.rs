@@ -0,0 +1,433 @@
+//! Provides an exact solver for small problem instances.
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/exact_test.rs"]
+mod exact_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::{Actor, Costs, Job, TimeBand, TimeBandDimension, TravelTime};
+use crate::models::{CoreStateKeys, GoalContext, ObjectiveKind, Problem, Solution};
+use crate::solver::{RefinementContext, Solver};
+use rosomaxa::evolution::EvolutionConfig;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Specifies how good the solution is known to be.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Optimality {
+    /// The solution is proven to be optimal.
+    Optimal,
+    /// The search was interrupted by its budget: keeps a relative gap between solution cost and
+    /// its lower bound if it can be estimated.
+    Gap(Option<f64>),
+    /// The solution is found by metaheuristic, so nothing is known about its optimality.
+    Heuristic,
+}
+
+/// Solves small Vehicle Routing Problems exactly using depth first branch and bound search which
+/// builds routes job by job. When the problem is too large or contains features which are not supported
+/// by exact search, it falls back to metaheuristic `Solver`.
+///
+/// Exact search is applicable only when the global objective of the goal minimizes amount of unassigned
+/// jobs first, optionally amount of tours then, and total cost in the end. Solutions are compared using
+/// the goal, feasibility is checked using its hard constraints. Lower bound assumes that routing costs
+/// satisfy the triangle inequality, that time bands do not make travel cheaper, and that each unassigned
+/// job has positive estimate.
+pub struct ExactSolver {
+    problem: Arc<Problem>,
+    config: EvolutionConfig<RefinementContext, GoalContext, InsertionContext>,
+    max_jobs: usize,
+    max_nodes: usize,
+}
+
+impl ExactSolver {
+    /// Creates a new instance of `ExactSolver`. Provided config is used when fallback to
+    /// metaheuristic is needed.
+    pub fn new(
+        problem: Arc<Problem>,
+        config: EvolutionConfig<RefinementContext, GoalContext, InsertionContext>,
+    ) -> Self {
+        Self { problem, config, max_jobs: 30, max_nodes: 1_000_000 }
+    }
+
+    /// Sets max amount of jobs for which exact search is used. Default is 30.
+    pub fn with_max_jobs(mut self, max_jobs: usize) -> Self {
+        self.max_jobs = max_jobs;
+        self
+    }
+
+    /// Sets max amount of search nodes explored by exact search. Default is 1 000 000.
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Checks whether exact search can be applied to the problem.
+    pub fn is_applicable(&self) -> bool {
+        let problem = self.problem.as_ref();
+
+        problem.jobs.size() <= self.max_jobs
+            && problem.jobs.all().all(|job| job.as_single().is_some())
+            && problem.locks.is_empty()
+            && problem.extras.get_schedule_keys().is_some()
+            && get_goal_levels(problem.goal.as_ref()).is_some()
+            && !has_discount_time_bands(problem)
+    }
+
+    /// Solves a Vehicle Routing Problem and returns a solution with its optimality status.
+    pub fn solve(self) -> Result<(Solution, Optimality), GenericError> {
+        if !self.is_applicable() {
+            (self.config.context.environment.logger)("exact search is not applicable, fallback to metaheuristic");
+            return Solver::new(self.problem, self.config).solve().map(|solution| (solution, Optimality::Heuristic));
+        }
+
+        let environment = self.config.context.environment.clone();
+        (environment.logger)(&format!("exact search, total jobs: {}", self.problem.jobs.size()));
+
+        let (insertion_ctx, optimality) = solve_exactly(self.problem.clone(), environment.clone(), self.max_nodes)?;
+
+        (environment.logger)(&format!("exact search is finished: {optimality:?}"));
+
+        Ok((Solution { is_final: true, ..insertion_ctx.into() }, optimality))
+    }
+}
+
+/// Runs branch and bound search and returns the best found solution.
+fn solve_exactly(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    max_nodes: usize,
+) -> Result<(InsertionContext, Optimality), GenericError> {
+    let mut insertion_ctx = InsertionContext::new(problem.clone(), environment.clone());
+    prepare_insertion_ctx(&mut insertion_ctx);
+    let actors = problem.fleet.actors.clone();
+
+    // jobs which cannot be assigned to any empty route are excluded from the search
+    let (remaining, infeasible): (Vec<_>, Vec<_>) = insertion_ctx
+        .solution
+        .required
+        .iter()
+        .map(|job| (job.clone(), get_infeasible_code(&insertion_ctx, actors.as_slice(), job)))
+        .partition(|(_, code)| code.is_none());
+    let remaining = remaining.into_iter().map(|(job, _)| job).collect::<Vec<_>>();
+
+    infeasible.into_iter().for_each(|(job, code)| {
+        insertion_ctx.solution.required.retain(|required| *required != job);
+        insertion_ctx.solution.unassigned.insert(job, UnassignmentInfo::Simple(code.unwrap_or_default()));
+    });
+
+    let min_costs = get_min_incoming_costs(problem.as_ref(), remaining.as_slice());
+    let root_bound = remaining.iter().map(|job| min_costs.get(job).copied().unwrap_or_default()).sum::<Cost>();
+
+    let has_tours =
+        get_goal_levels(problem.goal.as_ref()).is_some_and(|levels| levels.contains(&ObjectiveKind::MinTours));
+
+    let mut search = BranchAndBound {
+        goal: problem.goal.clone(),
+        has_tours,
+        actors,
+        min_costs,
+        max_nodes,
+        quota: environment.quota.clone(),
+        nodes: 0,
+        best: None,
+        is_interrupted: false,
+    };
+
+    search.explore(insertion_ctx, 0, remaining);
+
+    let is_interrupted = search.is_interrupted;
+    let ((unassigned, _, cost), best_ctx) = search.best.ok_or_else(|| "cannot find any solution".to_string())?;
+
+    let optimality = if is_interrupted {
+        let gap = if unassigned == 0 {
+            Some(if cost > 0. { ((cost - root_bound) / cost).max(0.) } else { 0. })
+        } else {
+            None
+        };
+
+        Optimality::Gap(gap)
+    } else {
+        Optimality::Optimal
+    };
+
+    Ok((best_ctx, optimality))
+}
+
+/// Keeps value of the search node: amount of unassigned jobs, amount of tours and total cost.
+type NodeValue = (usize, usize, Cost);
+
+struct BranchAndBound {
+    goal: Arc<GoalContext>,
+    has_tours: bool,
+    actors: Vec<Arc<Actor>>,
+    min_costs: hashbrown::HashMap<Job, Cost>,
+    max_nodes: usize,
+    quota: Option<Arc<dyn Quota + Send + Sync>>,
+    nodes: usize,
+    best: Option<(NodeValue, InsertionContext)>,
+    is_interrupted: bool,
+}
+
+impl BranchAndBound {
+    /// Explores a search node: jobs are appended to the end of the route of the actor with given index,
+    /// actors are used in their order.
+    fn explore(&mut self, insertion_ctx: InsertionContext, actor_idx: usize, remaining: Vec<Job>) {
+        if self.is_out_of_budget() {
+            return;
+        }
+
+        self.nodes += 1;
+
+        let cost = insertion_ctx.get_total_cost().unwrap_or_default();
+        let tours = insertion_ctx.solution.routes.len();
+        let bound = remaining.iter().map(|job| self.min_costs.get(job).copied().unwrap_or_default()).sum::<Cost>();
+        let closing = self.get_closing_cost(&insertion_ctx, actor_idx);
+
+        if !self.can_improve(tours, cost - closing + bound) {
+            return;
+        }
+
+        if remaining.is_empty() || actor_idx >= self.actors.len() {
+            self.accept_leaf(insertion_ctx, (remaining.len(), tours, cost));
+            return;
+        }
+
+        // append one of remaining jobs to the current route, try the cheapest options first
+        let mut children = remaining
+            .iter()
+            .filter_map(|job| self.append_job(&insertion_ctx, actor_idx, job).map(|child| (job.clone(), child)))
+            .map(|(job, child)| (child.get_total_cost().unwrap_or_default(), job, child))
+            .collect::<Vec<_>>();
+        children.sort_by(|(a, ..), (b, ..)| compare_floats(*a, *b));
+
+        children.into_iter().for_each(|(_, job, child)| {
+            let remaining = remaining.iter().filter(|other| **other != job).cloned().collect();
+            self.explore(child, actor_idx, remaining);
+        });
+
+        // close the current route and continue with the next actor
+        if actor_idx + 1 < self.actors.len() {
+            self.explore(insertion_ctx.deep_copy(), actor_idx + 1, remaining.clone());
+        }
+
+        // leave remaining jobs unassigned
+        self.accept_leaf(insertion_ctx, (remaining.len(), tours, cost));
+    }
+
+    fn append_job(&self, insertion_ctx: &InsertionContext, actor_idx: usize, job: &Job) -> Option<InsertionContext> {
+        let actor = &self.actors[actor_idx];
+        let route_ctx =
+            insertion_ctx.solution.routes.iter().find(|route_ctx| route_ctx.route().actor == *actor).or_else(|| {
+                insertion_ctx.solution.registry.next_route().find(|route_ctx| route_ctx.route().actor == *actor)
+            })?;
+
+        let leg_selection = LegSelection::Exhaustive;
+        let result_selector = BestResultSelector::default();
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        match eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            route_ctx,
+            InsertionPosition::Last,
+            InsertionResult::make_failure(),
+        ) {
+            InsertionResult::Success(success) => {
+                let mut child = insertion_ctx.deep_copy();
+                apply_insertion_success(&mut child, success);
+                Some(child)
+            }
+            InsertionResult::Failure(_) => None,
+        }
+    }
+
+    /// Returns a cost of the leg which returns the route of the actor with given index to its end. The leg is
+    /// replaced when a job is appended to the route, so its cost is excluded from the lower bound.
+    fn get_closing_cost(&self, insertion_ctx: &InsertionContext, actor_idx: usize) -> Cost {
+        let actor = &self.actors[actor_idx];
+
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .find(|route_ctx| route_ctx.route().actor == *actor)
+            .and_then(|route_ctx| {
+                let route = route_ctx.route();
+                let end_idx = route.tour.end_idx()?;
+                let (last, end) = (route.tour.get(end_idx.checked_sub(1)?)?, route.tour.get(end_idx)?);
+
+                // NOTE open tour ends with a job activity, so there is no leg to replace
+                if end.job.is_some() {
+                    return None;
+                }
+
+                let departure = last.schedule.departure;
+                let distance = insertion_ctx.problem.transport.distance(
+                    route,
+                    last.place.location,
+                    end.place.location,
+                    TravelTime::Departure(departure),
+                );
+                let duration = end.schedule.arrival - departure;
+
+                // NOTE keep it consistent with the way how total cost is calculated
+                let get_cost = |costs: &Costs| {
+                    costs.per_distance * distance
+                        + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+                };
+
+                Some(get_cost(&actor.vehicle.costs) + get_cost(&actor.driver.costs))
+            })
+            .unwrap_or_default()
+    }
+
+    fn accept_leaf(&mut self, mut insertion_ctx: InsertionContext, value: NodeValue) {
+        finalize_insertion_ctx(&mut insertion_ctx);
+
+        if self.best.as_ref().is_none_or(|(_, best)| self.goal.total_order(&insertion_ctx, best) == Ordering::Less) {
+            self.best = Some((value, insertion_ctx));
+        }
+    }
+
+    /// Checks whether a leaf with given amount of tours and cost (or bigger) can be better than the best
+    /// known one. Unassigned jobs can be improved only if there are some of them in the best leaf.
+    fn can_improve(&self, tours: usize, cost: Cost) -> bool {
+        self.best.as_ref().is_none_or(|((unassigned, best_tours, best_cost), _)| {
+            let is_better_cost = || compare_floats(cost, *best_cost) == Ordering::Less;
+
+            *unassigned > 0
+                || match (self.has_tours, tours.cmp(best_tours)) {
+                    (true, Ordering::Less) => true,
+                    (true, Ordering::Greater) => false,
+                    _ => is_better_cost(),
+                }
+        })
+    }
+
+    fn is_out_of_budget(&mut self) -> bool {
+        if !self.is_interrupted {
+            self.is_interrupted =
+                self.nodes >= self.max_nodes || self.quota.as_ref().is_some_and(|quota| quota.is_reached());
+        }
+
+        self.is_interrupted
+    }
+}
+
+/// Returns kinds of goal's global objectives if they are supported by exact search: minimization of
+/// unassigned jobs, optionally minimization of tours and minimization of total cost, one per level.
+fn get_goal_levels(goal: &GoalContext) -> Option<Vec<ObjectiveKind>> {
+    let levels = goal
+        .objective_kinds()
+        .into_iter()
+        .map(|kinds| if kinds.len() == 1 { Some(kinds[0]) } else { None })
+        .collect::<Option<Vec<_>>>()?;
+
+    match levels.as_slice() {
+        [ObjectiveKind::MinUnassigned, ObjectiveKind::MinCost]
+        | [ObjectiveKind::MinUnassigned, ObjectiveKind::MinTours, ObjectiveKind::MinCost] => Some(levels),
+        _ => None,
+    }
+}
+
+/// Checks whether some of actors have time bands which make travel cheaper than its base cost.
+fn has_discount_time_bands(problem: &Problem) -> bool {
+    problem
+        .fleet
+        .actors
+        .iter()
+        .flat_map(|actor| actor.vehicle.dimens.get_time_bands().into_iter().chain(actor.driver.dimens.get_time_bands()))
+        .flatten()
+        .any(|band| match band {
+            TimeBand::Duration { factor, .. } | TimeBand::TimeOfDay { factor, .. } => *factor < 1.,
+        })
+}
+
+/// Returns a constraint violation code if the job cannot be assigned to any of empty routes.
+fn get_infeasible_code(insertion_ctx: &InsertionContext, actors: &[Arc<Actor>], job: &Job) -> Option<i32> {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    let mut code = None;
+    let is_feasible =
+        insertion_ctx.solution.registry.next_route().filter(|route_ctx| actors.contains(&route_ctx.route().actor)).any(
+            |route_ctx| match eval_job_insertion_in_route(
+                insertion_ctx,
+                &eval_ctx,
+                route_ctx,
+                InsertionPosition::Last,
+                InsertionResult::make_failure(),
+            ) {
+                InsertionResult::Success(_) => true,
+                InsertionResult::Failure(failure) => {
+                    code = Some(failure.constraint);
+                    false
+                }
+            },
+        );
+
+    if is_feasible {
+        None
+    } else {
+        Some(code.unwrap_or_default())
+    }
+}
+
+/// Returns for each job a minimal cost of the leg which ends at job's location. It is used as
+/// a lower bound for the cost to serve the job.
+fn get_min_incoming_costs(problem: &Problem, jobs: &[Job]) -> hashbrown::HashMap<Job, Cost> {
+    let get_locations = |job: &Job| -> Vec<Location> {
+        job.as_single()
+            .map(|single| single.places.iter().filter_map(|place| place.location).collect())
+            .unwrap_or_default()
+    };
+
+    let sources = jobs
+        .iter()
+        .flat_map(|job| get_locations(job).into_iter().map(move |location| (job, location)))
+        .collect::<Vec<_>>();
+    let starts =
+        problem.fleet.actors.iter().filter_map(|actor| actor.detail.start.as_ref().map(|start| start.location));
+
+    let get_leg_cost = |actor: &Actor, from: Location, to: Location| {
+        let transport = problem.transport.as_ref();
+        let profile = &actor.vehicle.profile;
+        let (vehicle, driver) = (&actor.vehicle.costs, &actor.driver.costs);
+
+        (vehicle.per_distance + driver.per_distance) * transport.distance_approx(profile, from, to)
+            + (vehicle.per_driving_time + driver.per_driving_time) * transport.duration_approx(profile, from, to)
+    };
+
+    let starts = starts.collect::<Vec<_>>();
+
+    jobs.iter()
+        .map(|job| {
+            let cost = get_locations(job)
+                .into_iter()
+                .flat_map(|to| {
+                    let from_jobs = sources.iter().filter(|(other, _)| *other != job).map(|(_, from)| *from);
+                    from_jobs.chain(starts.iter().copied()).flat_map(move |from| {
+                        problem.fleet.actors.iter().map(move |actor| get_leg_cost(actor, from, to))
+                    })
+                })
+                .min_by(|a, b| compare_floats(*a, *b))
+                .unwrap_or_default();
+
+            (job.clone(), cost.max(0.))
+        })
+        .collect()
+}
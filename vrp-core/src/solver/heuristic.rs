@@ -6,6 +6,7 @@ use crate::models::{CoreStateKeys, Extras, GoalContext};
 use crate::rosomaxa::get_default_selection_size;
 use crate::solver::search::*;
 use rosomaxa::algorithms::gsom::Input;
+use rosomaxa::evolution::strategies::{Alns, AlnsConfig};
 use rosomaxa::hyper::*;
 use rosomaxa::population::*;
 use rosomaxa::termination::*;
@@ -175,6 +176,17 @@ pub fn get_dynamic_heuristic(
     )
 }
 
+/// Gets adaptive large neighbourhood search strategy which uses the same search operators as dynamic heuristic.
+pub fn get_alns_strategy(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    config: AlnsConfig,
+) -> Alns<RefinementContext, GoalContext, InsertionContext> {
+    let search_operators = dynamic::get_operators(problem, environment);
+
+    Alns::new(search_operators, config, 1)
+}
+
 /// Creates elitism population algorithm.
 pub fn create_elitism_population(
     objective: Arc<GoalContext>,
@@ -475,10 +487,12 @@ mod statik {
 
         Arc::new(WeightedHeuristicOperator::new(
             vec![
-                Arc::new(RuinAndRecreate::new(ruin, recreate)),
+                Arc::new(RuinAndRecreate::new(ruin, recreate.clone())),
                 create_default_local_search(problem.as_ref(), environment.random.clone()),
+                Arc::new(CrossoverSearch::new(Arc::new(SelectiveRouteExchange::new(recreate.clone(), 3)))),
+                Arc::new(RoutePoolSearch::new(recreate, 8, 10_000, 100)),
             ],
-            vec![100, 10],
+            vec![100, 10, 5, 1],
         ))
     }
 
@@ -494,6 +508,10 @@ mod statik {
                 (Arc::new(ExchangeSwapStar::new(random, SINGLE_HEURISTIC_QUOTA_LIMIT)), 200),
                 (Arc::new(ExchangeInterRouteBest::default()), 100),
                 (Arc::new(ExchangeSequence::default()), 100),
+                (Arc::new(ExchangeTwoOptStar::default()), 50),
+                (Arc::new(ExchangeCross::default()), 50),
+                (Arc::new(ExchangeOrOpt::default()), 50),
+                (Arc::new(ExchangeTwoOpt::default()), 30),
                 (Arc::new(ExchangeInterRouteRandom::default()), 30),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 30),
                 (Arc::new(RescheduleDeparture::new(schedule_keys)), 20),
@@ -581,6 +599,10 @@ mod dynamic {
                 "local_exch_intra_route_random".to_string(),
                 1.,
             ),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeTwoOptStar::default()))), "local_two_opt_star".to_string(), 1.),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeCross::default()))), "local_cross".to_string(), 1.),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeOrOpt::default()))), "local_or_opt".to_string(), 1.),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeTwoOpt::default()))), "local_two_opt".to_string(), 1.),
             (
                 Arc::new(LocalSearch::new(Arc::new(RescheduleDeparture::new(schedule_keys.clone())))),
                 "local_reschedule_departure".to_string(),
@@ -594,6 +616,24 @@ mod dynamic {
                 "local_swap_star".to_string(),
                 10.,
             ),
+            (
+                Arc::new(CrossoverSearch::new(Arc::new(SelectiveRouteExchange::new(
+                    Arc::new(RecreateWithCheapest::new(environment.random.clone())),
+                    3,
+                )))),
+                "crossover_srex".to_string(),
+                5.,
+            ),
+            (
+                Arc::new(RoutePoolSearch::new(
+                    Arc::new(RecreateWithCheapest::new(environment.random.clone())),
+                    8,
+                    10_000,
+                    100,
+                )),
+                "route_pool".to_string(),
+                2.,
+            ),
             (
                 Arc::new(DecomposeSearch::new(
                     Arc::new(WeightedHeuristicOperator::new(
@@ -708,6 +748,10 @@ mod dynamic {
                 (Arc::new(ExchangeInterRouteRandom::default()), 1),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 1),
                 (Arc::new(ExchangeSequence::default()), 1),
+                (Arc::new(ExchangeTwoOptStar::default()), 1),
+                (Arc::new(ExchangeCross::default()), 1),
+                (Arc::new(ExchangeOrOpt::default()), 1),
+                (Arc::new(ExchangeTwoOpt::default()), 1),
             ],
             1,
             1,
@@ -104,6 +104,12 @@ use rosomaxa::utils::Timer;
 pub mod processing;
 pub mod search;
 
+mod bounds;
+pub use self::bounds::{get_relative_gap, LowerBounds};
+
+mod exact;
+pub use self::exact::{ExactSolver, Optimality};
+
 mod heuristic;
 
 /// A type which encapsulates information needed to perform solution refinement process.
@@ -293,8 +299,8 @@ impl Solver {
         let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
             .ok_or_else(|| "cannot find any solution".to_string())?;
 
-        let solution = (insertion_ctx, metrics).into();
+        let solution: Solution = (insertion_ctx, metrics).into();
 
-        Ok(solution)
+        Ok(Solution { is_final: true, ..solution })
     }
 }
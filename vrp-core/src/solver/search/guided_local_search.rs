@@ -0,0 +1,211 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/guided_local_search_test.rs"]
+mod guided_local_search_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::TransportCost;
+use crate::models::solution::Route;
+use crate::models::*;
+use crate::solver::*;
+use hashbrown::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// A search operator which implements Guided Local Search (GLS): it runs inner search (typically,
+/// a local search) with objective augmented by penalties of edges used by previously found local
+/// optima. Each time inner search finishes, edges of its solution with the highest utility (long
+/// edges which are not yet penalized much) get their penalty increased, so the search is guided
+/// away from them. Penalties decay over time, so the search can return to previously penalized edges.
+///
+/// In contrast to `InfeasibleSearch`, constraints are not relaxed: only objective is modified.
+pub struct GuidedLocalSearch {
+    inner_search: TargetSearchOperator,
+    penalties: RwLock<EdgePenalties>,
+    alpha: f64,
+    decay: f64,
+}
+
+impl GuidedLocalSearch {
+    /// Creates a new instance of `GuidedLocalSearch`, where:
+    /// - `alpha` is a penalty factor relative to the average edge distance of the solution
+    /// - `decay` is a factor applied to all penalties after each search in range (0, 1]
+    pub fn new(inner_search: TargetSearchOperator, alpha: f64, decay: f64) -> Self {
+        assert!(alpha > 0.);
+        assert!(decay > 0. && decay <= 1.);
+
+        Self { inner_search, penalties: RwLock::new(EdgePenalties::default()), alpha, decay }
+    }
+}
+
+impl HeuristicSearchOperator for GuidedLocalSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let insertion_ctx = solution;
+
+        let penalties = Arc::new(self.penalties.read().unwrap().clone());
+        let lambda = self.alpha * get_average_edge_distance(insertion_ctx);
+        let objective = Arc::new(EdgePenaltyObjective { penalties, lambda });
+
+        let augmented_insertion_ctx = create_augmented_insertion_ctx(insertion_ctx, objective);
+        let augmented_refinement_ctx = create_augmented_refinement_ctx(heuristic_ctx, &augmented_insertion_ctx);
+
+        let mut new_insertion_ctx = self.inner_search.search(&augmented_refinement_ctx, &augmented_insertion_ctx);
+        new_insertion_ctx.problem = insertion_ctx.problem.clone();
+
+        self.penalties.write().unwrap().update(&new_insertion_ctx, self.decay);
+
+        new_insertion_ctx
+    }
+}
+
+/// Keeps penalties of undirected edges.
+#[derive(Clone, Default)]
+struct EdgePenalties {
+    values: HashMap<(Location, Location), f64>,
+}
+
+impl EdgePenalties {
+    fn get(&self, from: Location, to: Location) -> f64 {
+        self.values.get(&get_edge_key(from, to)).copied().unwrap_or(0.)
+    }
+
+    /// Decays all penalties and increases penalties of edges with max utility in given solution.
+    fn update(&mut self, insertion_ctx: &InsertionContext, decay: f64) {
+        self.values.values_mut().for_each(|penalty| *penalty *= decay);
+        self.values.retain(|_, penalty| *penalty > 1E-2);
+
+        let transport = insertion_ctx.problem.transport.as_ref();
+        let utilities = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| get_edges(route_ctx.route(), transport))
+            .map(|(key, distance)| (key, distance / (1. + self.get(key.0, key.1))))
+            .collect::<Vec<_>>();
+
+        let max_utility = utilities.iter().map(|(_, utility)| *utility).fold(0., f64::max);
+        if max_utility > 0. {
+            utilities.into_iter().filter(|(_, utility)| *utility == max_utility).for_each(|(key, _)| {
+                *self.values.entry(key).or_insert(0.) += 1.;
+            });
+        }
+    }
+}
+
+/// An objective which estimates penalties of used edges scaled by lambda.
+struct EdgePenaltyObjective {
+    penalties: Arc<EdgePenalties>,
+    lambda: f64,
+}
+
+impl Objective for EdgePenaltyObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let penalty = solution
+            .solution
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let locations = get_locations(route_ctx.route()).collect::<Vec<_>>();
+                locations.windows(2).map(|pair| self.penalties.get(pair[0], pair[1])).sum::<f64>()
+            })
+            .sum::<f64>();
+
+        self.lambda * penalty
+    }
+}
+
+impl FeatureObjective for EdgePenaltyObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { activity_ctx, .. } => {
+                let prev = activity_ctx.prev.place.location;
+                let target = activity_ctx.target.place.location;
+
+                let penalty = self.penalties.get(prev, target)
+                    + activity_ctx.next.map_or(0., |next| {
+                        let next = next.place.location;
+                        self.penalties.get(target, next) - self.penalties.get(prev, next)
+                    });
+
+                self.lambda * penalty
+            }
+        }
+    }
+}
+
+fn get_edge_key(from: Location, to: Location) -> (Location, Location) {
+    if from < to {
+        (from, to)
+    } else {
+        (to, from)
+    }
+}
+
+fn get_locations(route: &Route) -> impl Iterator<Item = Location> + '_ {
+    route.tour.all_activities().map(|activity| activity.place.location)
+}
+
+/// Returns keys of route edges with their distances. Edges between the same locations are skipped.
+fn get_edges(route: &Route, transport: &(dyn TransportCost + Send + Sync)) -> Vec<((Location, Location), f64)> {
+    let profile = &route.actor.vehicle.profile;
+    let locations = get_locations(route).collect::<Vec<_>>();
+
+    locations
+        .windows(2)
+        .filter(|pair| pair[0] != pair[1])
+        .map(|pair| (get_edge_key(pair[0], pair[1]), transport.distance_approx(profile, pair[0], pair[1])))
+        .collect()
+}
+
+fn get_average_edge_distance(insertion_ctx: &InsertionContext) -> f64 {
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let (total, count) = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| get_edges(route_ctx.route(), transport))
+        .fold((0., 0), |(total, count), (_, distance)| (total + distance, count + 1));
+
+    if count > 0 {
+        total / count as f64
+    } else {
+        0.
+    }
+}
+
+fn create_augmented_insertion_ctx(
+    insertion_ctx: &InsertionContext,
+    objective: Arc<EdgePenaltyObjective>,
+) -> InsertionContext {
+    let problem = &insertion_ctx.problem;
+
+    let mut insertion_ctx = insertion_ctx.deep_copy();
+    insertion_ctx.problem = Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        goal: Arc::new(problem.goal.with_augmented_objective(objective)),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        extras: problem.extras.clone(),
+    });
+
+    insertion_ctx
+}
+
+fn create_augmented_refinement_ctx(
+    refinement_ctx: &RefinementContext,
+    insertion_ctx: &InsertionContext,
+) -> RefinementContext {
+    let problem = insertion_ctx.problem.clone();
+    let environment = refinement_ctx.environment.clone();
+    let population = Box::new(GreedyPopulation::new(problem.goal.clone(), 1, None));
+
+    RefinementContext::new(problem, population, TelemetryMode::None, environment)
+}
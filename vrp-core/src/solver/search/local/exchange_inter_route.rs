@@ -100,7 +100,8 @@ fn find_best_insertion_pair(
 
         let new_insertion_ctx = get_new_insertion_ctx(insertion_ctx, &seed_job, seed_route_idx).unwrap();
         let seed_route = new_insertion_ctx.solution.routes.get(seed_route_idx).unwrap();
-        let leg_selection = LegSelection::Stochastic(insertion_ctx.environment.random.clone());
+        let leg_selection = LegSelection::Stochastic(insertion_ctx.environment.random.clone())
+            .with_granularity(insertion_ctx.problem.as_ref());
         let result_selector = NoiseResultSelector::new(noise.clone());
 
         let insertion_pair = new_insertion_ctx
@@ -189,9 +190,12 @@ fn test_job_insertion(
         InsertionResult::make_failure(),
     );
 
-    match insertion {
-        InsertionResult::Failure(_) => None,
-        InsertionResult::Success(success) => Some(success),
+    match (insertion, leg_selection.fallback()) {
+        (InsertionResult::Success(success), _) => Some(success),
+        (InsertionResult::Failure(_), Some(fallback)) => {
+            test_job_insertion(insertion_ctx, route_ctx, job, fallback, result_selector)
+        }
+        (InsertionResult::Failure(_), None) => None,
     }
 }
 
@@ -0,0 +1,316 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_segments_test.rs"]
+mod exchange_segments_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::{Job, Multi};
+use crate::solver::search::LocalOperator;
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::*;
+
+const MAX_ATTEMPTS: usize = 4;
+
+/// A local search operator which implements classic 2-opt* move: it exchanges tails of two routes.
+/// A move is applied only if the whole resulting tails are feasible, e.g. in terms of time windows.
+#[derive(Default)]
+pub struct ExchangeTwoOptStar {}
+
+impl LocalOperator for ExchangeTwoOptStar {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_with_attempts(insertion_ctx, |insertion_ctx| {
+            let (first_idx, second_idx) = get_random_route_pair(insertion_ctx)?;
+            let random = insertion_ctx.environment.random.as_ref();
+
+            let first_cut = random.uniform_int(0, get_job_activity_count(insertion_ctx, first_idx) as i32) as usize;
+            let second_cut = random.uniform_int(0, get_job_activity_count(insertion_ctx, second_idx) as i32) as usize;
+
+            let first_tail = get_segment_jobs(insertion_ctx, first_idx, first_cut + 1, usize::MAX)?;
+            let second_tail = get_segment_jobs(insertion_ctx, second_idx, second_cut + 1, usize::MAX)?;
+
+            if first_tail.is_empty() && second_tail.is_empty() {
+                return None;
+            }
+
+            Some(swap_segments((first_idx, first_cut, first_tail), (second_idx, second_cut, second_tail)))
+        })
+    }
+}
+
+/// A local search operator which implements CROSS-exchange move: it exchanges two segments of
+/// jobs between two routes keeping their order. A move is applied only if it is feasible.
+pub struct ExchangeCross {
+    max_segment_size: usize,
+}
+
+impl ExchangeCross {
+    /// Creates a new instance of `ExchangeCross`.
+    pub fn new(max_segment_size: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { max_segment_size }
+    }
+}
+
+impl Default for ExchangeCross {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl LocalOperator for ExchangeCross {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_with_attempts(insertion_ctx, |insertion_ctx| {
+            let (first_idx, second_idx) = get_random_route_pair(insertion_ctx)?;
+
+            let (first_start, first_segment) = get_random_segment(insertion_ctx, first_idx, 0, self.max_segment_size)?;
+            let (second_start, second_segment) =
+                get_random_segment(insertion_ctx, second_idx, 0, self.max_segment_size)?;
+
+            if first_segment.is_empty() && second_segment.is_empty() {
+                return None;
+            }
+
+            Some(swap_segments(
+                (first_idx, first_start - 1, first_segment),
+                (second_idx, second_start - 1, second_segment),
+            ))
+        })
+    }
+}
+
+/// A local search operator which implements Or-opt move: it relocates a short segment of jobs
+/// to another position inside the same route keeping their order.
+pub struct ExchangeOrOpt {
+    max_segment_size: usize,
+}
+
+impl ExchangeOrOpt {
+    /// Creates a new instance of `ExchangeOrOpt`.
+    pub fn new(max_segment_size: usize) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { max_segment_size }
+    }
+}
+
+impl Default for ExchangeOrOpt {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl LocalOperator for ExchangeOrOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_with_attempts(insertion_ctx, |insertion_ctx| {
+            let route_idx = get_random_route_idx(insertion_ctx, 2)?;
+            let activities = get_job_activity_count(insertion_ctx, route_idx);
+            let max_segment_size = self.max_segment_size.min(activities - 1);
+
+            let (start, segment) = get_random_segment(insertion_ctx, route_idx, 1, max_segment_size)?;
+
+            let random = insertion_ctx.environment.random.as_ref();
+            let leg_idx = random.uniform_int(0, (activities - segment.len()) as i32) as usize;
+
+            if leg_idx == start - 1 {
+                return None;
+            }
+
+            Some(move |insertion_ctx: &mut InsertionContext| {
+                remove_jobs(insertion_ctx, route_idx, segment.as_slice());
+                insert_jobs_in_order(insertion_ctx, route_idx, segment, leg_idx)
+            })
+        })
+    }
+}
+
+/// A local search operator which implements 2-opt move: it reverses a segment of jobs inside
+/// the same route. A move is applied only if reversed segment is feasible.
+#[derive(Default)]
+pub struct ExchangeTwoOpt {}
+
+impl LocalOperator for ExchangeTwoOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        explore_with_attempts(insertion_ctx, |insertion_ctx| {
+            let route_idx = get_random_route_idx(insertion_ctx, 2)?;
+            let activities = get_job_activity_count(insertion_ctx, route_idx) as i32;
+
+            let random = insertion_ctx.environment.random.as_ref();
+            let start = random.uniform_int(1, activities - 1) as usize;
+            let end = random.uniform_int(start as i32 + 1, activities) as usize;
+
+            let mut segment = get_segment_jobs(insertion_ctx, route_idx, start, end + 1)?;
+
+            Some(move |insertion_ctx: &mut InsertionContext| {
+                remove_jobs(insertion_ctx, route_idx, segment.as_slice());
+                segment.reverse();
+                insert_jobs_in_order(insertion_ctx, route_idx, segment, start - 1)
+            })
+        })
+    }
+}
+
+/// Tries to apply a randomly selected move a few times. A move is selected using original context and
+/// applied on its copy: if the move is not feasible, the copy is discarded.
+fn explore_with_attempts<S, M>(insertion_ctx: &InsertionContext, select_move: S) -> Option<InsertionContext>
+where
+    S: Fn(&InsertionContext) -> Option<M>,
+    M: FnOnce(&mut InsertionContext) -> bool,
+{
+    (0..MAX_ATTEMPTS).find_map(|_| {
+        let apply_move = select_move(insertion_ctx)?;
+        let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+        if apply_move(&mut new_insertion_ctx) {
+            finalize_insertion_ctx(&mut new_insertion_ctx);
+            Some(new_insertion_ctx)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns a move which exchanges segments between two routes. Each segment is specified by route index,
+/// leg index where segment starts and its jobs.
+fn swap_segments(
+    first: (usize, usize, Vec<Job>),
+    second: (usize, usize, Vec<Job>),
+) -> impl FnOnce(&mut InsertionContext) -> bool {
+    move |insertion_ctx: &mut InsertionContext| {
+        let (first_idx, first_leg, first_segment) = first;
+        let (second_idx, second_leg, second_segment) = second;
+
+        remove_jobs(insertion_ctx, first_idx, first_segment.as_slice());
+        remove_jobs(insertion_ctx, second_idx, second_segment.as_slice());
+
+        insert_jobs_in_order(insertion_ctx, first_idx, second_segment, first_leg)
+            && insert_jobs_in_order(insertion_ctx, second_idx, first_segment, second_leg)
+    }
+}
+
+fn get_job_activity_count(insertion_ctx: &InsertionContext, route_idx: usize) -> usize {
+    insertion_ctx.solution.routes[route_idx].route().tour.job_activity_count()
+}
+
+fn get_route_indices(insertion_ctx: &InsertionContext, min_activities: usize) -> Vec<usize> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route().tour.job_activity_count() >= min_activities)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn get_random_route_idx(insertion_ctx: &InsertionContext, min_activities: usize) -> Option<usize> {
+    let route_indices = get_route_indices(insertion_ctx, min_activities);
+
+    if route_indices.is_empty() {
+        None
+    } else {
+        let idx = insertion_ctx.environment.random.uniform_int(0, route_indices.len() as i32 - 1) as usize;
+        route_indices.get(idx).copied()
+    }
+}
+
+fn get_random_route_pair(insertion_ctx: &InsertionContext) -> Option<(usize, usize)> {
+    let route_indices = get_route_indices(insertion_ctx, 1);
+
+    if route_indices.len() < 2 {
+        return None;
+    }
+
+    let random = insertion_ctx.environment.random.as_ref();
+    let first = random.uniform_int(0, route_indices.len() as i32 - 1) as usize;
+    let second = random.uniform_int(0, route_indices.len() as i32 - 2) as usize;
+    let second = if second >= first { second + 1 } else { second };
+
+    Some((route_indices[first], route_indices[second]))
+}
+
+/// Returns a random segment of movable jobs with its start activity index.
+fn get_random_segment(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    min_size: usize,
+    max_size: usize,
+) -> Option<(usize, Vec<Job>)> {
+    let activities = get_job_activity_count(insertion_ctx, route_idx);
+    let max_size = max_size.min(activities);
+
+    if min_size > max_size {
+        return None;
+    }
+
+    let random = insertion_ctx.environment.random.as_ref();
+    let size = random.uniform_int(min_size as i32, max_size as i32) as usize;
+    let start = random.uniform_int(1, (activities - size + 1) as i32) as usize;
+
+    get_segment_jobs(insertion_ctx, route_idx, start, start + size).map(|segment| (start, segment))
+}
+
+/// Returns jobs of activities in range [start, end) if all of them can be moved: only single jobs
+/// which are not locked are considered.
+fn get_segment_jobs(insertion_ctx: &InsertionContext, route_idx: usize, start: usize, end: usize) -> Option<Vec<Job>> {
+    let end = end.min(get_job_activity_count(insertion_ctx, route_idx) + 1);
+
+    (start..end)
+        .map(|activity_idx| {
+            insertion_ctx.solution.routes[route_idx]
+                .route()
+                .tour
+                .get(activity_idx)
+                .and_then(|activity| activity.job.as_ref())
+                .filter(|single| Multi::roots(single).is_none())
+                .map(|single| Job::Single(single.clone()))
+                .filter(|job| !insertion_ctx.solution.locked.contains(job))
+        })
+        .collect()
+}
+
+fn remove_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: &[Job]) {
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+
+    jobs.iter().for_each(|job| {
+        assert!(route_ctx.route_mut().tour.remove(job));
+    });
+
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+}
+
+/// Inserts jobs into the route in the exact order starting from given leg.
+/// Returns false if at least one insertion is not feasible.
+fn insert_jobs_in_order(
+    insertion_ctx: &mut InsertionContext,
+    route_idx: usize,
+    jobs: Vec<Job>,
+    leg_idx: usize,
+) -> bool {
+    let leg_selection = LegSelection::Exhaustive;
+    let result_selector = BestResultSelector::default();
+
+    jobs.into_iter().enumerate().all(|(offset, job)| {
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job: &job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        let insertion = eval_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            insertion_ctx.solution.routes.get(route_idx).unwrap(),
+            InsertionPosition::Concrete(leg_idx + offset),
+            InsertionResult::make_failure(),
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => {
+                apply_insertion_success(insertion_ctx, success);
+                true
+            }
+            InsertionResult::Failure(_) => false,
+        }
+    })
+}
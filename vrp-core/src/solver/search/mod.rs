@@ -27,6 +27,9 @@ pub(crate) use self::utils::*;
 mod decompose_search;
 pub use self::decompose_search::DecomposeSearch;
 
+mod guided_local_search;
+pub use self::guided_local_search::GuidedLocalSearch;
+
 mod infeasible_search;
 pub use self::infeasible_search::InfeasibleSearch;
 
@@ -36,6 +39,12 @@ pub use self::local_search::LocalSearch;
 mod redistribute_search;
 pub use self::redistribute_search::RedistributeSearch;
 
+mod route_exchange;
+pub use self::route_exchange::SelectiveRouteExchange;
+
+mod route_pool_search;
+pub use self::route_pool_search::RoutePoolSearch;
+
 mod ruin_recreate;
 pub use self::ruin_recreate::RuinAndRecreate;
 
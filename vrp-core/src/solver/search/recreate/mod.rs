@@ -93,12 +93,13 @@ impl Recreate for ConfigurableRecreate {
             ResultSelection::Concrete(concrete) => concrete.as_ref(),
             ResultSelection::Stochastic(provider) => provider.pick(),
         };
+        let leg_selection = self.leg_selection.clone().with_granularity(insertion_ctx.problem.as_ref());
 
         self.insertion_heuristic.process(
             insertion_ctx,
             self.job_selector.as_ref(),
             self.route_selector.as_ref(),
-            &self.leg_selection,
+            &leg_selection,
             result_selector,
         )
     }
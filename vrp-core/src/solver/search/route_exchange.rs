@@ -0,0 +1,144 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/route_exchange_test.rs"]
+mod route_exchange_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::construction::probing::repair_solution_from_unknown;
+use crate::models::problem::{Actor, Job};
+use hashbrown::HashSet;
+use rosomaxa::prelude::*;
+use rosomaxa::utils::SelectionSamplingIterator;
+use std::sync::Arc;
+
+/// A crossover operator which implements a variant of Selective Route Exchange (SREX): it copies a few
+/// routes from the second parent into the first one, removes their jobs from the rest of the first
+/// parent's routes and inserts remaining unassigned jobs using recreate method.
+pub struct SelectiveRouteExchange {
+    recreate: Arc<dyn Recreate + Send + Sync>,
+    max_routes: usize,
+}
+
+impl SelectiveRouteExchange {
+    /// Creates a new instance of `SelectiveRouteExchange`.
+    pub fn new(recreate: Arc<dyn Recreate + Send + Sync>, max_routes: usize) -> Self {
+        assert!(max_routes > 0);
+
+        Self { recreate, max_routes }
+    }
+}
+
+impl HeuristicCrossoverOperator for SelectiveRouteExchange {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn crossover(
+        &self,
+        heuristic_ctx: &Self::Context,
+        first: &Self::Solution,
+        second: &Self::Solution,
+    ) -> Self::Solution {
+        let mut insertion_ctx = first.deep_copy();
+
+        let donor_routes = select_donor_routes(&insertion_ctx, second, self.max_routes);
+        if donor_routes.is_empty() {
+            return insertion_ctx;
+        }
+
+        if !exchange_routes(&mut insertion_ctx, donor_routes.as_slice()) {
+            return first.deep_copy();
+        }
+
+        // NOTE routes from different solutions can violate solution wide constraints, e.g. shared resources
+        let insertion_ctx = repair_solution_from_unknown(&insertion_ctx, &|| {
+            InsertionContext::new(first.problem.clone(), first.environment.clone())
+        });
+
+        self.recreate.run(heuristic_ctx, insertion_ctx)
+    }
+}
+
+/// Selects a random subset of routes from donor solution. Routes with locked jobs are not exchanged.
+fn select_donor_routes<'a>(
+    insertion_ctx: &InsertionContext,
+    donor_ctx: &'a InsertionContext,
+    max_routes: usize,
+) -> Vec<&'a RouteContext> {
+    let solution = &insertion_ctx.solution;
+    let has_locked_jobs =
+        |route_ctx: &RouteContext| route_ctx.route().tour.jobs().any(|job| solution.locked.contains(job));
+    let has_locked_recipient = |actor: &Arc<Actor>| {
+        solution.routes.iter().any(|route_ctx| route_ctx.route().actor == *actor && has_locked_jobs(route_ctx))
+    };
+
+    let candidates = donor_ctx
+        .solution
+        .routes
+        .iter()
+        .filter(|route_ctx| route_ctx.route().tour.has_jobs())
+        .filter(|route_ctx| !has_locked_jobs(route_ctx) && !has_locked_recipient(&route_ctx.route().actor))
+        .collect::<Vec<_>>();
+
+    if candidates.is_empty() {
+        return Vec::default();
+    }
+
+    let random = insertion_ctx.environment.random.clone();
+    let amount = random.uniform_int(1, max_routes.min(candidates.len()) as i32) as usize;
+
+    SelectionSamplingIterator::new(candidates.into_iter(), amount, random).collect()
+}
+
+/// Replaces routes of the same actors with donor ones and removes donor jobs from other routes.
+/// Returns false when some of donor actors cannot be used in the solution.
+fn exchange_routes(insertion_ctx: &mut InsertionContext, donor_routes: &[&RouteContext]) -> bool {
+    let actors = donor_routes.iter().map(|route_ctx| route_ctx.route().actor.clone()).collect::<HashSet<_>>();
+    let donor_jobs =
+        donor_routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).cloned().collect::<HashSet<_>>();
+
+    let goal = insertion_ctx.problem.goal.clone();
+    let solution = &mut insertion_ctx.solution;
+
+    // remove routes of the same actors, their jobs need to be reinserted
+    let removed_jobs = solution
+        .routes
+        .iter()
+        .filter(|route_ctx| actors.contains(&route_ctx.route().actor))
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs().cloned())
+        .filter(|job| !donor_jobs.contains(job))
+        .collect::<Vec<Job>>();
+    solution.keep_routes(&|route_ctx| !actors.contains(&route_ctx.route().actor));
+
+    // remove donor jobs from the rest of routes
+    solution.routes.iter_mut().for_each(|route_ctx| {
+        let jobs = route_ctx.route().tour.jobs().filter(|job| donor_jobs.contains(*job)).cloned().collect::<Vec<_>>();
+
+        if !jobs.is_empty() {
+            jobs.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+            goal.accept_route_state(route_ctx);
+        }
+    });
+
+    solution.required.retain(|job| !donor_jobs.contains(job));
+    solution.ignored.retain(|job| !donor_jobs.contains(job));
+    solution.unassigned.retain(|job, _| !donor_jobs.contains(job));
+
+    if !donor_routes.iter().all(|donor_route| solution.registry.get_route(&donor_route.route().actor).is_some()) {
+        return false;
+    }
+
+    donor_routes.iter().for_each(|donor_route| {
+        let mut route_ctx = donor_route.deep_copy();
+        goal.accept_route_state(&mut route_ctx);
+        solution.routes.push(route_ctx);
+    });
+
+    removed_jobs.into_iter().for_each(|job| {
+        solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+    });
+
+    true
+}
@@ -0,0 +1,352 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/route_pool_search_test.rs"]
+mod route_pool_search_test;
+
+use crate::construction::heuristics::*;
+use crate::construction::probing::repair_solution_from_unknown;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job, TransportCost, TravelTime};
+use crate::models::solution::Route;
+use crate::models::GoalContext;
+use crate::solver::search::Recreate;
+use crate::solver::RefinementContext;
+use hashbrown::{HashMap, HashSet};
+use rosomaxa::prelude::*;
+use rosomaxa::utils::Timer;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A search operator which recombines routes from multiple solutions of the population: it collects
+/// them into a route pool and solves set partitioning problem over it using a simple branch and bound
+/// method within node and time budget. Jobs which are not covered by selected routes are inserted
+/// back using recreate method.
+pub struct RoutePoolSearch {
+    recreate: Arc<dyn Recreate + Send + Sync>,
+    max_solutions: usize,
+    max_nodes: usize,
+    max_time_ms: u128,
+}
+
+impl RoutePoolSearch {
+    /// Creates a new instance of `RoutePoolSearch`.
+    /// `max_solutions` specifies how many solutions from the population are used to build a route pool,
+    /// `max_nodes` and `max_time_ms` limit branch and bound search.
+    pub fn new(
+        recreate: Arc<dyn Recreate + Send + Sync>,
+        max_solutions: usize,
+        max_nodes: usize,
+        max_time_ms: u128,
+    ) -> Self {
+        assert!(max_solutions > 0);
+        assert!(max_nodes > 0);
+
+        Self { recreate, max_solutions, max_nodes, max_time_ms }
+    }
+}
+
+impl HeuristicSearchOperator for RoutePoolSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let solutions = std::iter::once(solution)
+            .chain(heuristic_ctx.ranked().map(|(insertion_ctx, _)| insertion_ctx).take(self.max_solutions))
+            .collect::<Vec<_>>();
+
+        let pool = RoutePool::new(solution, solutions.as_slice());
+        if pool.columns.is_empty() {
+            return solution.deep_copy();
+        }
+
+        let selected = pool.solve(self.max_nodes, self.max_time_ms);
+        let insertion_ctx = if let Some(insertion_ctx) = pool.create_solution(solution, selected.as_slice()) {
+            insertion_ctx
+        } else {
+            return solution.deep_copy();
+        };
+        // NOTE routes from different solutions can violate solution wide constraints, e.g. shared resources
+        let insertion_ctx = repair_solution_from_unknown(&insertion_ctx, &|| {
+            InsertionContext::new(solution.problem.clone(), solution.environment.clone())
+        });
+
+        self.recreate.run(heuristic_ctx, insertion_ctx)
+    }
+}
+
+/// Specifies a route candidate in the route pool.
+struct Column<'a> {
+    route_ctx: &'a RouteContext,
+    actor_idx: usize,
+    job_indices: Vec<usize>,
+    cost: Cost,
+}
+
+/// Keeps route pool with jobs and actors represented by their indices.
+struct RoutePool<'a> {
+    columns: Vec<Column<'a>>,
+    /// Indices of columns which cover given job sorted by their cost.
+    job_columns: Vec<Vec<usize>>,
+    /// Order in which jobs are considered for branching: the most constrained jobs go first.
+    job_order: Vec<usize>,
+    /// Indices of columns taken from original solution.
+    initial: Vec<usize>,
+    actor_count: usize,
+}
+
+impl<'a> RoutePool<'a> {
+    /// Creates a route pool from given solutions. Routes of the original solution with locked jobs are
+    /// kept as is, so routes which share jobs or actors with them are not part of the pool.
+    fn new(original: &InsertionContext, solutions: &[&'a InsertionContext]) -> Self {
+        let locked = &original.solution.locked;
+        let transport = original.problem.transport.as_ref();
+
+        let (fixed_jobs, fixed_actors): (HashSet<_>, HashSet<_>) = original
+            .solution
+            .routes
+            .iter()
+            .filter(|route_ctx| route_ctx.route().tour.jobs().any(|job| locked.contains(job)))
+            .fold((HashSet::default(), HashSet::default()), |(mut jobs, mut actors), route_ctx| {
+                jobs.extend(route_ctx.route().tour.jobs().cloned());
+                actors.insert(route_ctx.route().actor.clone());
+                (jobs, actors)
+            });
+
+        let mut jobs: HashMap<Job, usize> = HashMap::default();
+        let mut actors: HashMap<Arc<Actor>, usize> = HashMap::default();
+        let mut unique: HashMap<(usize, Vec<usize>), usize> = HashMap::default();
+        let mut columns: Vec<Column> = Vec::default();
+        let mut initial = Vec::default();
+
+        solutions.iter().enumerate().for_each(|(solution_idx, insertion_ctx)| {
+            insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .filter(|route_ctx| route_ctx.route().tour.has_jobs())
+                .filter(|route_ctx| !fixed_actors.contains(&route_ctx.route().actor))
+                .filter(|route_ctx| route_ctx.route().tour.jobs().all(|job| !fixed_jobs.contains(job)))
+                .for_each(|route_ctx| {
+                    let route = route_ctx.route();
+
+                    let next_actor_idx = actors.len();
+                    let actor_idx = *actors.entry(route.actor.clone()).or_insert(next_actor_idx);
+
+                    let mut job_indices = route
+                        .tour
+                        .jobs()
+                        .map(|job| {
+                            let next_job_idx = jobs.len();
+                            *jobs.entry(job.clone()).or_insert(next_job_idx)
+                        })
+                        .collect::<Vec<_>>();
+                    job_indices.sort_unstable();
+
+                    let cost = get_route_cost(route, transport);
+
+                    let column_idx = match unique.get(&(actor_idx, job_indices.clone())).copied() {
+                        Some(column_idx) if compare_floats(columns[column_idx].cost, cost) != Ordering::Greater => {
+                            column_idx
+                        }
+                        Some(column_idx) => {
+                            columns[column_idx] = Column { route_ctx, actor_idx, job_indices, cost };
+                            column_idx
+                        }
+                        None => {
+                            unique.insert((actor_idx, job_indices.clone()), columns.len());
+                            columns.push(Column { route_ctx, actor_idx, job_indices, cost });
+                            columns.len() - 1
+                        }
+                    };
+
+                    if solution_idx == 0 {
+                        initial.push(column_idx);
+                    }
+                });
+        });
+
+        let mut job_columns = vec![Vec::default(); jobs.len()];
+        columns.iter().enumerate().for_each(|(column_idx, column)| {
+            column.job_indices.iter().for_each(|&job_idx| job_columns[job_idx].push(column_idx));
+        });
+        job_columns.iter_mut().for_each(|indices| {
+            indices.sort_by(|&a, &b| compare_floats(columns[a].cost, columns[b].cost));
+        });
+
+        let mut job_order = (0..jobs.len()).collect::<Vec<_>>();
+        job_order.sort_by_key(|&job_idx| job_columns[job_idx].len());
+
+        Self { columns, job_columns, job_order, initial, actor_count: actors.len() }
+    }
+
+    /// Solves set partitioning problem: maximizes amount of covered jobs first and minimizes total cost
+    /// then. Each job and each actor can be used at most once. Returns indices of selected columns.
+    fn solve(&self, max_nodes: usize, max_time_ms: u128) -> Vec<usize> {
+        let initial_bound = self.get_bound(self.initial.as_slice());
+
+        let mut search = BranchAndBound {
+            pool: self,
+            timer: Timer::start(),
+            max_nodes,
+            max_time_ms,
+            nodes: 0,
+            job_states: vec![false; self.job_columns.len()],
+            used_actors: vec![false; self.actor_count],
+            selected: Vec::default(),
+            best: (initial_bound, self.initial.clone()),
+        };
+
+        search.explore(0, (0, Cost::default()));
+
+        search.best.1
+    }
+
+    /// Creates a new solution from the original one with routes replaced by selected columns.
+    /// Returns `None` when some of selected routes' actors cannot be used in the solution.
+    fn create_solution(&self, original: &InsertionContext, selected: &[usize]) -> Option<InsertionContext> {
+        let mut insertion_ctx = original.deep_copy();
+        let goal = insertion_ctx.problem.goal.clone();
+        let solution = &mut insertion_ctx.solution;
+        let locked = solution.locked.clone();
+
+        let has_locked_jobs = |route_ctx: &RouteContext| route_ctx.route().tour.jobs().any(|job| locked.contains(job));
+
+        let removed_jobs = solution
+            .routes
+            .iter()
+            .filter(|route_ctx| !has_locked_jobs(route_ctx))
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs().cloned())
+            .collect::<Vec<_>>();
+        solution.keep_routes(&has_locked_jobs);
+
+        let selected_routes = selected.iter().map(|&column_idx| self.columns[column_idx].route_ctx).collect::<Vec<_>>();
+        let selected_jobs =
+            selected_routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()).cloned().collect::<HashSet<_>>();
+
+        solution.required.retain(|job| !selected_jobs.contains(job));
+        solution.ignored.retain(|job| !selected_jobs.contains(job));
+        solution.unassigned.retain(|job, _| !selected_jobs.contains(job));
+
+        if !selected_routes.iter().all(|route_ctx| solution.registry.get_route(&route_ctx.route().actor).is_some()) {
+            return None;
+        }
+
+        selected_routes.into_iter().for_each(|route_ctx| {
+            let mut route_ctx = route_ctx.deep_copy();
+            goal.accept_route_state(&mut route_ctx);
+            solution.routes.push(route_ctx);
+        });
+
+        removed_jobs.into_iter().filter(|job| !selected_jobs.contains(job)).for_each(|job| {
+            solution.unassigned.insert(job, UnassignmentInfo::Unknown);
+        });
+
+        Some(insertion_ctx)
+    }
+
+    fn get_bound(&self, selected: &[usize]) -> (usize, Cost) {
+        let covered = selected.iter().map(|&column_idx| self.columns[column_idx].job_indices.len()).sum::<usize>();
+        let cost = selected.iter().map(|&column_idx| self.columns[column_idx].cost).sum::<Cost>();
+
+        (self.job_columns.len() - covered, cost)
+    }
+}
+
+/// A depth first branch and bound search over the route pool.
+struct BranchAndBound<'a, 'b> {
+    pool: &'b RoutePool<'a>,
+    timer: Timer,
+    max_nodes: usize,
+    max_time_ms: u128,
+    nodes: usize,
+    /// Specifies whether the job is already decided: covered by a selected column or left uncovered.
+    job_states: Vec<bool>,
+    used_actors: Vec<bool>,
+    selected: Vec<usize>,
+    best: ((usize, Cost), Vec<usize>),
+}
+
+impl BranchAndBound<'_, '_> {
+    fn explore(&mut self, order_idx: usize, current: (usize, Cost)) {
+        if self.is_out_of_budget() || !is_better(current, self.best.0) {
+            return;
+        }
+
+        self.nodes += 1;
+
+        let pool = self.pool;
+        let Some((order_idx, job_idx)) = pool
+            .job_order
+            .iter()
+            .enumerate()
+            .skip(order_idx)
+            .find(|(_, &job_idx)| !self.job_states[job_idx])
+            .map(|(order_idx, &job_idx)| (order_idx, job_idx))
+        else {
+            self.best = (current, self.selected.clone());
+            return;
+        };
+
+        // branch on each column which covers the job
+        pool.job_columns[job_idx].iter().for_each(|&column_idx| {
+            let column = &pool.columns[column_idx];
+            let is_compatible = !self.used_actors[column.actor_idx]
+                && column.job_indices.iter().all(|&job_idx| !self.job_states[job_idx]);
+
+            if !is_compatible {
+                return;
+            }
+
+            self.set_column(column_idx, true);
+            self.explore(order_idx + 1, (current.0, current.1 + column.cost));
+            self.set_column(column_idx, false);
+        });
+
+        // branch where the job is left uncovered
+        self.job_states[job_idx] = true;
+        self.explore(order_idx + 1, (current.0 + 1, current.1));
+        self.job_states[job_idx] = false;
+    }
+
+    fn set_column(&mut self, column_idx: usize, is_selected: bool) {
+        let column = &self.pool.columns[column_idx];
+
+        column.job_indices.iter().for_each(|&job_idx| self.job_states[job_idx] = is_selected);
+        self.used_actors[column.actor_idx] = is_selected;
+
+        if is_selected {
+            self.selected.push(column_idx);
+        } else {
+            self.selected.pop();
+        }
+    }
+
+    fn is_out_of_budget(&self) -> bool {
+        self.nodes >= self.max_nodes || self.timer.elapsed_millis() > self.max_time_ms
+    }
+}
+
+fn is_better(candidate: (usize, Cost), best: (usize, Cost)) -> bool {
+    match candidate.0.cmp(&best.0) {
+        Ordering::Less => true,
+        Ordering::Greater => false,
+        Ordering::Equal => compare_floats(candidate.1, best.1) == Ordering::Less,
+    }
+}
+
+/// Estimates route cost as a sum of fixed and transport costs.
+fn get_route_cost(route: &Route, transport: &(dyn TransportCost + Send + Sync)) -> Cost {
+    let costs = route.actor.vehicle.costs.fixed + route.actor.driver.costs.fixed;
+
+    route.tour.legs().fold(costs, |acc, (items, _)| match items {
+        [from, to] => {
+            acc + transport.cost(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            )
+        }
+        _ => acc,
+    })
+}
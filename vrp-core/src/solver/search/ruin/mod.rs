@@ -30,6 +30,9 @@ pub use self::route_removal::*;
 mod random_job_removal;
 pub use self::random_job_removal::RandomJobRemoval;
 
+mod time_slack_removal;
+pub use self::time_slack_removal::TimeSlackRemoval;
+
 mod worst_jobs_removal;
 pub use self::worst_jobs_removal::WorstJobRemoval;
 
@@ -3,7 +3,10 @@
 mod route_removal_test;
 
 use super::*;
+use crate::algorithms::geometry::{get_convex_hull, get_convex_overlap_area, Point};
+use crate::models::common::{Location, ValueDimension};
 use crate::models::problem::Actor;
+use crate::models::Extras;
 use crate::solver::search::JobRemovalTracker;
 use crate::solver::RefinementContext;
 use rand::prelude::SliceRandom;
@@ -134,6 +137,110 @@ impl Ruin for WorstRouteRemoval {
     }
 }
 
+/// A type to map location to its coordinates in 2D space.
+pub type LocationCoordinatesFn = Arc<dyn Fn(Location) -> Option<Point> + Send + Sync>;
+
+/// Provides way to get/set location coordinates used by geometry aware ruin methods.
+pub trait LocationCoordinates {
+    /// Gets location coordinates function.
+    fn get_location_coordinates(&self) -> Option<LocationCoordinatesFn>;
+
+    /// Sets location coordinates function.
+    fn set_location_coordinates(&mut self, coordinates_fn: LocationCoordinatesFn);
+}
+
+impl LocationCoordinates for Extras {
+    fn get_location_coordinates(&self) -> Option<LocationCoordinatesFn> {
+        self.get_value("location_coordinates").cloned()
+    }
+
+    fn set_location_coordinates(&mut self, coordinates_fn: LocationCoordinatesFn) {
+        self.set_value("location_coordinates", coordinates_fn);
+    }
+}
+
+/// Removes routes which convex hulls overlap other routes the most, so crossing tours can be
+/// untangled by recreate. Requires location coordinates to be set in problem extras, otherwise
+/// does nothing.
+pub struct OverlapRouteRemoval {
+    limits: RemovalLimits,
+}
+
+impl OverlapRouteRemoval {
+    /// Creates a new instance of `OverlapRouteRemoval`.
+    pub fn new(limits: RemovalLimits) -> Self {
+        Self { limits }
+    }
+}
+
+impl Ruin for OverlapRouteRemoval {
+    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        if insertion_ctx.solution.routes.len() < 2 {
+            return insertion_ctx;
+        }
+
+        let Some(coordinates_fn) = insertion_ctx.problem.extras.get_location_coordinates() else {
+            return insertion_ctx;
+        };
+
+        let random = insertion_ctx.environment.random.clone();
+
+        let mut route_overlaps = get_route_overlaps(&insertion_ctx.solution, coordinates_fn.as_ref())
+            .into_iter()
+            .enumerate()
+            .filter(|(_, overlap)| *overlap > 0.)
+            .collect::<Vec<_>>();
+        route_overlaps.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        route_overlaps.truncate(self.limits.affected_routes_range.end.max(2));
+
+        let shuffle_amount = (route_overlaps.len() as f64 * 0.25) as usize;
+        route_overlaps.partial_shuffle(&mut random.get_rng(), shuffle_amount);
+
+        #[allow(clippy::needless_collect)]
+        let routes = route_overlaps
+            .iter()
+            .filter_map(|(idx, _)| insertion_ctx.solution.routes.get(*idx))
+            .map(|route_ctx| route_ctx.route().actor.clone())
+            .collect::<Vec<_>>();
+
+        remove_routes_with_actors(&mut insertion_ctx.solution, &self.limits, random.as_ref(), routes.into_iter());
+
+        insertion_ctx
+    }
+}
+
+/// Returns total area of convex hull overlap with other routes for each route in solution.
+fn get_route_overlaps(solution_ctx: &SolutionContext, coordinates_fn: &dyn Fn(Location) -> Option<Point>) -> Vec<f64> {
+    let hulls = solution_ctx
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            let points = route_ctx
+                .route()
+                .tour
+                .all_activities()
+                .filter(|activity| activity.job.is_some())
+                .filter_map(|activity| coordinates_fn(activity.place.location))
+                .collect::<Vec<_>>();
+
+            get_convex_hull(points.as_slice())
+        })
+        .collect::<Vec<_>>();
+
+    hulls
+        .iter()
+        .enumerate()
+        .map(|(idx, hull)| {
+            hulls
+                .iter()
+                .enumerate()
+                .filter(|(other_idx, _)| *other_idx != idx)
+                .map(|(_, other)| get_convex_overlap_area(hull.as_slice(), other.as_slice()))
+                .sum()
+        })
+        .collect()
+}
+
 fn remove_routes_with_actors<Iter>(
     solution_ctx: &mut SolutionContext,
     limits: &RemovalLimits,
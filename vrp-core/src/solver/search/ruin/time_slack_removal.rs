@@ -0,0 +1,84 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/ruin/time_slack_removal_test.rs"]
+mod time_slack_removal_test;
+
+use super::*;
+use crate::models::problem::Job;
+use crate::models::solution::Route;
+use crate::solver::search::JobRemovalTracker;
+
+/// A ruin strategy which removes jobs from routes with the least time window slack: such routes
+/// are tightly scheduled, so it is hard to improve them by moving jobs one by one. Jobs with the
+/// least slack are removed first. Routes without time windows are ignored.
+pub struct TimeSlackRemoval {
+    limits: RemovalLimits,
+}
+
+impl TimeSlackRemoval {
+    /// Creates a new instance of `TimeSlackRemoval`.
+    pub fn new(limits: RemovalLimits) -> Self {
+        Self { limits }
+    }
+}
+
+impl Ruin for TimeSlackRemoval {
+    fn run(&self, _refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let random = insertion_ctx.environment.random.clone();
+
+        let mut route_slacks = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .map(|(route_idx, route_ctx)| (route_idx, get_job_slacks(route_ctx.route())))
+            .filter_map(|(route_idx, job_slacks)| {
+                job_slacks.first().map(|(_, slack)| *slack).map(|slack| (route_idx, slack, job_slacks))
+            })
+            .collect::<Vec<_>>();
+        route_slacks.sort_by(|(_, a, _), (_, b, _)| a.total_cmp(b));
+        route_slacks.truncate(self.limits.affected_routes_range.end);
+
+        let shuffle_amount = (route_slacks.len() as f64 * 0.25) as usize;
+        route_slacks.partial_shuffle(&mut random.get_rng(), shuffle_amount);
+
+        let mut tracker = JobRemovalTracker::new(&self.limits, random.as_ref());
+
+        for (route_idx, _, job_slacks) in route_slacks {
+            for (job, _) in job_slacks {
+                if tracker.is_limit() {
+                    return insertion_ctx;
+                }
+
+                tracker.try_remove_job(&mut insertion_ctx.solution, route_idx, &job);
+            }
+        }
+
+        insertion_ctx
+    }
+}
+
+/// Returns jobs of the route with finite time window slack sorted by slack ascending. A slack of
+/// the activity is a difference between its time window end and actual service start time. Jobs
+/// with the same slack keep their tour order, so the result is deterministic.
+fn get_job_slacks(route: &Route) -> Vec<(Job, f64)> {
+    let mut job_slacks = route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job().map(|job| (job, activity)))
+        .filter(|(_, activity)| activity.place.time.end < f64::MAX)
+        .fold(Vec::<(Job, f64)>::default(), |mut acc, (job, activity)| {
+            let service_start = activity.schedule.arrival.max(activity.place.time.start);
+            let slack = (activity.place.time.end - service_start).max(0.);
+
+            match acc.iter_mut().find(|(other, _)| *other == job) {
+                Some((_, value)) => *value = value.min(slack),
+                None => acc.push((job, slack)),
+            }
+
+            acc
+        });
+
+    job_slacks.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    job_slacks
+}
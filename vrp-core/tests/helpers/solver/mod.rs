@@ -169,8 +169,14 @@ pub fn generate_matrix_routes(
         extras: Arc::new(extras),
     };
 
-    let solution =
-        Solution { cost: Cost::default(), registry, routes, unassigned: Default::default(), telemetry: None };
+    let solution = Solution {
+        cost: Cost::default(),
+        registry,
+        routes,
+        unassigned: Default::default(),
+        telemetry: None,
+        is_final: false,
+    };
 
     (problem, solution)
 }
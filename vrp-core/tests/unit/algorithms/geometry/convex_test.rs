@@ -0,0 +1,76 @@
+use super::*;
+
+fn create_square(min: f64, max: f64) -> Vec<Point> {
+    vec![Point::new(min, min), Point::new(max, min), Point::new(max, max), Point::new(min, max)]
+}
+
+fn to_tuples(points: Vec<Point>) -> Vec<(f64, f64)> {
+    points.into_iter().map(|point| (point.x, point.y)).collect()
+}
+
+#[test]
+fn can_build_convex_hull() {
+    let points = vec![
+        Point::new(0., 0.),
+        Point::new(2., 2.),
+        Point::new(1., 1.),
+        Point::new(2., 0.),
+        Point::new(0., 2.),
+        Point::new(1., 0.),
+        Point::new(0., 0.),
+    ];
+
+    let hull = get_convex_hull(points.as_slice());
+
+    assert_eq!(to_tuples(hull), vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.)]);
+}
+
+parameterized_test! {can_build_degenerate_convex_hull, (points, expected), {
+    can_build_degenerate_convex_hull_impl(points, expected);
+}}
+
+can_build_degenerate_convex_hull! {
+    case01_empty: (vec![], 0),
+    case02_single: (vec![(1., 1.)], 1),
+    case03_same: (vec![(1., 1.), (1., 1.)], 1),
+    case04_collinear: (vec![(0., 0.), (1., 1.), (2., 2.)], 2),
+}
+
+fn can_build_degenerate_convex_hull_impl(points: Vec<(f64, f64)>, expected: usize) {
+    let points = points.into_iter().map(|(x, y)| Point::new(x, y)).collect::<Vec<_>>();
+
+    let hull = get_convex_hull(points.as_slice());
+
+    assert_eq!(hull.len(), expected);
+    assert_eq!(get_polygon_area(hull.as_slice()), 0.);
+}
+
+parameterized_test! {can_get_convex_overlap_area, (first, second, expected), {
+    can_get_convex_overlap_area_impl(first, second, expected);
+}}
+
+can_get_convex_overlap_area! {
+    case01_same: ((0., 2.), (0., 2.), 4.),
+    case02_partial: ((0., 2.), (1., 3.), 1.),
+    case03_inside: ((0., 4.), (1., 2.), 1.),
+    case04_disjoint: ((0., 1.), (2., 3.), 0.),
+    case05_touching: ((0., 1.), (1., 2.), 0.),
+}
+
+fn can_get_convex_overlap_area_impl(first: (f64, f64), second: (f64, f64), expected: f64) {
+    let first = create_square(first.0, first.1);
+    let second = create_square(second.0, second.1);
+
+    let result = get_convex_overlap_area(first.as_slice(), second.as_slice());
+
+    assert!((result - expected).abs() < 1E-9);
+}
+
+#[test]
+fn can_get_triangle_overlap_area() {
+    let triangle = get_convex_hull(&[Point::new(0., 0.), Point::new(4., 0.), Point::new(0., 4.)]);
+    let square = create_square(0., 2.);
+
+    assert_eq!(get_polygon_area(triangle.as_slice()), 8.);
+    assert!((get_convex_overlap_area(triangle.as_slice(), square.as_slice()) - 4.).abs() < 1E-9);
+}
@@ -0,0 +1,30 @@
+use super::*;
+
+fn create_square(min: f64, max: f64) -> Vec<Point> {
+    vec![Point::new(min, min), Point::new(max, min), Point::new(max, max), Point::new(min, max)]
+}
+
+parameterized_test! {can_check_point_inside_polygon, (point, expected), {
+    can_check_point_inside_polygon_impl(Point::new(point.0, point.1), expected);
+}}
+
+can_check_point_inside_polygon! {
+    case01_inside: ((1., 1.), true),
+    case02_outside: ((11., 1.), false),
+    case03_outside_negative: ((-1., 5.), false),
+    case04_inside_hole: ((5., 5.), false),
+    case05_inside_near_hole: ((3., 5.), true),
+}
+
+fn can_check_point_inside_polygon_impl(point: Point, expected: bool) {
+    let polygon = Polygon::new(vec![create_square(0., 10.), create_square(4., 6.)]);
+
+    assert_eq!(polygon.contains(&point), expected);
+}
+
+#[test]
+fn can_handle_degenerate_polygon() {
+    let polygon = Polygon::new(vec![vec![Point::new(0., 0.), Point::new(1., 1.)]]);
+
+    assert!(!polygon.contains(&Point::new(0.5, 0.5)));
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::construction::heuristics::InsertionContextBuilder;
+use crate::helpers::models::problem::{get_job_id, get_vehicle_id, SingleBuilder};
+use crate::helpers::models::solution::*;
+
+fn create_test_feature() -> Feature {
+    create_job_preference_feature(
+        "job_preference",
+        Arc::new(|actor, job| match (get_vehicle_id(&actor.vehicle).as_str(), get_job_id(job).as_str()) {
+            ("v1", "job1") => 0.,
+            ("v1", "job2") => 5.,
+            _ => 1.,
+        }),
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_estimate_job_preference, (job_id, expected), {
+    can_estimate_job_preference_impl(job_id, expected);
+}}
+
+can_estimate_job_preference! {
+    case_01_satisfied: ("job1", 0.),
+    case_02_unsatisfied: ("job2", 5.),
+    case_03_other: ("job3", 1.),
+}
+
+fn can_estimate_job_preference_impl(job_id: &str, expected: Cost) {
+    let objective = create_test_feature().objective.unwrap();
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let solution_ctx = InsertionContextBuilder::default().build().solution;
+
+    let result = objective.estimate(&MoveContext::route(
+        &solution_ctx,
+        &route_ctx,
+        &SingleBuilder::default().id(job_id).build_as_job_ref(),
+    ));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let objective = create_test_feature().objective.unwrap();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(["job1", "job2", "job3"].into_iter().map(|job_id| {
+                    ActivityBuilder::default().job(Some(SingleBuilder::default().id(job_id).build_shared())).build()
+                }))
+                .build(),
+        )
+        .build();
+    let insertion_ctx = InsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, 6.);
+}
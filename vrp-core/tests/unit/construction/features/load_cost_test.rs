@@ -0,0 +1,90 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::InsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+use crate::models::problem::Fleet;
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(10).build())
+        .build()
+}
+
+fn create_test_feature(capacity_key: StateKey) -> Feature {
+    create_load_dependent_cost_feature::<SingleDimLoad>(
+        create_minimize_tours_feature("min_tours").unwrap(),
+        TestTransportCost::new_shared(),
+        Arc::new(|_, ratio| 1. + ratio),
+        capacity_key,
+    )
+    .unwrap()
+}
+
+fn create_test_route_ctx(fleet: &Fleet, capacity_key: StateKey, loads: Vec<i32>) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(fleet, "v1")
+                .add_activity(ActivityBuilder::with_location(10).build())
+                .add_activity(ActivityBuilder::with_location(20).build())
+                .build(),
+        )
+        .build();
+    route_ctx.state_mut().put_activity_states(capacity_key, loads.into_iter().map(SingleDimLoad::new).collect());
+
+    route_ctx
+}
+
+parameterized_test! {can_calculate_fitness, (loads, expected), {
+    can_calculate_fitness_impl(loads, expected);
+}}
+
+can_calculate_fitness! {
+    case01_empty_vehicle: (vec![0, 0, 0, 0], 1.),
+    case02_full_vehicle_on_first_leg: (vec![10, 0, 0, 0], 41.),
+    case03_half_loaded_vehicle: (vec![10, 5, 0, 0], 61.),
+    case04_all_legs_loaded: (vec![10, 10, 10, 10], 161.),
+}
+
+fn can_calculate_fitness_impl(loads: Vec<i32>, expected: Cost) {
+    let capacity_key = StateKeyRegistry::default().next_key();
+    let fleet = create_test_fleet();
+    let feature = create_test_feature(capacity_key);
+    let insertion_ctx = InsertionContextBuilder::default()
+        .with_routes(vec![create_test_route_ctx(&fleet, capacity_key, loads)])
+        .build();
+
+    let result = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert!((result - expected).abs() < 1E-6);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (demand, expected), {
+    can_estimate_activity_insertion_impl(demand, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case01_delivery: (-4, 8.),
+    case02_pickup: (6, 12.),
+    case03_no_demand: (0, 0.),
+}
+
+fn can_estimate_activity_insertion_impl(demand: i32, expected: Cost) {
+    let capacity_key = StateKeyRegistry::default().next_key();
+    let fleet = create_test_fleet();
+    let feature = create_test_feature(capacity_key);
+    let route_ctx = create_test_route_ctx(&fleet, capacity_key, vec![0, 0, 0, 0]);
+    let route = route_ctx.route();
+    let target = ActivityBuilder::with_location(15)
+        .job(Some(SingleBuilder::default().demand(create_simple_demand(demand)).build_shared()))
+        .build();
+    let activity_ctx =
+        ActivityContext { index: 1, prev: route.tour.get(1).unwrap(), target: &target, next: route.tour.get(2) };
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert!((result - expected).abs() < 1E-6);
+}
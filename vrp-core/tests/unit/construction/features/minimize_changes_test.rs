@@ -0,0 +1,109 @@
+use super::*;
+use crate::helpers::construction::heuristics::InsertionContextBuilder;
+use crate::helpers::models::problem::{get_job_id, get_vehicle_id, SingleBuilder};
+use crate::helpers::models::solution::*;
+
+const REASSIGNMENT_WEIGHT: Cost = 2.;
+const SEQUENCE_WEIGHT: Cost = 0.5;
+
+fn create_test_feature() -> Feature {
+    create_minimize_changes_feature(
+        "minimize_changes",
+        Arc::new(|job| match get_job_id(job).as_str() {
+            "job5" => Some(ReferencePosition { tour: 1, rank: 0 }),
+            "job6" => None,
+            job_id => job_id.trim_start_matches("job").parse().ok().map(|rank| ReferencePosition { tour: 0, rank }),
+        }),
+        Arc::new(|actor| if get_vehicle_id(&actor.vehicle) == "v1" { Some(0) } else { None }),
+        MinimizeChangesWeights { reassignment: REASSIGNMENT_WEIGHT, sequence: SEQUENCE_WEIGHT },
+    )
+    .unwrap()
+}
+
+fn create_activity(job_id: &str) -> Activity {
+    ActivityBuilder::default().job(Some(SingleBuilder::default().id(job_id).build_shared())).build()
+}
+
+fn create_route_ctx(job_ids: &[&str]) -> RouteContext {
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(job_ids.iter().map(|job_id| create_activity(job_id)))
+                .build(),
+        )
+        .build()
+}
+
+parameterized_test! {can_calculate_fitness, (job_ids, expected), {
+    can_calculate_fitness_impl(job_ids, expected);
+}}
+
+can_calculate_fitness! {
+    case_01_same_sequence: (&["job1", "job2", "job3"], 0.),
+    case_02_one_break: (&["job2", "job1", "job3"], SEQUENCE_WEIGHT),
+    case_03_two_breaks: (&["job3", "job2", "job1"], 2. * SEQUENCE_WEIGHT),
+    case_04_reassigned: (&["job1", "job5"], REASSIGNMENT_WEIGHT),
+    case_05_new_job: (&["job1", "job6", "job2"], 0.),
+    case_06_both: (&["job2", "job5", "job1"], REASSIGNMENT_WEIGHT + SEQUENCE_WEIGHT),
+}
+
+fn can_calculate_fitness_impl(job_ids: &[&str], expected: Cost) {
+    let objective = create_test_feature().objective.unwrap();
+    let insertion_ctx = InsertionContextBuilder::default().with_routes(vec![create_route_ctx(job_ids)]).build();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_route_insertion, (job_id, expected), {
+    can_estimate_route_insertion_impl(job_id, expected);
+}}
+
+can_estimate_route_insertion! {
+    case_01_same_tour: ("job1", 0.),
+    case_02_other_tour: ("job5", REASSIGNMENT_WEIGHT),
+    case_03_new_job: ("job6", 0.),
+}
+
+fn can_estimate_route_insertion_impl(job_id: &str, expected: Cost) {
+    let objective = create_test_feature().objective.unwrap();
+    let route_ctx = create_route_ctx(&[]);
+    let solution_ctx = InsertionContextBuilder::default().build().solution;
+
+    let result = objective.estimate(&MoveContext::route(
+        &solution_ctx,
+        &route_ctx,
+        &SingleBuilder::default().id(job_id).build_as_job_ref(),
+    ));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_activity_insertion, (prev_idx, job_id, expected), {
+    can_estimate_activity_insertion_impl(prev_idx, job_id, expected);
+}}
+
+can_estimate_activity_insertion! {
+    case_01_keeps_order: (1, "job2", 0.),
+    case_02_breaks_order: (1, "job4", SEQUENCE_WEIGHT),
+    case_03_breaks_order_at_end: (2, "job2", SEQUENCE_WEIGHT),
+    case_04_new_job: (1, "job6", 0.),
+}
+
+fn can_estimate_activity_insertion_impl(prev_idx: usize, job_id: &str, expected: Cost) {
+    let objective = create_test_feature().objective.unwrap();
+    let route_ctx = create_route_ctx(&["job1", "job3"]);
+    let route = route_ctx.route();
+    let target = create_activity(job_id);
+    let activity_ctx = ActivityContext {
+        index: prev_idx,
+        prev: route.tour.get(prev_idx).unwrap(),
+        target: &target,
+        next: route.tour.get(prev_idx + 1),
+    };
+
+    let result = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
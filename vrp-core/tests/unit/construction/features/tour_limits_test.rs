@@ -173,3 +173,96 @@ mod traveling {
         assert_eq!(result, ConstraintViolation::skip(DURATION_CODE));
     }
 }
+
+mod minimum {
+    use super::*;
+    use crate::helpers::construction::features::create_simple_demand;
+    use crate::helpers::construction::heuristics::InsertionContextBuilder;
+    use crate::models::common::{Duration, SingleDimLoad};
+
+    fn create_limits(
+        tour_size: Option<usize>,
+        duration: Option<Duration>,
+        load: Option<i32>,
+    ) -> MinTourLimits<SingleDimLoad> {
+        MinTourLimits { tour_size, duration, load: load.map(SingleDimLoad::new) }
+    }
+
+    parameterized_test! {can_get_deficit, (limits, tour_size, duration, load, expected), {
+        can_get_deficit_impl(limits, tour_size, duration, load, expected);
+    }}
+
+    can_get_deficit! {
+        case01_no_limits: ((None, None, None), 1, 10., 1, 0.),
+        case02_tour_size_met: ((Some(2), None, None), 2, 10., 1, 0.),
+        case03_tour_size_not_met: ((Some(4), None, None), 1, 10., 1, 0.75),
+        case04_duration_met: ((None, Some(100.), None), 1, 100., 1, 0.),
+        case05_duration_not_met: ((None, Some(100.), None), 1, 50., 1, 0.5),
+        case06_load_met: ((None, None, Some(5)), 1, 10., 5, 0.),
+        case07_load_not_met: ((None, None, Some(5)), 1, 10., 4, 1.),
+        case08_all_not_met: ((Some(4), Some(100.), Some(5)), 2, 50., 4, 2.),
+    }
+
+    fn can_get_deficit_impl(
+        limits: (Option<usize>, Option<Duration>, Option<i32>),
+        tour_size: usize,
+        duration: Duration,
+        load: i32,
+        expected: f64,
+    ) {
+        let limits = create_limits(limits.0, limits.1, limits.2);
+
+        let result = limits.get_deficit(tour_size, duration, SingleDimLoad::new(load));
+
+        assert!((result - expected).abs() < 1E-6);
+    }
+
+    parameterized_test! {can_estimate_job_insertion, (activities, job_demand, limits, expected), {
+        can_estimate_job_insertion_impl(activities, job_demand, limits, expected);
+    }}
+
+    can_estimate_job_insertion! {
+        case01_empty_route: (0, 1, (Some(2), None, None), 0.5),
+        case02_reduces_size_deficit: (1, 1, (Some(2), None, None), -0.5),
+        case03_no_size_deficit: (2, 1, (Some(2), None, None), 0.),
+        case04_reduces_load_deficit: (1, 2, (None, None, Some(3)), -1.),
+        case05_keeps_load_deficit: (1, 1, (None, None, Some(3)), 0.),
+        case06_no_limits: (1, 1, (None, None, None), 0.),
+    }
+
+    fn can_estimate_job_insertion_impl(
+        activities: usize,
+        job_demand: i32,
+        limits: (Option<usize>, Option<Duration>, Option<i32>),
+        expected: Cost,
+    ) {
+        let mut state_registry = StateKeyRegistry::default();
+        let (duration_key, load_key) = (state_registry.next_key(), state_registry.next_key());
+        let limits = create_limits(limits.0, limits.1, limits.2);
+        let feature = create_min_tour_limit_feature::<SingleDimLoad>(
+            "min_tour_limit",
+            Arc::new(move |_| Some(limits.clone())),
+            duration_key,
+            load_key,
+        )
+        .unwrap();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::with_default_vehicle()
+                    .add_activities((0..activities).map(|idx| {
+                        ActivityBuilder::with_location(idx)
+                            .job(Some(SingleBuilder::default().demand(create_simple_demand(-1)).build_shared()))
+                            .build()
+                    }))
+                    .build(),
+            )
+            .build();
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+        let solution_ctx = InsertionContextBuilder::default().build().solution;
+        let job = SingleBuilder::default().id("job").demand(create_simple_demand(-job_demand)).build_as_job_ref();
+
+        let result = feature.objective.unwrap().estimate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert!((result - expected).abs() < 1E-6);
+    }
+}
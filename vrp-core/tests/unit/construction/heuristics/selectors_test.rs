@@ -69,6 +69,8 @@ mod iterators {
 mod selections {
     use super::*;
     use crate::helpers::models::problem::SingleBuilder;
+    use crate::models::solution::{Route, Tour};
+    use crate::models::ExtrasBuilder;
 
     parameterized_test! {can_use_stochastic_selection_mode, (skip, activities, expected_threshold), {
         can_use_stochastic_selection_mode_impl(skip, activities, expected_threshold);
@@ -111,6 +113,90 @@ mod selections {
 
         assert!(counter < expected_threshold);
     }
+
+    parameterized_test! {can_use_granular_selection_mode, (job_idx, neighbours, skip, expected), {
+        can_use_granular_selection_mode_impl(job_idx, neighbours, skip, expected);
+    }}
+
+    can_use_granular_selection_mode! {
+        case_01: (0, 2, 0, vec![1, 2, 3]),
+        case_02: (0, 2, 3, vec![3]),
+        case_03: (5, 1, 0, vec![4, 5]),
+        case_04: (9, 3, 0, vec![6, 7, 8, 9]),
+    }
+
+    fn can_use_granular_selection_mode_impl(job_idx: usize, neighbours: usize, skip: usize, expected: Vec<usize>) {
+        let (problem, solution) = generate_matrix_routes_with_defaults(10, 1, false);
+        let job = problem.jobs.all().nth(job_idx).unwrap();
+        let selection_mode = LegSelection::Granular {
+            jobs: problem.jobs.clone(),
+            neighbours,
+            fallback: Box::new(LegSelection::Exhaustive),
+        };
+        let route_ctx = RouteContext::new_with_state(solution.routes.into_iter().next().unwrap(), Default::default());
+        let mut legs = vec![];
+
+        selection_mode.sample_best(
+            &route_ctx,
+            &job,
+            skip,
+            (),
+            &mut |leg: Leg, _| {
+                legs.push(leg.1);
+                ControlFlow::Continue(())
+            },
+            |_, _| true,
+        );
+
+        assert_eq!(legs, expected);
+    }
+
+    #[test]
+    fn can_use_granularity_from_extras() {
+        let (problem, _) = generate_matrix_routes_with_defaults(10, 1, false);
+        assert!(matches!(LegSelection::Exhaustive.with_granularity(&problem), LegSelection::Exhaustive));
+
+        let extras = ExtrasBuilder::from(problem.extras.as_ref()).with_granularity(3).build().unwrap();
+        let problem = Problem { extras: Arc::new(extras), ..problem };
+
+        match LegSelection::Exhaustive.with_granularity(&problem).with_granularity(&problem) {
+            LegSelection::Granular { neighbours, fallback, .. } => {
+                assert_eq!(neighbours, 3);
+                assert!(matches!(fallback.as_ref(), LegSelection::Exhaustive));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn can_use_all_legs_of_empty_tour_in_granular_selection_mode() {
+        let (problem, solution) = generate_matrix_routes_with_defaults(10, 1, false);
+        let job = problem.jobs.all().next().unwrap();
+        let selection_mode = LegSelection::Granular {
+            jobs: problem.jobs.clone(),
+            neighbours: 2,
+            fallback: Box::new(LegSelection::Exhaustive),
+        };
+        let actor = solution.routes[0].actor.clone();
+        let route_ctx = RouteContext::new_with_state(Route { tour: Tour::new(&actor), actor }, Default::default());
+        let mut legs = 0;
+
+        selection_mode.sample_best(
+            &route_ctx,
+            &job,
+            0,
+            (),
+            &mut |_: Leg, _| {
+                legs += 1;
+                ControlFlow::Continue(())
+            },
+            |_, _| true,
+        );
+
+        assert_eq!(legs, 1);
+        assert!(selection_mode.fallback().is_some());
+        assert!(LegSelection::Exhaustive.fallback().is_none());
+    }
 }
 
 mod positions {
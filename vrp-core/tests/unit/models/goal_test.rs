@@ -234,3 +234,30 @@ fn can_use_objective_total_order_impl(
 
     assert_eq!(goal_ctx.total_order(&left, &right), expected);
 }
+
+#[test]
+fn can_augment_objective_of_lowest_priority() {
+    let route_ctx = RouteContext::new(test_actor());
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &ActivityBuilder::default().job(None).build(),
+        target: &ActivityBuilder::default().job(None).build(),
+        next: None,
+    };
+    let move_ctx = MoveContext::activity(&route_ctx, &activity_ctx);
+    let augment = create_objective_feature_with_fixed_cost("augment", 2.).objective.unwrap();
+    let goal = GoalContextBuilder::default()
+        .add_features(vec![
+            create_objective_feature_with_fixed_cost("o_1", 1.),
+            create_objective_feature_with_fixed_cost("o_2", 1.),
+        ])
+        .with_objectives(vec![vec!["o_1"], vec!["o_2"]])
+        .build();
+
+    let augmented = goal.with_augmented_objective(augment);
+
+    let insertion_ctx = InsertionContextBuilder::default().build();
+    assert_eq!(augmented.estimate(&move_ctx), InsertionCost::new(&[1., 3.]));
+    assert_eq!(augmented.fitness(&insertion_ctx).collect::<Vec<_>>(), vec![1., 3.]);
+    assert_eq!(goal.fitness(&insertion_ctx).collect::<Vec<_>>(), vec![1., 1.]);
+}
@@ -238,3 +238,91 @@ mod objective {
         assert_eq!(result, expected);
     }
 }
+
+mod time_bands {
+    use super::*;
+    use crate::helpers::models::problem::*;
+    use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder};
+    use crate::models::problem::TimeBandDimension;
+
+    fn create_route_with_time_bands(time_bands: Vec<TimeBand>) -> Route {
+        let mut vehicle = VehicleBuilder::default().id("v1").build();
+        vehicle.dimens.set_time_bands(time_bands);
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+
+        RouteBuilder::default().with_vehicle(&fleet, "v1").build()
+    }
+
+    parameterized_test! {can_get_time_band_duration, (time_bands, interval, expected), {
+        can_get_time_band_duration_impl(time_bands, interval, expected);
+    }}
+
+    can_get_time_band_duration! {
+        case01_no_bands: (vec![], (10., 20.), 10.),
+        case02_duration_band_after: (vec![TimeBand::Duration { start: 15., factor: 2. }], (10., 20.), 15.),
+        case03_duration_band_before: (vec![TimeBand::Duration { start: 30., factor: 2. }], (10., 20.), 10.),
+        case04_time_of_day_inside: (vec![TimeBand::TimeOfDay { start: 0., end: 15., factor: 3. }], (10., 20.), 20.),
+        case05_time_of_day_next_day: (
+            vec![TimeBand::TimeOfDay { start: 0., end: 15., factor: 3. }],
+            (86_390., 86_410.),
+            40.
+        ),
+        case06_time_of_day_wrap: (
+            vec![TimeBand::TimeOfDay { start: 86_395., end: 5., factor: 2. }],
+            (86_390., 86_410.),
+            30.
+        ),
+        case07_max_factor_on_overlap: (
+            vec![
+                TimeBand::Duration { start: 10., factor: 2. },
+                TimeBand::TimeOfDay { start: 15., end: 20., factor: 3. },
+            ],
+            (10., 20.),
+            25.
+        ),
+    }
+
+    fn can_get_time_band_duration_impl(
+        time_bands: Vec<TimeBand>,
+        interval: (Timestamp, Timestamp),
+        expected: Duration,
+    ) {
+        let route = create_route_with_time_bands(time_bands);
+
+        let result = get_time_band_duration(&route, &TimeWindow::new(interval.0, interval.1));
+
+        assert!((result - expected).abs() < 1E-6);
+    }
+
+    parameterized_test! {can_apply_time_bands_to_transport_cost, (use_time_bands, expected), {
+        can_apply_time_bands_to_transport_cost_impl(use_time_bands, expected);
+    }}
+
+    can_apply_time_bands_to_transport_cost! {
+        case01_with_time_band_cost: (true, (10. + 15.) * 2.),
+        case02_without_time_band_cost: (false, (10. + 10.) * 2.),
+    }
+
+    fn can_apply_time_bands_to_transport_cost_impl(use_time_bands: bool, expected: Cost) {
+        let route = create_route_with_time_bands(vec![TimeBand::Duration { start: 5., factor: 2. }]);
+        let transport: Arc<dyn TransportCost + Send + Sync> = Arc::new(TestTransportCost::default());
+        let transport = if use_time_bands { Arc::new(TimeBandTransportCost::new(transport)) } else { transport };
+
+        // NOTE: distance and duration are 10, the band starts after 5. Driver and vehicle have the same costs
+        let result = transport.cost(&route, 0, 10, TravelTime::Departure(0.));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_apply_time_bands_to_activity_cost() {
+        let route = create_route_with_time_bands(vec![TimeBand::Duration { start: 5., factor: 2. }]);
+        let activity_cost = TimeBandActivityCost::new(TestActivityCost::new_shared());
+        let activity = ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(10., 100.), 10.).build();
+
+        // NOTE: waiting is from 0 to 10 and service is from 10 to 20, the band starts after 5
+        let result = activity_cost.cost(&route, &activity, 0.);
+
+        assert_eq!(result, (15. + 20.) * 2.);
+    }
+}
@@ -0,0 +1,127 @@
+use super::*;
+use crate::construction::features::create_minimize_transport_costs_feature;
+use crate::helpers::construction::features::{create_simple_demand, create_simple_dynamic_demand};
+use crate::helpers::models::domain::GoalContextBuilder;
+use crate::helpers::models::problem::SingleBuilder;
+use crate::helpers::solver::{generate_matrix_routes, generate_matrix_routes_with_defaults};
+use crate::models::{CoreStateKeys, Solution};
+
+fn get_solution_jobs(solution: &Solution) -> Vec<Job> {
+    solution.routes.iter().flat_map(|route| route.tour.jobs()).collect::<HashSet<_>>().into_iter().cloned().collect()
+}
+
+parameterized_test! {can_estimate_routing_bounds, (rows, cols, is_open_vrp, expected_distance), {
+    can_estimate_routing_bounds_impl(rows, cols, is_open_vrp, expected_distance);
+}}
+
+can_estimate_routing_bounds! {
+    case01_single_vehicle: (5, 1, false, 4.),
+    case02_single_vehicle_open: (5, 1, true, 4.),
+    case03_two_vehicles: (3, 2, false, 5.),
+}
+
+fn can_estimate_routing_bounds_impl(rows: usize, cols: usize, is_open_vrp: bool, expected_distance: Distance) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, is_open_vrp);
+
+    let bounds = LowerBounds::new::<SingleDimLoad>(&problem, get_solution_jobs(&solution).as_slice(), false, 1.);
+
+    assert_eq!(bounds.distance, expected_distance);
+    assert_eq!(bounds.duration, expected_distance);
+    assert_eq!(bounds.tours, None);
+    assert!(bounds.cost >= 2. * expected_distance);
+}
+
+parameterized_test! {can_estimate_tours_bound, (rows, capacity, demands, is_dynamic, expected), {
+    can_estimate_tours_bound_impl(rows, capacity, demands, is_dynamic, expected);
+}}
+
+can_estimate_tours_bound! {
+    case01_static_deliveries: (3, 5, (-2, -2), false, Some(3)),
+    case02_tight_static_pickup_and_delivery: (1, 10, (-10, 10), false, Some(1)),
+    case03_tight_dynamic_pickup_and_delivery: (5, 10, (10, -10), true, Some(1)),
+    case04_tight_static_deliveries: (1, 10, (-10, -10), false, Some(2)),
+}
+
+fn can_estimate_tours_bound_impl(
+    rows: usize,
+    capacity: i32,
+    demands: (i32, i32),
+    is_dynamic: bool,
+    expected: Option<usize>,
+) {
+    let (problem, solution) = generate_matrix_routes(
+        rows,
+        2,
+        false,
+        |transport, activity, extras| {
+            let schedule_keys = extras.get_schedule_keys().cloned().expect("no schedule keys");
+            GoalContextBuilder::default()
+                .add_feature(
+                    create_minimize_transport_costs_feature("transport", transport, activity, schedule_keys, 1)
+                        .unwrap(),
+                )
+                .with_objectives(vec![vec!["transport"]])
+                .build()
+        },
+        |id, location| {
+            let size = if location.unwrap_or_default() % 2 == 0 { demands.0 } else { demands.1 };
+            let demand = if is_dynamic { create_simple_dynamic_demand(size) } else { create_simple_demand(size) };
+            SingleBuilder::default().id(id).location(location).demand(demand).build_shared()
+        },
+        |mut vehicle| {
+            vehicle.dimens.set_capacity(SingleDimLoad::new(capacity));
+            vehicle
+        },
+        |data| (data.clone(), data),
+    );
+
+    let bounds = LowerBounds::new::<SingleDimLoad>(&problem, get_solution_jobs(&solution).as_slice(), true, 1.);
+
+    assert_eq!(bounds.tours, expected);
+}
+
+parameterized_test! {can_scale_cost_bound_by_load_factor, load_factor, {
+    can_scale_cost_bound_by_load_factor_impl(load_factor);
+}}
+
+can_scale_cost_bound_by_load_factor! {
+    case01_cheaper: 0.5,
+    case02_same: 1.,
+    case03_more_expensive: 1.5,
+}
+
+fn can_scale_cost_bound_by_load_factor_impl(load_factor: f64) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 1, false);
+    let jobs = get_solution_jobs(&solution);
+
+    let default_bounds = LowerBounds::new::<SingleDimLoad>(&problem, jobs.as_slice(), false, 1.);
+    let bounds = LowerBounds::new::<SingleDimLoad>(&problem, jobs.as_slice(), false, load_factor);
+
+    let travel = default_bounds.distance + default_bounds.duration;
+    assert_eq!(bounds.distance, default_bounds.distance);
+    assert!((bounds.cost - default_bounds.cost - (load_factor - 1.) * travel).abs() < 1E-6);
+}
+
+#[test]
+fn can_estimate_bounds_for_empty_jobs() {
+    let (problem, _) = generate_matrix_routes_with_defaults(3, 1, false);
+
+    let bounds = LowerBounds::new::<SingleDimLoad>(&problem, &[], true, 1.);
+
+    assert_eq!(bounds, LowerBounds { distance: 0., duration: 0., tours: Some(0), cost: 0. });
+}
+
+#[test]
+fn can_calculate_min_spanning_tree() {
+    let weights = vec![vec![0., 1., 4., 3.], vec![1., 0., 2., 5.], vec![4., 2., 0., 1.], vec![3., 5., 1., 0.]];
+
+    assert_eq!(get_min_spanning_tree(&weights), 4.);
+    assert_eq!(get_min_spanning_tree(&[]), 0.);
+}
+
+#[test]
+fn can_calculate_relative_gap() {
+    assert_eq!(get_relative_gap(10., 8.), 0.2);
+    assert_eq!(get_relative_gap(10., 12.), 0.);
+    assert_eq!(get_relative_gap(0., 0.), 0.);
+}
@@ -0,0 +1,137 @@
+use super::*;
+use crate::construction::features::*;
+use crate::helpers::models::domain::GoalContextBuilder;
+use crate::helpers::models::problem::SingleBuilder;
+use crate::helpers::solver::{generate_matrix_routes, generate_matrix_routes_with_defaults};
+use crate::models::problem::Vehicle;
+use crate::solver::create_default_config_builder;
+use rosomaxa::evolution::TelemetryMode;
+
+fn create_problem(rows: usize, cols: usize, with_tours: bool, vehicle_modify: impl Fn(Vehicle) -> Vehicle) -> Problem {
+    let (problem, _) = generate_matrix_routes(
+        rows,
+        cols,
+        false,
+        |transport, activity, extras| {
+            let schedule_keys = extras.get_schedule_keys().cloned().expect("no schedule keys");
+            let objectives = if with_tours {
+                vec![vec!["min_unassigned"], vec!["min_tours"], vec!["transport"]]
+            } else {
+                vec![vec!["min_unassigned"], vec!["transport"]]
+            };
+
+            GoalContextBuilder::default()
+                .add_feature(create_minimize_unassigned_jobs_feature("min_unassigned", Arc::new(|_, _| 1.)).unwrap())
+                .add_feature(create_minimize_tours_feature("min_tours").unwrap())
+                .add_feature(
+                    create_minimize_transport_costs_feature("transport", transport, activity, schedule_keys, 1)
+                        .unwrap(),
+                )
+                .with_objectives(objectives)
+                .build()
+        },
+        |id, location| SingleBuilder::default().id(id).location(location).build_shared(),
+        vehicle_modify,
+        |data| (data.clone(), data),
+    );
+
+    problem
+}
+
+fn create_exact_solver(problem: Problem) -> ExactSolver {
+    let problem = Arc::new(problem);
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(problem.clone(), environment, TelemetryMode::None)
+        .with_max_generations(Some(10))
+        .build()
+        .expect("cannot build config");
+
+    ExactSolver::new(problem, config)
+}
+
+fn get_job_counts(solution: &Solution) -> Vec<usize> {
+    let mut counts = solution.routes.iter().map(|route| route.tour.job_count()).collect::<Vec<_>>();
+    counts.sort_unstable();
+
+    counts
+}
+
+parameterized_test! {can_solve_small_problem_optimally, (rows, cols, expected_cost, expected_counts), {
+    can_solve_small_problem_optimally_impl(rows, cols, expected_cost, expected_counts);
+}}
+
+can_solve_small_problem_optimally! {
+    case01_single_vehicle: (5, 1, 16., vec![5]),
+    case02_two_vehicles: (3, 2, 20., vec![6]),
+}
+
+fn can_solve_small_problem_optimally_impl(rows: usize, cols: usize, expected_cost: Cost, expected_counts: Vec<usize>) {
+    let problem = create_problem(rows, cols, false, |v| v);
+
+    let (solution, optimality) = create_exact_solver(problem).solve().expect("cannot solve");
+
+    assert_eq!(optimality, Optimality::Optimal);
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.cost, expected_cost);
+    assert_eq!(get_job_counts(&solution), expected_counts);
+}
+
+#[test]
+fn can_report_gap_when_interrupted() {
+    let problem = create_problem(5, 1, false, |v| v);
+
+    let (solution, optimality) = create_exact_solver(problem).with_max_nodes(7).solve().expect("cannot solve");
+
+    assert!(solution.unassigned.is_empty());
+    match optimality {
+        Optimality::Gap(Some(gap)) => assert!((0. ..=1.).contains(&gap)),
+        _ => unreachable!("unexpected optimality: {optimality:?}"),
+    }
+}
+
+#[test]
+fn can_fallback_to_metaheuristic() {
+    let problem = create_problem(5, 1, false, |v| v);
+    let solver = create_exact_solver(problem).with_max_jobs(4);
+    assert!(!solver.is_applicable());
+
+    let (_, optimality) = solver.solve().expect("cannot solve");
+
+    assert_eq!(optimality, Optimality::Heuristic);
+}
+
+#[test]
+fn can_solve_with_tours_objective() {
+    let problem = create_problem(3, 2, true, |v| v);
+
+    let (solution, optimality) = create_exact_solver(problem).solve().expect("cannot solve");
+
+    assert_eq!(optimality, Optimality::Optimal);
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+}
+
+#[test]
+fn can_detect_unsupported_goal() {
+    let (problem, _) = generate_matrix_routes_with_defaults(3, 1, false);
+
+    assert!(!create_exact_solver(problem).is_applicable());
+}
+
+parameterized_test! {can_detect_time_bands_with_discount, (factor, expected), {
+    can_detect_time_bands_with_discount_impl(factor, expected);
+}}
+
+can_detect_time_bands_with_discount! {
+    case01_discount: (0.5, false),
+    case02_surcharge: (2., true),
+}
+
+fn can_detect_time_bands_with_discount_impl(factor: f64, expected: bool) {
+    let problem = create_problem(3, 1, false, |mut vehicle| {
+        vehicle.dimens.set_time_bands(vec![TimeBand::Duration { start: 0., factor }]);
+        vehicle
+    });
+
+    assert_eq!(create_exact_solver(problem).is_applicable(), expected);
+}
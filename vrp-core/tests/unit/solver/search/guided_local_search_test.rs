@@ -0,0 +1,82 @@
+use super::*;
+use crate::helpers::models::solution::ActivityBuilder;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::solver::search::{ExchangeTwoOpt, LocalSearch};
+
+fn create_insertion_ctx(rows: usize, cols: usize) -> InsertionContext {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+
+    InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment)
+}
+
+fn get_penalties(values: Vec<((Location, Location), f64)>) -> EdgePenalties {
+    EdgePenalties { values: values.into_iter().collect() }
+}
+
+#[test]
+fn can_penalize_edges_with_max_utility() {
+    // NOTE route is 0 -> 0 -> 1 -> 2 -> 3 -> 0, so the longest edge is (3, 0)
+    let insertion_ctx = create_insertion_ctx(4, 1);
+    let mut penalties = EdgePenalties::default();
+
+    penalties.update(&insertion_ctx, 0.5);
+    assert_eq!(penalties.values.iter().collect::<Vec<_>>(), vec![(&(0, 3), &1.)]);
+
+    penalties.update(&insertion_ctx, 0.5);
+    assert_eq!(penalties.values.iter().collect::<Vec<_>>(), vec![(&(0, 3), &1.5)]);
+}
+
+#[test]
+fn can_decay_penalties() {
+    let insertion_ctx = create_insertion_ctx(4, 1);
+    let mut penalties = get_penalties(vec![((0, 3), 100.), ((5, 6), 0.015)]);
+
+    penalties.update(&insertion_ctx, 0.5);
+
+    assert_eq!(penalties.get(3, 0), 50.);
+    assert!(!penalties.values.contains_key(&(5, 6)));
+    // NOTE all unit edges have the same max utility now
+    assert_eq!(penalties.get(0, 1), 1.);
+    assert_eq!(penalties.get(1, 2), 1.);
+    assert_eq!(penalties.get(2, 3), 1.);
+}
+
+#[test]
+fn can_estimate_activity_insertion() {
+    let insertion_ctx = create_insertion_ctx(4, 1);
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let route = route_ctx.route();
+    let target = ActivityBuilder::with_location(0).build();
+    let activity_ctx =
+        ActivityContext { index: 4, prev: route.tour.get(4).unwrap(), target: &target, next: route.tour.get(2) };
+    let objective = EdgePenaltyObjective { penalties: Arc::new(get_penalties(vec![((0, 3), 2.)])), lambda: 0.5 };
+
+    let result = objective.estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result, 1.);
+}
+
+#[test]
+fn can_calculate_fitness() {
+    let insertion_ctx = create_insertion_ctx(4, 1);
+    let objective =
+        EdgePenaltyObjective { penalties: Arc::new(get_penalties(vec![((0, 3), 2.), ((1, 2), 1.)])), lambda: 0.5 };
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, 1.5);
+}
+
+#[test]
+fn can_search_with_augmented_objective_and_restore_original_problem() {
+    let insertion_ctx = create_insertion_ctx(4, 2);
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let search = GuidedLocalSearch::new(Arc::new(LocalSearch::new(Arc::new(ExchangeTwoOpt::default()))), 0.3, 0.9);
+
+    let result = search.search(&refinement_ctx, &insertion_ctx);
+
+    assert!(Arc::ptr_eq(&result.problem, &insertion_ctx.problem));
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 8);
+    assert!(!search.penalties.read().unwrap().values.is_empty());
+}
@@ -0,0 +1,110 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use std::sync::Arc;
+
+fn explore_with_operator(
+    operator: &(dyn LocalOperator + Send + Sync),
+    ints: Vec<i32>,
+    disallowed_pairs: Vec<(&str, &str)>,
+    locked_ids: &[&str],
+) -> Option<Vec<Vec<String>>> {
+    let (problem, solution) = generate_matrix_routes_with_disallow_list(5, 2, false, disallowed_pairs);
+    let problem = Arc::new(problem);
+    let insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(
+            problem.clone(),
+            (solution, None),
+            create_test_environment_with_random(Arc::new(FakeRandom::new(ints, vec![]))),
+        ),
+        locked_ids,
+    );
+
+    operator
+        .explore(&create_default_refinement_ctx(problem), &insertion_ctx)
+        .map(|insertion_ctx| get_customer_ids_from_routes(&insertion_ctx))
+}
+
+fn repeat_attempts(ints: Vec<i32>) -> Vec<i32> {
+    (0..MAX_ATTEMPTS).flat_map(|_| ints.iter().cloned()).collect()
+}
+
+parameterized_test! {can_use_two_opt_star, (ints, disallowed_pairs, locked_ids, expected), {
+    can_use_two_opt_star_impl(ints, disallowed_pairs, locked_ids, expected);
+}}
+
+can_use_two_opt_star! {
+    case01_tails: (vec![0, 0, 2, 1], vec![], &[],
+                   Some(vec![vec!["c0", "c1", "c6", "c7", "c8", "c9"], vec!["c5", "c2", "c3", "c4"]])),
+    case02_empty_tail: (vec![0, 0, 5, 3], vec![], &[],
+                        Some(vec![vec!["c0", "c1", "c2", "c3", "c4", "c8", "c9"], vec!["c5", "c6", "c7"]])),
+    case03_infeasible: (repeat_attempts(vec![0, 0, 2, 1]), vec![("c1", "cX")], &[], None),
+    case04_locked: (repeat_attempts(vec![0, 0, 2, 1]), vec![], &["c3"], None),
+}
+
+fn can_use_two_opt_star_impl(
+    ints: Vec<i32>,
+    disallowed_pairs: Vec<(&str, &str)>,
+    locked_ids: &[&str],
+    expected: Option<Vec<Vec<&str>>>,
+) {
+    let result = explore_with_operator(&ExchangeTwoOptStar::default(), ints, disallowed_pairs, locked_ids);
+
+    assert_eq!(result, expected.map(to_strings));
+}
+
+parameterized_test! {can_use_cross_exchange, (ints, expected), {
+    can_use_cross_exchange_impl(ints, expected);
+}}
+
+can_use_cross_exchange! {
+    case01_both_segments: (vec![0, 0, 1, 2, 2, 3],
+                           Some(vec![vec!["c0", "c7", "c8", "c2", "c3", "c4"], vec!["c5", "c6", "c1", "c9"]])),
+    case02_one_empty_segment: (vec![0, 0, 0, 2, 1, 1],
+                               Some(vec![vec!["c0", "c5", "c1", "c2", "c3", "c4"], vec!["c6", "c7", "c8", "c9"]])),
+    case03_both_empty_segments: (repeat_attempts(vec![0, 0, 0, 1, 0, 1]), None),
+}
+
+fn can_use_cross_exchange_impl(ints: Vec<i32>, expected: Option<Vec<Vec<&str>>>) {
+    let result = explore_with_operator(&ExchangeCross::default(), ints, vec![], &[]);
+
+    assert_eq!(result, expected.map(to_strings));
+}
+
+parameterized_test! {can_use_or_opt, (ints, expected), {
+    can_use_or_opt_impl(ints, expected);
+}}
+
+can_use_or_opt! {
+    case01_move_to_end: (vec![0, 2, 1, 3], Some(vec![vec!["c2", "c3", "c4", "c0", "c1"], vec!["c5", "c6", "c7", "c8", "c9"]])),
+    case02_move_to_start: (vec![1, 1, 5, 0], Some(vec![vec!["c0", "c1", "c2", "c3", "c4"], vec!["c9", "c5", "c6", "c7", "c8"]])),
+    case03_same_position: (repeat_attempts(vec![0, 1, 2, 1]), None),
+}
+
+fn can_use_or_opt_impl(ints: Vec<i32>, expected: Option<Vec<Vec<&str>>>) {
+    let result = explore_with_operator(&ExchangeOrOpt::default(), ints, vec![], &[]);
+
+    assert_eq!(result, expected.map(to_strings));
+}
+
+parameterized_test! {can_use_two_opt, (ints, disallowed_pairs, expected), {
+    can_use_two_opt_impl(ints, disallowed_pairs, expected);
+}}
+
+can_use_two_opt! {
+    case01_middle: (vec![0, 2, 4], vec![], Some(vec![vec!["c0", "c3", "c2", "c1", "c4"], vec!["c5", "c6", "c7", "c8", "c9"]])),
+    case02_whole: (vec![1, 1, 5], vec![], Some(vec![vec!["c0", "c1", "c2", "c3", "c4"], vec!["c9", "c8", "c7", "c6", "c5"]])),
+    case03_infeasible: (repeat_attempts(vec![0, 2, 4]), vec![("c2", "c4")], None),
+}
+
+fn can_use_two_opt_impl(ints: Vec<i32>, disallowed_pairs: Vec<(&str, &str)>, expected: Option<Vec<Vec<&str>>>) {
+    let result = explore_with_operator(&ExchangeTwoOpt::default(), ints, disallowed_pairs, &[]);
+
+    assert_eq!(result, expected.map(to_strings));
+}
+
+fn to_strings(routes: Vec<Vec<&str>>) -> Vec<Vec<String>> {
+    routes.into_iter().map(|route| route.into_iter().map(|id| id.to_string()).collect()).collect()
+}
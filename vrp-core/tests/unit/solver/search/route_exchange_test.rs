@@ -0,0 +1,104 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_id, get_customer_ids_from_routes};
+use crate::helpers::solver::*;
+
+fn create_parents() -> (InsertionContext, InsertionContext) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let first = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::default());
+
+    // NOTE donor moves c5 from the second route to the end of the first one
+    let mut second = first.deep_copy();
+    let job = second.problem.jobs.all().find(|job| get_customer_id(job) == "c5").unwrap();
+    let activity = second.solution.routes[1].route().tour.job_activities(&job).next().unwrap().deep_copy();
+    second.solution.routes[1].route_mut().tour.remove(&job);
+    second.solution.routes[0].route_mut().tour.insert_last(activity);
+    second.solution.routes.iter_mut().for_each(|route_ctx| second.problem.goal.accept_route_state(route_ctx));
+
+    (first, second)
+}
+
+fn get_sorted_unassigned(insertion_ctx: &InsertionContext) -> Vec<String> {
+    let mut ids = insertion_ctx.solution.unassigned.keys().map(get_customer_id).collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+#[test]
+fn can_exchange_routes() {
+    let (mut first, second) = create_parents();
+
+    let result = exchange_routes(&mut first, &[&second.solution.routes[1]]);
+
+    assert!(result);
+
+    assert_eq!(
+        get_customer_ids_from_routes(&first),
+        vec![vec!["c0", "c1", "c2", "c3", "c4"], vec!["c6", "c7", "c8", "c9"]]
+    );
+    assert_eq!(get_sorted_unassigned(&first), vec!["c5"]);
+}
+
+#[test]
+fn can_remove_donor_jobs_from_other_routes() {
+    let (mut first, second) = create_parents();
+
+    let result = exchange_routes(&mut first, &[&second.solution.routes[0]]);
+
+    assert!(result);
+
+    assert_eq!(
+        get_customer_ids_from_routes(&first),
+        vec![vec!["c6", "c7", "c8", "c9"], vec!["c0", "c1", "c2", "c3", "c4", "c5"]]
+    );
+    assert!(first.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_skip_exchange_when_donor_actor_is_not_available() {
+    let (mut first, _) = create_parents();
+    // NOTE actors of another problem are not known to the first solution's registry
+    let (other, _) = create_parents();
+
+    let result = exchange_routes(&mut first, &[&other.solution.routes[0]]);
+
+    assert!(!result);
+}
+
+parameterized_test! {can_select_donor_routes, (locked_ids, expected), {
+    can_select_donor_routes_impl(locked_ids, expected);
+}}
+
+can_select_donor_routes! {
+    case01_no_locked: (&[], 2),
+    case02_locked_in_one_route: (&["c9"], 1),
+    case03_locked_in_moved_job: (&["c5"], 0),
+    case04_locked_in_both_routes: (&["c0", "c9"], 0),
+}
+
+fn can_select_donor_routes_impl(locked_ids: &[&str], expected: usize) {
+    let (first, second) = create_parents();
+    let first = promote_to_locked(first, locked_ids);
+
+    let routes = select_donor_routes(&first, &second, 2);
+
+    assert!(routes.len() <= expected);
+    assert_eq!(routes.is_empty(), expected == 0);
+    assert!(routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+        .all(|job| !first.solution.locked.contains(job)));
+}
+
+#[test]
+fn can_create_child_with_all_jobs_assigned() {
+    let (first, second) = create_parents();
+    let recreate = Arc::new(RecreateWithCheapest::new(first.environment.random.clone()));
+    let crossover = SelectiveRouteExchange::new(recreate, 2);
+
+    let child = crossover.crossover(&create_default_refinement_ctx(first.problem.clone()), &first, &second);
+
+    assert!(child.solution.unassigned.is_empty());
+    assert!(child.solution.required.is_empty());
+    assert_eq!(child.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 10);
+}
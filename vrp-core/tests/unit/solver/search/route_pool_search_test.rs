@@ -0,0 +1,106 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_id, get_customer_ids_from_routes};
+use crate::helpers::solver::*;
+use crate::solver::search::RecreateWithCheapest;
+
+fn create_solutions() -> (InsertionContext, InsertionContext) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let first = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::default());
+
+    // NOTE second solution has c5 moved from the second route to the end of the first one
+    let mut second = first.deep_copy();
+    let job = second.problem.jobs.all().find(|job| get_customer_id(job) == "c5").unwrap();
+    let activity = second.solution.routes[1].route().tour.job_activities(&job).next().unwrap().deep_copy();
+    second.solution.routes[1].route_mut().tour.remove(&job);
+    second.solution.routes[0].route_mut().tour.insert_last(activity);
+    second.solution.routes.iter_mut().for_each(|route_ctx| second.problem.goal.accept_route_state(route_ctx));
+
+    (first, second)
+}
+
+#[test]
+fn can_build_route_pool_without_duplicates() {
+    let (first, second) = create_solutions();
+
+    let pool = RoutePool::new(&first, &[&first, &second, &first, &second]);
+
+    assert_eq!(pool.columns.len(), 4);
+    assert_eq!(pool.initial, vec![0, 1]);
+    assert_eq!(pool.actor_count, 2);
+    assert_eq!(pool.job_columns.len(), 10);
+}
+
+parameterized_test! {can_solve_set_partitioning, (costs, max_nodes, expected), {
+    can_solve_set_partitioning_impl(costs, max_nodes, expected);
+}}
+
+can_solve_set_partitioning! {
+    case01_keep_initial: (vec![1., 1., 5., 5.], 1000, vec![0, 1]),
+    case02_use_cheaper: (vec![5., 5., 1., 1.], 1000, vec![2, 3]),
+    case03_use_cheaper_with_small_diff: (vec![1., 1., 1., 0.5], 1000, vec![2, 3]),
+    case04_out_of_budget: (vec![5., 5., 1., 1.], 1, vec![0, 1]),
+}
+
+fn can_solve_set_partitioning_impl(costs: Vec<Cost>, max_nodes: usize, expected: Vec<usize>) {
+    let (first, second) = create_solutions();
+    let mut pool = RoutePool::new(&first, &[&first, &second]);
+    pool.columns.iter_mut().zip(costs).for_each(|(column, cost)| column.cost = cost);
+
+    let mut result = pool.solve(max_nodes, 1000);
+
+    result.sort_unstable();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_create_solution_from_selected_columns() {
+    let (first, second) = create_solutions();
+    let pool = RoutePool::new(&first, &[&first, &second]);
+
+    let insertion_ctx = pool.create_solution(&first, &[2, 3]).expect("cannot create solution");
+
+    let mut routes = get_customer_ids_from_routes(&insertion_ctx);
+    routes.sort();
+    assert_eq!(routes, vec![vec!["c0", "c1", "c2", "c3", "c4", "c5"], vec!["c6", "c7", "c8", "c9"]]);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_keep_routes_with_locked_jobs() {
+    let (first, second) = create_solutions();
+    let first = promote_to_locked(first, &["c0"]);
+    let pool = RoutePool::new(&first, &[&first, &second]);
+
+    let insertion_ctx = pool.create_solution(&first, &[0]).expect("cannot create solution");
+
+    assert_eq!(pool.columns.len(), 2);
+    assert_eq!(
+        get_customer_ids_from_routes(&insertion_ctx),
+        vec![vec!["c0", "c1", "c2", "c3", "c4"], vec!["c5", "c6", "c7", "c8", "c9"]]
+    );
+}
+
+#[test]
+fn can_skip_solution_when_column_actor_is_not_available() {
+    let (first, _) = create_solutions();
+    // NOTE actors of another problem are not known to the first solution's registry
+    let (other, _) = create_solutions();
+    let pool = RoutePool::new(&first, &[&other]);
+
+    let insertion_ctx = pool.create_solution(&first, &[0]);
+
+    assert!(insertion_ctx.is_none());
+}
+
+#[test]
+fn can_search_with_all_jobs_assigned() {
+    let (first, _) = create_solutions();
+    let recreate = Arc::new(RecreateWithCheapest::new(first.environment.random.clone()));
+    let search = RoutePoolSearch::new(recreate, 4, 1000, 1000);
+
+    let result = search.search(&create_default_refinement_ctx(first.problem.clone()), &first);
+
+    assert!(result.solution.unassigned.is_empty());
+    assert!(result.solution.required.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 10);
+}
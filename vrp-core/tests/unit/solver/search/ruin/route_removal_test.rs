@@ -1,4 +1,5 @@
-use super::{RandomRouteRemoval, Ruin};
+use super::{LocationCoordinates, OverlapRouteRemoval, RandomRouteRemoval, Ruin};
+use crate::algorithms::geometry::Point;
 use crate::construction::heuristics::InsertionContext;
 use crate::helpers::models::domain::*;
 use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
@@ -84,3 +85,51 @@ fn can_remove_worst_route() {
     assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), vec!["c10", "c11", "c9"]);
     assert_eq!(insertion_ctx.solution.routes.len(), 3);
 }
+
+fn create_overlap_insertion_ctx(has_coordinates: bool) -> InsertionContext {
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(4, 3, false);
+    if has_coordinates {
+        // NOTE first two routes form overlapping squares, the third one is far away
+        let corners = [(0., 0.), (2., 0.), (2., 2.), (0., 2.)];
+        let offsets = [0., 1., 10.];
+        Arc::get_mut(&mut problem.extras).unwrap().set_location_coordinates(Arc::new(move |location| {
+            let (x, y) = corners[location % 4];
+            let offset = offsets.get(location / 4)?;
+
+            Some(Point::new(x + offset, y + offset))
+        }));
+    }
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![10, 2], vec![]))),
+    )
+}
+
+#[test]
+fn can_remove_overlapping_routes() {
+    let limits = RemovalLimits { removed_activities_range: 10..10, affected_routes_range: 2..2 };
+    let insertion_ctx = create_overlap_insertion_ctx(true);
+
+    let insertion_ctx = OverlapRouteRemoval::new(limits)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(
+        get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required),
+        vec!["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7"]
+    );
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["c8", "c9", "c10", "c11"]]);
+}
+
+#[test]
+fn can_skip_overlap_removal_without_coordinates() {
+    let limits = RemovalLimits { removed_activities_range: 10..10, affected_routes_range: 2..2 };
+    let insertion_ctx = create_overlap_insertion_ctx(false);
+
+    let insertion_ctx = OverlapRouteRemoval::new(limits)
+        .run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert_eq!(insertion_ctx.solution.routes.len(), 3);
+}
@@ -0,0 +1,48 @@
+use super::*;
+use crate::helpers::models::domain::get_sorted_customer_ids_from_jobs;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::common::TimeWindow;
+use std::sync::Arc;
+
+fn create_insertion_ctx(route_ends: Vec<Vec<f64>>) -> InsertionContext {
+    let (problem, mut solution) = generate_matrix_routes_with_defaults(3, route_ends.len(), false);
+
+    // NOTE arrival time is recalculated when context is created: it equals to job's location index
+    solution.routes.iter_mut().zip(route_ends).for_each(|(route, ends)| {
+        route
+            .tour
+            .all_activities_mut()
+            .filter(|activity| activity.job.is_some())
+            .zip(ends)
+            .for_each(|(activity, end)| activity.place.time = TimeWindow::new(0., end));
+    });
+
+    InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        create_test_environment_with_random(Arc::new(FakeRandom::new(vec![2, 1], vec![]))),
+    )
+}
+
+parameterized_test! {can_remove_jobs_with_least_slack, (route_ends, expected), {
+    can_remove_jobs_with_least_slack_impl(route_ends, expected);
+}}
+
+can_remove_jobs_with_least_slack! {
+    case01_second_route_is_tight: (vec![vec![100., 90., 80.], vec![7., 5., 6.]], vec!["c4", "c5"]),
+    case02_first_route_is_tight: (vec![vec![3., 90., 4.], vec![20., 30., 40.]], vec!["c0", "c2"]),
+    case03_no_time_windows: (vec![vec![f64::MAX; 3], vec![f64::MAX; 3]], vec![]),
+    case04_same_slack_keeps_tour_order: (vec![vec![100., 90., 80.], vec![5., 6., 7.]], vec!["c3", "c4"]),
+}
+
+fn can_remove_jobs_with_least_slack_impl(route_ends: Vec<Vec<f64>>, expected: Vec<&str>) {
+    let limits = RemovalLimits { removed_activities_range: 2..2, affected_routes_range: 1..1 };
+    let insertion_ctx = create_insertion_ctx(route_ends);
+
+    let insertion_ctx =
+        TimeSlackRemoval::new(limits).run(&create_default_refinement_ctx(insertion_ctx.problem.clone()), insertion_ctx);
+
+    assert_eq!(get_sorted_customer_ids_from_jobs(&insertion_ctx.solution.required), expected);
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/territories_test.rs"]
+mod territories_test;
+
+use super::*;
+use crate::format::problem::get_territory_polygons;
+use vrp_core::algorithms::geometry::Point;
+
+/// Checks that vehicles with hard territory policy serve jobs only inside their territories.
+pub fn check_territories(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    let territories = get_territory_polygons(&context.problem);
+
+    context
+        .solution
+        .tours
+        .iter()
+        .try_for_each::<_, Result<_, GenericError>>(|tour| {
+            let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+            let vehicle_territories = match vehicle.territories.as_ref() {
+                Some(territories) if matches!(territories.policy, VehicleTerritoryPolicy::Hard) => territories,
+                _ => return Ok(()),
+            };
+
+            let polygons = territories
+                .iter()
+                .filter(|(id, _)| vehicle_territories.ids.contains(id))
+                .flat_map(|(_, polygons)| polygons.iter())
+                .collect::<Vec<_>>();
+
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+                .filter(|(_, activity)| context.job_map.contains_key(&activity.job_id))
+                .try_for_each(|(stop, activity)| {
+                    let is_inside = match context.get_activity_location(stop, activity) {
+                        Some(Location::Coordinate { lat, lng }) => {
+                            let point = Point::new(lng, lat);
+                            polygons.iter().any(|polygon| polygon.contains(&point))
+                        }
+                        _ => false,
+                    };
+
+                    if is_inside {
+                        Ok(())
+                    } else {
+                        Err(format!(
+                            "territory violation, job '{}' is served outside of territories, vehicle id '{}', shift index: {}",
+                            activity.job_id, tour.vehicle_id, tour.shift_index
+                        )
+                        .into())
+                    }
+                })
+        })
+        .map_err(|err| vec![err])
+}
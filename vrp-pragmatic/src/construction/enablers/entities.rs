@@ -1,8 +1,9 @@
 //! Specifies different entities as extension points on Dimensions type.
 
-use crate::construction::features::{BreakPolicy, JobSkills};
+use crate::construction::features::{BreakPolicy, JobPreferences, JobSkills, LoadCostFactors, VehicleTerritories};
 use hashbrown::HashSet;
-use vrp_core::models::common::{Dimensions, ValueDimension};
+use vrp_core::construction::features::MinTourLimits;
+use vrp_core::models::common::{Dimensions, LoadOps, ValueDimension};
 
 /// Specifies vehicle entity.
 pub trait VehicleTie {
@@ -30,6 +31,21 @@ pub trait VehicleTie {
     fn get_tour_size(&self) -> Option<usize>;
     /// Sets vehicle's tour size.
     fn set_tour_size(&mut self, tour_size: usize) -> &mut Self;
+
+    /// Gets vehicle's minimum tour limits.
+    fn get_min_tour_limits<T: LoadOps>(&self) -> Option<&MinTourLimits<T>>;
+    /// Sets vehicle's minimum tour limits.
+    fn set_min_tour_limits<T: LoadOps>(&mut self, limits: MinTourLimits<T>) -> &mut Self;
+
+    /// Gets vehicle's load dependent travel cost factors.
+    fn get_load_cost_factors(&self) -> Option<&LoadCostFactors>;
+    /// Sets vehicle's load dependent travel cost factors.
+    fn set_load_cost_factors(&mut self, factors: LoadCostFactors) -> &mut Self;
+
+    /// Gets vehicle's territories.
+    fn get_vehicle_territories(&self) -> Option<&VehicleTerritories>;
+    /// Sets vehicle's territories.
+    fn set_vehicle_territories(&mut self, territories: VehicleTerritories) -> &mut Self;
 }
 
 impl VehicleTie for Dimensions {
@@ -79,6 +95,33 @@ impl VehicleTie for Dimensions {
         self.set_value("tour_size", tour_size);
         self
     }
+
+    fn get_min_tour_limits<T: LoadOps>(&self) -> Option<&MinTourLimits<T>> {
+        self.get_value("min_tour_limits")
+    }
+
+    fn set_min_tour_limits<T: LoadOps>(&mut self, limits: MinTourLimits<T>) -> &mut Self {
+        self.set_value("min_tour_limits", limits);
+        self
+    }
+
+    fn get_load_cost_factors(&self) -> Option<&LoadCostFactors> {
+        self.get_value("load_cost_factors")
+    }
+
+    fn set_load_cost_factors(&mut self, factors: LoadCostFactors) -> &mut Self {
+        self.set_value("load_cost_factors", factors);
+        self
+    }
+
+    fn get_vehicle_territories(&self) -> Option<&VehicleTerritories> {
+        self.get_value("vehicle_territories")
+    }
+
+    fn set_vehicle_territories(&mut self, territories: VehicleTerritories) -> &mut Self {
+        self.set_value("vehicle_territories", territories);
+        self
+    }
 }
 
 /// Specifies job entity.
@@ -93,6 +136,11 @@ pub trait JobTie {
     /// Sets job skills.
     fn set_job_skills(&mut self, skills: Option<JobSkills>) -> &mut Self;
 
+    /// Gets job preferences.
+    fn get_job_preferences(&self) -> Option<&JobPreferences>;
+    /// Sets job preferences.
+    fn set_job_preferences(&mut self, preferences: Option<JobPreferences>) -> &mut Self;
+
     /// Get job place tags.
     fn get_place_tags(&self) -> Option<&Vec<(usize, String)>>;
     /// Sets job place tags.
@@ -150,6 +198,20 @@ impl JobTie for Dimensions {
         self
     }
 
+    fn get_job_preferences(&self) -> Option<&JobPreferences> {
+        self.get_value("job_preferences")
+    }
+
+    fn set_job_preferences(&mut self, preferences: Option<JobPreferences>) -> &mut Self {
+        if let Some(preferences) = preferences {
+            self.set_value("job_preferences", preferences);
+        } else {
+            self.remove("job_preferences");
+        }
+
+        self
+    }
+
     fn get_place_tags(&self) -> Option<&Vec<(usize, String)>> {
         self.get_value("job_tags")
     }
@@ -0,0 +1,46 @@
+//! Provides load dependent cost factors used by the load cost feature.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/load_costs_test.rs"]
+mod load_costs_test;
+
+use super::*;
+use crate::construction::enablers::VehicleTie;
+
+/// Specifies a piecewise linear function of vehicle load ratio which returns travel cost factor.
+pub struct LoadCostFactors {
+    points: Vec<(f64, f64)>,
+}
+
+impl LoadCostFactors {
+    /// Creates a new instance of [`LoadCostFactors`] from (load ratio, factor) points.
+    pub fn new(mut points: Vec<(f64, f64)>) -> Self {
+        points.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Self { points }
+    }
+
+    /// Returns a cost factor for given load ratio: it is interpolated linearly between neighbour points
+    /// and is constant outside of points range.
+    pub fn get_factor(&self, ratio: f64) -> f64 {
+        let idx = self.points.partition_point(|(load, _)| *load <= ratio);
+
+        match (idx.checked_sub(1).and_then(|idx| self.points.get(idx)), self.points.get(idx)) {
+            (Some(&(l_load, l_factor)), Some(&(r_load, r_factor))) => {
+                l_factor + (r_factor - l_factor) * (ratio - l_load) / (r_load - l_load)
+            }
+            (Some(&(_, factor)), None) | (None, Some(&(_, factor))) => factor,
+            (None, None) => 1.,
+        }
+    }
+
+    /// Returns the minimum cost factor over all load ratios.
+    pub fn get_min_factor(&self) -> f64 {
+        self.points.iter().map(|(_, factor)| *factor).reduce(f64::min).unwrap_or(1.)
+    }
+}
+
+/// Returns a travel cost factor for given actor and its load ratio.
+pub fn get_load_cost_factor(actor: &Actor, ratio: f64) -> f64 {
+    actor.vehicle.dimens.get_load_cost_factors().map_or(1., |factors| factors.get_factor(ratio))
+}
@@ -17,6 +17,12 @@ pub use self::compatibility::*;
 pub mod groups;
 pub use self::groups::*;
 
+pub mod load_costs;
+pub use self::load_costs::*;
+
+pub mod preferences;
+pub use self::preferences::*;
+
 pub mod reachable;
 pub use self::reachable::*;
 
@@ -28,3 +34,6 @@ pub use self::reloads::*;
 
 pub mod skills;
 pub use self::skills::*;
+
+pub mod territories;
+pub use self::territories::*;
@@ -0,0 +1,52 @@
+//! Provides job preferences (soft skills) used by the job preference feature.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/preferences_test.rs"]
+mod preferences_test;
+
+use super::*;
+use crate::construction::enablers::JobTie;
+use crate::construction::enablers::VehicleTie;
+use hashbrown::HashSet;
+
+/// A job preferences defined by vehicle skills.
+pub struct JobPreferences {
+    /// Vehicle is preferred to have all skills of each of these preferences.
+    pub wanted: Vec<(HashSet<String>, f64)>,
+    /// Vehicle is preferred to not have all skills of each of these preferences.
+    pub unwanted: Vec<(HashSet<String>, f64)>,
+}
+
+impl JobPreferences {
+    /// Creates a new instance of [`JobPreferences`].
+    pub fn new(wanted: Vec<(Vec<String>, f64)>, unwanted: Vec<(Vec<String>, f64)>) -> Self {
+        let map = |preferences: Vec<(Vec<String>, f64)>| {
+            preferences.into_iter().map(|(skills, weight)| (skills.into_iter().collect(), weight)).collect()
+        };
+
+        Self { wanted: map(wanted), unwanted: map(unwanted) }
+    }
+
+    /// Returns total weights of satisfied and unsatisfied preferences for given vehicle skills.
+    pub fn get_score(&self, vehicle_skills: Option<&HashSet<String>>) -> (f64, f64) {
+        let has_all = |skills: &HashSet<String>| vehicle_skills.map_or(skills.is_empty(), |vs| skills.is_subset(vs));
+
+        let wanted = self.wanted.iter().map(|(skills, weight)| (has_all(skills), *weight));
+        let unwanted = self.unwanted.iter().map(|(skills, weight)| (!has_all(skills), *weight));
+
+        wanted.chain(unwanted).fold((0., 0.), |(satisfied, unsatisfied), (is_satisfied, weight)| {
+            if is_satisfied {
+                (satisfied + weight, unsatisfied)
+            } else {
+                (satisfied, unsatisfied + weight)
+            }
+        })
+    }
+}
+
+/// Returns total weights of satisfied and unsatisfied job preferences for given actor.
+pub fn get_job_preference_score(actor: &Actor, job: &Job) -> (f64, f64) {
+    job.dimens()
+        .get_job_preferences()
+        .map_or((0., 0.), |preferences| preferences.get_score(actor.vehicle.dimens.get_vehicle_skills()))
+}
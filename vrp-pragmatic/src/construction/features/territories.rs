@@ -0,0 +1,176 @@
+//! A territory feature which restricts or penalizes vehicles serving jobs outside of their territories.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/territories_test.rs"]
+mod territories_test;
+
+use super::*;
+use crate::construction::enablers::{JobTie, VehicleTie};
+use hashbrown::{HashMap, HashSet};
+use vrp_core::models::solution::Activity;
+use vrp_core::rosomaxa::prelude::Objective;
+
+/// Specifies how strictly vehicle territories are enforced.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TerritoryPolicy {
+    /// Vehicle cannot serve job activities outside of its territories.
+    Hard,
+    /// Vehicle can serve job activities outside of its territories, but each of them is penalized.
+    Soft,
+}
+
+/// Specifies vehicle territories.
+pub struct VehicleTerritories {
+    /// Ids of territories assigned to the vehicle.
+    pub ids: HashSet<String>,
+    /// Territory policy.
+    pub policy: TerritoryPolicy,
+}
+
+/// Keeps mapping between locations and territories which contain them.
+#[derive(Default)]
+pub struct TerritoryIndex {
+    index: HashMap<Location, HashSet<String>>,
+}
+
+impl TerritoryIndex {
+    /// Creates a new instance of `TerritoryIndex`.
+    pub fn new(index: HashMap<Location, HashSet<String>>) -> Self {
+        Self { index }
+    }
+
+    /// Returns ids of territories which contain given location.
+    pub fn get(&self, location: Location) -> Option<&HashSet<String>> {
+        self.index.get(&location)
+    }
+
+    /// Checks whether given location is inside any of given territories.
+    pub fn is_inside(&self, location: Location, territories: &HashSet<String>) -> bool {
+        self.index.get(&location).is_some_and(|ids| !ids.is_disjoint(territories))
+    }
+}
+
+/// Creates a territory feature as hard constraint. It is applied only to vehicles with hard policy.
+pub fn create_territory_hard_feature(
+    name: &str,
+    territory_index: Arc<TerritoryIndex>,
+    code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(TerritoryConstraint { territory_index, code }).build()
+}
+
+/// Creates a territory feature as soft constraint. It is applied only to vehicles with soft policy.
+pub fn create_territory_soft_feature(
+    name: &str,
+    territory_index: Arc<TerritoryIndex>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_objective(TerritoryObjective { territory_index }).build()
+}
+
+struct TerritoryConstraint {
+    territory_index: Arc<TerritoryIndex>,
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for TerritoryConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let territories = get_territories(route_ctx.route().actor.as_ref(), TerritoryPolicy::Hard)?;
+
+                let has_no_territory_place = is_territory_job(job.dimens())
+                    && get_singles(job).any(|single| {
+                        single.places.iter().all(|place| {
+                            place.location.is_some_and(|l| !self.territory_index.is_inside(l, territories))
+                        })
+                    });
+
+                if has_no_territory_place {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let territories = get_territories(route_ctx.route().actor.as_ref(), TerritoryPolicy::Hard)?;
+
+                if is_outside(activity_ctx.target, &self.territory_index, territories) {
+                    ConstraintViolation::skip(self.code)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct TerritoryObjective {
+    territory_index: Arc<TerritoryIndex>,
+}
+
+impl Objective for TerritoryObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .filter_map(|route_ctx| {
+                get_territories(route_ctx.route().actor.as_ref(), TerritoryPolicy::Soft)
+                    .map(|territories| (route_ctx, territories))
+            })
+            .map(|(route_ctx, territories)| {
+                route_ctx
+                    .route()
+                    .tour
+                    .all_activities()
+                    .filter(|activity| is_outside(activity, &self.territory_index, territories))
+                    .count()
+            })
+            .sum::<usize>() as f64
+    }
+}
+
+impl FeatureObjective for TerritoryObjective {
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                get_territories(route_ctx.route().actor.as_ref(), TerritoryPolicy::Soft)
+                    .filter(|territories| is_outside(activity_ctx.target, &self.territory_index, territories))
+                    .map_or(Cost::default(), |_| 1.)
+            }
+            MoveContext::Route { .. } => Cost::default(),
+        }
+    }
+}
+
+fn get_territories(actor: &Actor, policy: TerritoryPolicy) -> Option<&HashSet<String>> {
+    actor
+        .vehicle
+        .dimens
+        .get_vehicle_territories()
+        .filter(|territories| territories.policy == policy)
+        .map(|territories| &territories.ids)
+}
+
+fn is_outside(activity: &Activity, territory_index: &TerritoryIndex, territories: &HashSet<String>) -> bool {
+    activity.job.as_ref().is_some_and(|single| {
+        is_territory_job(&single.dimens) && !territory_index.is_inside(activity.place.location, territories)
+    })
+}
+
+fn is_territory_job(dimens: &Dimensions) -> bool {
+    !matches!(dimens.get_job_type().map(String::as_str), Some("break" | "reload" | "recharge"))
+}
+
+fn get_singles(job: &Job) -> Box<dyn Iterator<Item = &Arc<Single>> + '_> {
+    match job {
+        Job::Single(single) => Box::new(std::iter::once(single)),
+        Job::Multi(multi) => Box::new(multi.jobs.iter()),
+    }
+}
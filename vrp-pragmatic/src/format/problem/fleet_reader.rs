@@ -4,14 +4,31 @@ mod fleet_reader_test;
 
 use super::*;
 use crate::construction::enablers::{create_typed_actor_groups, UnknownLocationFallback, VehicleTie};
-use crate::get_unique_locations;
+use crate::construction::features::{LoadCostFactors, TerritoryPolicy, VehicleTerritories};
 use crate::utils::get_approx_transportation;
 use crate::Location as ApiLocation;
+use crate::{get_unique_locations, parse_time_of_day_safe};
 use hashbrown::HashSet;
 use std::cmp::Ordering;
+use vrp_core::construction::features::MinTourLimits;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 
+fn get_time_bands(time_bands: Option<&Vec<VehicleTimeBand>>) -> Vec<TimeBand> {
+    time_bands
+        .iter()
+        .flat_map(|time_bands| time_bands.iter())
+        .map(|time_band| match time_band {
+            VehicleTimeBand::Duration { start, factor } => TimeBand::Duration { start: *start, factor: *factor },
+            VehicleTimeBand::TimeOfDay { start, end, factor } => TimeBand::TimeOfDay {
+                start: parse_time_of_day_safe(start).expect("invalid time of day"),
+                end: parse_time_of_day_safe(end).expect("invalid time of day"),
+                factor: *factor,
+            },
+        })
+        .collect()
+}
+
 pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name).is_none() {
@@ -108,6 +125,11 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let profile = Profile::new(index, vehicle.profile.scale);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let time_bands = get_time_bands(vehicle.costs.time_bands.as_ref());
+        let min_tour_limits =
+            vehicle.limits.as_ref().filter(|_| props.has_min_tour_limits).map(|limits| {
+                (limits.min_tour_size, limits.min_duration, limits.min_load.clone().map(MultiDimLoad::new))
+            });
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
@@ -152,10 +174,39 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
                 }
 
+                if let Some((tour_size, duration, load)) = min_tour_limits {
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_min_tour_limits(MinTourLimits { tour_size, duration, load });
+                    } else {
+                        let load = load.map(|load| SingleDimLoad::new(load.load[0]));
+                        dimens.set_min_tour_limits(MinTourLimits { tour_size, duration, load });
+                    }
+                }
+
+                if !time_bands.is_empty() {
+                    dimens.set_time_bands(time_bands.clone());
+                }
+
+                if let Some(load_factors) = vehicle.costs.load_factors.as_ref().filter(|_| props.has_load_costs) {
+                    dimens.set_load_cost_factors(LoadCostFactors::new(
+                        load_factors.iter().map(|load_factor| (load_factor.load, load_factor.factor)).collect(),
+                    ));
+                }
+
                 if let Some(skills) = vehicle.skills.as_ref() {
                     dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
                 }
 
+                if let Some(territories) = vehicle.territories.as_ref().filter(|_| props.has_territories) {
+                    dimens.set_vehicle_territories(VehicleTerritories {
+                        ids: territories.ids.iter().cloned().collect(),
+                        policy: match territories.policy {
+                            VehicleTerritoryPolicy::Hard => TerritoryPolicy::Hard,
+                            VehicleTerritoryPolicy::Soft => TerritoryPolicy::Soft,
+                        },
+                    });
+                }
+
                 vehicles.push(Arc::new(Vehicle {
                     profile: profile.clone(),
                     costs: costs.clone(),
@@ -78,6 +78,14 @@ pub(super) fn create_goal_context(
         )?);
     }
 
+    if props.has_territories {
+        features.push(create_territory_hard_feature(
+            "territory",
+            get_territory_index(blocks)?,
+            TERRITORY_CONSTRAINT_CODE,
+        )?);
+    }
+
     if props.has_tour_size_limits {
         features.push(create_activity_limit_feature(
             "activity_limit",
@@ -105,13 +113,21 @@ fn get_objective_features(
             objectives
                 .iter()
                 .map(|objective| match objective {
-                    Objective::MinimizeCost => create_minimize_transport_costs_feature(
-                        "min_cost",
-                        blocks.transport.clone(),
-                        blocks.activity.clone(),
-                        state_context.schedule_keys.clone(),
-                        TIME_CONSTRAINT_CODE,
-                    ),
+                    Objective::MinimizeCost => {
+                        let feature = create_minimize_transport_costs_feature(
+                            "min_cost",
+                            blocks.transport.clone(),
+                            blocks.activity.clone(),
+                            state_context.schedule_keys.clone(),
+                            TIME_CONSTRAINT_CODE,
+                        )?;
+
+                        if props.has_load_costs {
+                            get_load_dependent_cost_feature(feature, blocks, props, state_context)
+                        } else {
+                            Ok(feature)
+                        }
+                    }
                     Objective::MinimizeDistance => create_minimize_distance_feature(
                         "min_distance",
                         blocks.transport.clone(),
@@ -250,6 +266,35 @@ fn get_objective_features(
                     Objective::FastService { tolerance } => {
                         get_fast_service_feature("fast_service", blocks, props, *tolerance, state_context)
                     }
+                    Objective::TerritoryAffinity => {
+                        create_territory_soft_feature("territory_affinity", get_territory_index(blocks)?)
+                    }
+                    Objective::JobPreference => create_job_preference_feature(
+                        "job_preference",
+                        Arc::new(|actor, job| get_job_preference_score(actor, job).1),
+                    ),
+                    Objective::MinTourLimits => {
+                        let duration_key = state_context.schedule_keys.total_duration;
+                        let load_key = state_context.next_key();
+                        if props.has_multi_dimen_capacity {
+                            create_min_tour_limit_feature::<MultiDimLoad>(
+                                "min_tour_limits",
+                                Arc::new(|actor| actor.vehicle.dimens.get_min_tour_limits().cloned()),
+                                duration_key,
+                                load_key,
+                            )
+                        } else {
+                            create_min_tour_limit_feature::<SingleDimLoad>(
+                                "min_tour_limits",
+                                Arc::new(|actor| actor.vehicle.dimens.get_min_tour_limits().cloned()),
+                                duration_key,
+                                load_key,
+                            )
+                        }
+                    }
+                    Objective::MinimizeChanges { reference, options } => {
+                        get_minimize_changes_feature("minimize_changes", reference, options)
+                    }
                 })
                 .collect()
         })
@@ -266,6 +311,18 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ve
             vec![Objective::MinimizeCost],
         ];
 
+        if props.has_soft_territories {
+            objectives.insert(2, vec![Objective::TerritoryAffinity])
+        }
+
+        if props.has_min_tour_limits {
+            objectives.insert(1, vec![Objective::MinTourLimits])
+        }
+
+        if props.has_preferences {
+            objectives.insert(objectives.len() - 1, vec![Objective::JobPreference])
+        }
+
         if props.has_value {
             objectives.insert(0, vec![Objective::MaximizeValue { breaks: None }])
         }
@@ -276,6 +333,58 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ve
 
 const RELOAD_THRESHOLD: f64 = 0.9;
 
+fn get_load_dependent_cost_feature(
+    cost_feature: Feature,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
+    state_context: &StateKeyContext,
+) -> Result<Feature, GenericError> {
+    let transport = blocks.transport.clone();
+    let factor_fn = Arc::new(get_load_cost_factor);
+    let current_capacity_key = state_context.capacity_keys.current_capacity;
+
+    if props.has_multi_dimen_capacity {
+        create_load_dependent_cost_feature::<MultiDimLoad>(cost_feature, transport, factor_fn, current_capacity_key)
+    } else {
+        create_load_dependent_cost_feature::<SingleDimLoad>(cost_feature, transport, factor_fn, current_capacity_key)
+    }
+}
+
+fn get_minimize_changes_feature(
+    name: &str,
+    reference: &[ReferenceTour],
+    options: &Option<MinimizeChangesOptions>,
+) -> Result<Feature, GenericError> {
+    let mut tours = HashMap::new();
+    let mut jobs = HashMap::new();
+
+    reference.iter().enumerate().for_each(|(tour_idx, tour)| {
+        tours.entry((tour.vehicle_id.clone(), tour.shift_index)).or_insert(tour_idx);
+        tour.job_ids.iter().enumerate().for_each(|(rank, job_id)| {
+            jobs.entry(job_id.clone()).or_insert(ReferencePosition { tour: tour_idx, rank });
+        });
+    });
+
+    let weights = MinimizeChangesWeights {
+        reassignment: options.as_ref().and_then(|options| options.reassignment).unwrap_or(1.),
+        sequence: options.as_ref().and_then(|options| options.sequence).unwrap_or(0.5),
+    };
+
+    create_minimize_changes_feature(
+        name,
+        Arc::new(move |job| job.dimens().get_job_id().and_then(|job_id| jobs.get(job_id)).copied()),
+        Arc::new(move |actor| {
+            let dimens = &actor.vehicle.dimens;
+            dimens
+                .get_vehicle_id()
+                .zip(dimens.get_shift_index())
+                .and_then(|(vehicle_id, shift_index)| tours.get(&(vehicle_id.clone(), shift_index)))
+                .copied()
+        }),
+        weights,
+    )
+}
+
 fn get_capacity_feature(
     name: &str,
     api_problem: &ApiProblem,
@@ -584,6 +693,10 @@ fn extract_feature_map(features: &[Vec<Feature>]) -> Result<(Vec<Vec<String>>, V
     Ok((global_objective_map, local_objective_map))
 }
 
+fn get_territory_index(blocks: &ProblemBlocks) -> Result<Arc<TerritoryIndex>, GenericError> {
+    blocks.territory_index.clone().ok_or_else(|| "misconfiguration in goal reader: territory index is not set".into())
+}
+
 fn get_threshold(options: &Option<BalanceOptions>) -> Option<f64> {
     options.as_ref().and_then(|o| o.threshold)
 }
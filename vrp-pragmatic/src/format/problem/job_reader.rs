@@ -1,6 +1,10 @@
 use crate::construction::enablers::{BreakTie, JobTie, VehicleTie};
-use crate::construction::features::{BreakPolicy, JobSkills as FeatureJobSkills};
+use crate::construction::features::{
+    BreakPolicy, JobPreferences as FeatureJobPreferences, JobSkills as FeatureJobSkills,
+};
 use crate::format::coord_index::CoordIndex;
+use crate::format::problem::JobPreference as ApiJobPreference;
+use crate::format::problem::JobPreferences as ApiJobPreferences;
 use crate::format::problem::JobSkills as ApiJobSkills;
 use crate::format::problem::*;
 use crate::format::{JobIndex, Location};
@@ -410,7 +414,8 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
-        .set_job_skills(get_skills(&job.skills));
+        .set_job_skills(get_skills(&job.skills))
+        .set_job_preferences(get_preferences(&job.preferences));
 
     Job::Single(Arc::new(single))
 }
@@ -427,7 +432,8 @@ fn get_multi_job(
         .set_job_value(job.value)
         .set_job_group(job.group.clone())
         .set_job_compatibility(job.compatibility.clone())
-        .set_job_skills(get_skills(&job.skills));
+        .set_job_skills(get_skills(&job.skills))
+        .set_job_preferences(get_preferences(&job.preferences));
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -463,6 +469,21 @@ fn get_skills(skills: &Option<ApiJobSkills>) -> Option<FeatureJobSkills> {
         .map(|skills| FeatureJobSkills::new(skills.all_of.clone(), skills.one_of.clone(), skills.none_of.clone()))
 }
 
+fn get_preferences(preferences: &Option<ApiJobPreferences>) -> Option<FeatureJobPreferences> {
+    let map = |preferences: &Option<Vec<ApiJobPreference>>| {
+        preferences
+            .iter()
+            .flatten()
+            .map(|preference| (preference.skills.clone(), preference.weight))
+            .collect::<Vec<_>>()
+    };
+
+    preferences
+        .as_ref()
+        .map(|preferences| FeatureJobPreferences::new(map(&preferences.wanted), map(&preferences.unwanted)))
+        .filter(|preferences| !preferences.wanted.is_empty() || !preferences.unwanted.is_empty())
+}
+
 fn empty() -> MultiDimLoad {
     MultiDimLoad::default()
 }
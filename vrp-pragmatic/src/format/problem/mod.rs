@@ -1,7 +1,10 @@
 //! Specifies logic to read problem and routing matrix from json input.
 
 use super::*;
+use crate::construction::features::TerritoryIndex;
+use crate::format::solution::Solution as ApiSolution;
 use crate::parse_time;
+use hashbrown::HashSet;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::common::TimeWindow;
@@ -29,6 +32,9 @@ mod job_reader;
 mod problem_reader;
 use self::problem_reader::{map_to_problem_with_approx, map_to_problem_with_matrices};
 
+mod territory_reader;
+pub(crate) use self::territory_reader::get_territory_polygons;
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     /// Reads problem defined in pragmatic format.
@@ -99,10 +105,57 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     }
 }
 
+/// Sets tours of the reference solution to the `minimize-changes` objective of the problem.
+/// Returns an error if the problem has no such objective.
+pub fn set_reference_solution(problem: &mut Problem, solution: &ApiSolution) -> Result<(), GenericError> {
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+
+    let reference = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let mut visited = HashSet::new();
+            let tour_job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .map(|activity| activity.job_id.as_str())
+                .filter(|job_id| job_ids.contains(job_id) && visited.insert(*job_id))
+                .map(|job_id| job_id.to_string())
+                .collect();
+
+            ReferenceTour { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index, job_ids: tour_job_ids }
+        })
+        .collect::<Vec<_>>();
+
+    let objective = problem
+        .objectives
+        .iter_mut()
+        .flatten()
+        .flatten()
+        .find_map(|objective| match objective {
+            Objective::MinimizeChanges { reference, .. } => Some(reference),
+            _ => None,
+        })
+        .ok_or_else(|| GenericError::from("problem has no 'minimize-changes' objective"))?;
+
+    *objective = reference;
+
+    Ok(())
+}
+
 pub(crate) fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
     job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter()).flatten()
 }
 
+/// Checks whether job has at least one wanted or unwanted preference.
+pub(crate) fn has_job_preferences(job: &Job) -> bool {
+    job.preferences.as_ref().is_some_and(|preferences| {
+        preferences.wanted.as_ref().is_some_and(|wanted| !wanted.is_empty())
+            || preferences.unwanted.as_ref().is_some_and(|unwanted| !unwanted.is_empty())
+    })
+}
+
 /// Keeps track of problem properties (e.g. features).
 struct ProblemProperties {
     has_multi_dimen_capacity: bool,
@@ -117,6 +170,11 @@ struct ProblemProperties {
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_min_tour_limits: bool,
+    has_load_costs: bool,
+    has_territories: bool,
+    has_soft_territories: bool,
+    has_preferences: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -128,6 +186,7 @@ struct ProblemBlocks {
     activity: Arc<dyn ActivityCost + Send + Sync>,
     locks: Vec<Arc<Lock>>,
     reserved_times_index: ReservedTimesIndex,
+    territory_index: Option<Arc<TerritoryIndex>>,
 }
 
 fn parse_time_window(tw: &[String]) -> TimeWindow {
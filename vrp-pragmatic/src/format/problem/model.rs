@@ -53,6 +53,26 @@ pub struct JobSkills {
     pub none_of: Option<Vec<String>>,
 }
 
+/// A job preference which is defined by vehicle skills.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPreference {
+    /// Vehicle skills: preference matches a vehicle which has all of them.
+    pub skills: Vec<String>,
+    /// A penalty weight applied when preference is not satisfied.
+    pub weight: f64,
+}
+
+/// A job preferences (soft skills) for a vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobPreferences {
+    /// Vehicle is preferred to match these preferences.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wanted: Option<Vec<JobPreference>>,
+    /// Vehicle is preferred to not match these preferences.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unwanted: Option<Vec<JobPreference>>,
+}
+
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct JobPlace {
@@ -111,6 +131,10 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<JobSkills>,
 
+    /// A job preferences (soft skills) for serving a job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<JobPreferences>,
+
     /// Job value, bigger value - more chances for assignment.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
@@ -212,6 +236,35 @@ pub struct VicinityFilteringPolicy {
 
 // endregion
 
+// region Territories
+
+/// Specifies territory geometry in GeoJSON format. Coordinates are defined as longitude and latitude pairs.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum TerritoryGeometry {
+    /// A polygon: the first ring is an outer boundary, the rest are holes.
+    Polygon {
+        /// List of linear rings.
+        coordinates: Vec<Vec<(f64, f64)>>,
+    },
+    /// A multi polygon: a list of polygons.
+    MultiPolygon {
+        /// List of polygons.
+        coordinates: Vec<Vec<Vec<(f64, f64)>>>,
+    },
+}
+
+/// Specifies a territory: a named area on the map.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Territory {
+    /// A territory id.
+    pub id: String,
+    /// A territory geometry.
+    pub geometry: TerritoryGeometry,
+}
+
+// endregion
+
 /// A plan specifies work which has to be done.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Plan {
@@ -225,6 +278,10 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// List of territories: named areas which can be used to restrict vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub territories: Option<Vec<Territory>>,
 }
 
 // endregion
@@ -243,6 +300,49 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Time bands which change time costs within their intervals, e.g. overtime or night premium.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_bands: Option<Vec<VehicleTimeBand>>,
+
+    /// Load dependent travel cost factors, e.g. for heavy vehicles which consume more fuel when loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_factors: Option<Vec<VehicleLoadFactor>>,
+}
+
+/// Specifies a travel cost factor for a given vehicle load. Factors between specified loads are
+/// interpolated linearly.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleLoadFactor {
+    /// A load as a fraction of vehicle capacity in range [0, 1].
+    pub load: f64,
+    /// A travel cost factor.
+    pub factor: f64,
+}
+
+/// Specifies vehicle time band: time costs within the band are multiplied by its factor.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum VehicleTimeBand {
+    /// A band which starts once tour duration exceeds given amount of seconds (e.g. overtime).
+    #[serde(rename(deserialize = "duration", serialize = "duration"))]
+    Duration {
+        /// A duration since tour start in seconds.
+        start: f64,
+        /// A time cost factor.
+        factor: f64,
+    },
+    /// A daily recurring band (e.g. night premium).
+    #[serde(rename(deserialize = "time-of-day", serialize = "time-of-day"))]
+    TimeOfDay {
+        /// A band start time in "HH:MM" or "HH:MM:SS" format (UTC).
+        start: String,
+        /// A band end time in "HH:MM" or "HH:MM:SS" format (UTC). Can be less than start: then
+        /// the band wraps over midnight.
+        end: String,
+        /// A time cost factor.
+        factor: f64,
+    },
 }
 
 /// Specifies vehicle shift start.
@@ -356,6 +456,40 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Min amount of job activities per used vehicle.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tour_size: Option<usize>,
+
+    /// Min duration per used vehicle's tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_duration: Option<f64>,
+
+    /// Min total load handled by used vehicle's tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_load: Option<Vec<i32>>,
+}
+
+/// Specifies how strictly vehicle territories are enforced.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VehicleTerritoryPolicy {
+    /// Vehicle cannot serve jobs outside of its territories.
+    Hard,
+    /// Vehicle can serve jobs outside of its territories, but it is penalized.
+    Soft,
+}
+
+/// Specifies vehicle territories.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleTerritories {
+    /// Ids of territories assigned to the vehicle.
+    pub ids: Vec<String>,
+    /// Territory policy.
+    pub policy: VehicleTerritoryPolicy,
 }
 
 /// Vehicle optional break time variant.
@@ -465,6 +599,10 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle territories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub territories: Option<VehicleTerritories>,
 }
 
 /// Specifies a vehicle profile.
@@ -600,6 +738,27 @@ pub enum Objective {
     /// An objective to control order of job activities in the tour.
     TourOrder,
 
+    /// An objective to minimize amount of job activities served outside of vehicle territories.
+    TerritoryAffinity,
+
+    /// An objective to minimize total weight of unsatisfied job preferences.
+    JobPreference,
+
+    /// An objective to minimize deficit of used tours which do not meet vehicle minimum limits.
+    MinTourLimits,
+
+    /// An objective to minimize changes comparing to the reference solution: jobs assigned to
+    /// other vehicle shifts and changes of job sequence within the tour.
+    MinimizeChanges {
+        /// Tours of the reference solution. Can be omitted in the problem definition when the
+        /// reference solution is supplied separately.
+        #[serde(default)]
+        reference: Vec<ReferenceTour>,
+        /// Options to configure objective weights.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<MinimizeChangesOptions>,
+    },
+
     /// An objective to prefer jobs to be served as soon as possible.
     FastService {
         /// An objective tolerance specifies how different objective values have to be
@@ -618,6 +777,32 @@ pub struct BalanceOptions {
     pub threshold: Option<f64>,
 }
 
+/// Specifies a tour of the reference solution used by minimize changes objective.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceTour {
+    /// A vehicle id.
+    pub vehicle_id: String,
+    /// A vehicle shift index.
+    #[serde(default)]
+    pub shift_index: usize,
+    /// Ids of jobs served by the tour in the order of their activities.
+    pub job_ids: Vec<String>,
+}
+
+/// Specifies minimize changes objective options.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MinimizeChangesOptions {
+    /// A penalty for each job assigned to a different vehicle shift than in the reference solution.
+    /// Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reassignment: Option<f64>,
+    /// A penalty for each pair of consecutive jobs served in the reverse order comparing to the
+    /// reference tour. Default is 0.5.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<f64>,
+}
+
 /// Specifies tour compactness options to relax impact of objective.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
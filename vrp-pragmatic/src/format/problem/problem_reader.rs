@@ -4,14 +4,18 @@ use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
 use crate::format::problem::goal_reader::create_goal_context;
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
+use crate::format::problem::territory_reader::create_territory_index;
 use crate::format::{FormatError, JobIndex};
 use crate::validation::ValidationContext;
 use crate::{parse_time, CoordIndex};
+use vrp_core::algorithms::geometry::Point;
 use vrp_core::construction::enablers::*;
 use vrp_core::construction::heuristics::StateKeyRegistry;
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
+use vrp_core::models::problem::{TimeBandActivityCost, TimeBandDimension, TimeBandTransportCost};
 use vrp_core::models::ExtrasBuilder;
 use vrp_core::solver::processing::{ReservedTimeDimension, VicinityDimension};
+use vrp_core::solver::search::LocationCoordinates;
 
 pub(super) fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
@@ -40,6 +44,14 @@ pub(super) fn map_to_problem(
     extras.set_coord_index(coord_index);
 
     let coord_index = extras.get_coord_index().expect("cannot get coord index");
+    if coord_index.has_coordinates() {
+        let coord_index = coord_index.clone();
+        extras.set_location_coordinates(Arc::new(move |location| match coord_index.get_by_idx(location) {
+            Some(Location::Coordinate { lat, lng }) => Some(Point::new(lng, lat)),
+            _ => None,
+        }));
+    }
+
     let mut job_index = JobIndex::default();
 
     let props = get_problem_properties(&api_problem, &matrices);
@@ -158,6 +170,7 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.);
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
+    let has_preferences = api_problem.plan.jobs.iter().any(has_job_preferences);
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
 
@@ -167,6 +180,28 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .iter()
         .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
 
+    let has_min_tour_limits = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|v| v.limits.as_ref())
+        .any(|limits| limits.min_tour_size.is_some() || limits.min_duration.is_some() || limits.min_load.is_some());
+
+    let has_load_costs =
+        api_problem.fleet.vehicles.iter().any(|v| v.costs.load_factors.as_ref().is_some_and(|f| !f.is_empty()));
+
+    let get_territory_policies = || {
+        api_problem
+            .fleet
+            .vehicles
+            .iter()
+            .filter_map(|v| v.territories.as_ref())
+            .filter(|territories| !territories.ids.is_empty())
+            .map(|territories| &territories.policy)
+    };
+    let has_territories = get_territory_policies().next().is_some();
+    let has_soft_territories = get_territory_policies().any(|policy| matches!(policy, VehicleTerritoryPolicy::Soft));
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -180,6 +215,11 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_min_tour_limits,
+        has_load_costs,
+        has_territories,
+        has_soft_territories,
+        has_preferences,
     }
 }
 
@@ -221,6 +261,14 @@ fn get_problem_blocks(
             )?
     };
 
+    let has_time_bands = fleet.vehicles.iter().any(|vehicle| vehicle.dimens.get_time_bands().is_some());
+    let (transport, activity): (Arc<dyn TransportCost + Send + Sync>, Arc<dyn ActivityCost + Send + Sync>) =
+        if has_time_bands {
+            (Arc::new(TimeBandTransportCost::new(transport)), Arc::new(TimeBandActivityCost::new(activity)))
+        } else {
+            (transport, activity)
+        };
+
     // TODO pass random from outside as there might be need to have it initialized with seed
     //      at the moment, this random instance is used only by multi job permutation generator
     let random: Arc<dyn Random + Send + Sync> = Arc::new(DefaultRandom::default());
@@ -235,6 +283,12 @@ fn get_problem_blocks(
     );
     let locks = locks.into_iter().chain(read_locks(api_problem, job_index)).collect::<Vec<_>>();
 
+    let territory_index = if problem_props.has_territories {
+        Some(Arc::new(create_territory_index(api_problem, &coord_index)))
+    } else {
+        None
+    };
+
     Ok(ProblemBlocks {
         jobs: Arc::new(jobs),
         fleet: Arc::new(fleet),
@@ -243,5 +297,6 @@ fn get_problem_blocks(
         activity,
         locks,
         reserved_times_index,
+        territory_index,
     })
 }
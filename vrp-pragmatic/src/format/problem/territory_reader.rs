@@ -0,0 +1,52 @@
+use super::*;
+use crate::construction::features::TerritoryIndex;
+use hashbrown::{HashMap, HashSet};
+use vrp_core::algorithms::geometry::{Point, Polygon};
+
+/// Creates a territory index which maps problem locations to territories containing them.
+pub(super) fn create_territory_index(api_problem: &ApiProblem, coord_index: &CoordIndex) -> TerritoryIndex {
+    let territories = get_territory_polygons(api_problem);
+
+    let index = coord_index
+        .unique()
+        .into_iter()
+        .filter_map(|location| match location {
+            Location::Coordinate { lat, lng } => {
+                let point = Point::new(lng, lat);
+                let ids = territories
+                    .iter()
+                    .filter(|(_, polygons)| polygons.iter().any(|polygon| polygon.contains(&point)))
+                    .map(|(id, _)| id.clone())
+                    .collect::<HashSet<_>>();
+
+                coord_index.get_by_loc(&location).map(|idx| (idx, ids))
+            }
+            _ => None,
+        })
+        .filter(|(_, ids)| !ids.is_empty())
+        .collect::<HashMap<_, _>>();
+
+    TerritoryIndex::new(index)
+}
+
+/// Returns territory polygons defined in the plan.
+pub(crate) fn get_territory_polygons(api_problem: &ApiProblem) -> Vec<(String, Vec<Polygon>)> {
+    api_problem
+        .plan
+        .territories
+        .iter()
+        .flatten()
+        .map(|territory| (territory.id.clone(), get_polygons(&territory.geometry)))
+        .collect()
+}
+
+fn get_polygons(geometry: &TerritoryGeometry) -> Vec<Polygon> {
+    let create_polygon = |rings: &Vec<Vec<(f64, f64)>>| {
+        Polygon::new(rings.iter().map(|ring| ring.iter().map(|&(lng, lat)| Point::new(lng, lat)).collect()).collect())
+    };
+
+    match geometry {
+        TerritoryGeometry::Polygon { coordinates } => vec![create_polygon(coordinates)],
+        TerritoryGeometry::MultiPolygon { coordinates } => coordinates.iter().map(create_polygon).collect(),
+    }
+}
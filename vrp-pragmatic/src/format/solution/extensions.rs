@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{CostBreakdown, Statistic, Timing};
 use std::ops::Add;
 
 impl Add for Statistic {
@@ -17,6 +17,19 @@ impl Add for Statistic {
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
             },
+            breakdown: match (self.breakdown, rhs.breakdown) {
+                (None, None) => None,
+                (lhs_breakdown, rhs_breakdown) => {
+                    // NOTE statistic without breakdown has regular cost only
+                    let lhs_breakdown = lhs_breakdown.unwrap_or(CostBreakdown { regular: self.cost, overtime: 0. });
+                    let rhs_breakdown = rhs_breakdown.unwrap_or(CostBreakdown { regular: rhs.cost, overtime: 0. });
+
+                    Some(CostBreakdown {
+                        regular: lhs_breakdown.regular + rhs_breakdown.regular,
+                        overtime: lhs_breakdown.overtime + rhs_breakdown.overtime,
+                    })
+                }
+            },
         }
     }
 }
@@ -91,7 +91,7 @@ pub fn read_init_solution<R: Read>(
         problem.jobs.all().filter(|job| added_jobs.get(job).is_none()).map(|job| (job, UnassignmentInfo::Unknown)),
     );
 
-    Ok(Solution { cost: Cost::default(), registry, routes, unassigned, telemetry: None })
+    Ok(Solution { cost: Cost::default(), registry, routes, unassigned, telemetry: None, is_final: false })
 }
 
 fn try_insert_activity(
@@ -17,7 +17,7 @@ mod model;
 pub use self::model::*;
 
 mod solution_writer;
-pub(crate) use self::solution_writer::create_solution;
+pub use self::solution_writer::create_solution;
 
 use super::*;
 use crate::{format_time, parse_time};
@@ -31,6 +31,7 @@ type ApiMetrics = model::Metrics;
 type ApiGeneration = model::Generation;
 type AppPopulation = model::Population;
 type ApiIndividual = model::Individual;
+type ApiOperatorStatistic = model::OperatorStatistic;
 type DomainProblem = vrp_core::models::Problem;
 type DomainSolution = vrp_core::models::Solution;
 type DomainSchedule = vrp_core::models::common::Schedule;
@@ -74,7 +75,8 @@ pub fn write_pragmatic<W: Write>(
     Ok(())
 }
 
-fn map_code_reason(code: i32) -> (&'static str, &'static str) {
+/// Maps constraint violation code to unassigned reason code and its description.
+pub fn map_code_reason(code: i32) -> (&'static str, &'static str) {
     match code {
         SKILL_CONSTRAINT_CODE => ("SKILL_CONSTRAINT", "cannot serve required skill"),
         TIME_CONSTRAINT_CODE => ("TIME_WINDOW_CONSTRAINT", "cannot be visited within time window"),
@@ -101,6 +103,9 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
         RECHARGE_CONSTRAINT_CODE => ("RECHARGE_CONSTRAINT_CODE", "cannot be assigned due to recharge constraint"),
+        TERRITORY_CONSTRAINT_CODE => {
+            ("TERRITORY_CONSTRAINT", "cannot be assigned due to territory constraint of vehicle")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -122,6 +127,7 @@ fn map_reason_code(reason: &str) -> i32 {
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
         "RECHARGE_CONSTRAINT_CODE" => RECHARGE_CONSTRAINT_CODE,
+        "TERRITORY_CONSTRAINT" => TERRITORY_CONSTRAINT_CODE,
         _ => -1,
     }
 }
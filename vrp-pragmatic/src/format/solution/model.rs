@@ -38,6 +38,19 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Cost breakdown into regular and overtime parts. Omitted when vehicle time bands are not used.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<CostBreakdown>,
+}
+
+/// Represents cost breakdown.
+#[derive(Clone, Deserialize, Default, Serialize, PartialEq, Debug)]
+pub struct CostBreakdown {
+    /// A cost without time band effect.
+    pub regular: f64,
+    /// An extra cost caused by time bands (e.g. overtime or night premium).
+    pub overtime: f64,
 }
 
 /// Represents a schedule.
@@ -231,6 +244,18 @@ pub struct Tour {
     pub stops: Vec<Stop>,
     /// Tour statistic.
     pub statistic: Statistic,
+    /// Job preferences score of the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<PreferenceScore>,
+}
+
+/// Specifies how well job preferences are satisfied.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct PreferenceScore {
+    /// Total weight of satisfied job preferences.
+    pub satisfied: f64,
+    /// Total weight of unsatisfied job preferences.
+    pub unsatisfied: f64,
 }
 
 /// Unassigned job reason.
@@ -278,6 +303,16 @@ pub enum Violation {
         /// Index of the shift.
         shift_index: usize,
     },
+
+    /// A minimum tour limits violation.
+    MinTourLimit {
+        /// An id of a vehicle which tour does not meet minimum limits.
+        vehicle_id: String,
+        /// Index of the shift.
+        shift_index: usize,
+        /// Names of violated limits.
+        limits: Vec<String>,
+    },
 }
 
 /// Encapsulates different measurements regarding algorithm evaluation.
@@ -291,6 +326,29 @@ pub struct Metrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<Generation>,
+    /// Statistics learned about search operators. Can be used as priors for the next run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operators: Option<Vec<OperatorStatistic>>,
+}
+
+/// Represents learned reward statistics of search operator in the given search state.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorStatistic {
+    /// A search state name.
+    pub state: String,
+    /// A search operator name.
+    pub name: String,
+    /// Gamma shape parameter.
+    pub alpha: f64,
+    /// Gamma rate parameter.
+    pub beta: f64,
+    /// Estimated reward mean.
+    pub mu: f64,
+    /// Estimated reward variance.
+    pub v: f64,
+    /// The number of times the operator has been used.
+    pub n: usize,
 }
 
 /// Represents information about generation.
@@ -329,6 +387,31 @@ pub struct Population {
     pub individuals: Vec<Individual>,
 }
 
+/// Specifies a lower bound on objective value and a relative gap between actual value and the bound.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QualityBound {
+    /// A lower bound on objective value.
+    pub lower_bound: f64,
+    /// A relative gap between actual value and its lower bound: the solution is not worse than
+    /// optimal by more than this ratio.
+    pub gap: f64,
+}
+
+/// Estimates solution quality using fast lower bounds on primary objectives for assigned jobs.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Quality {
+    /// A bound on total cost.
+    pub cost: QualityBound,
+    /// A bound on total distance.
+    pub distance: QualityBound,
+    /// A bound on total driving time.
+    pub driving: QualityBound,
+    /// A bound on amount of tours. Omitted when vehicles can reload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tours: Option<QualityBound>,
+}
+
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Extras {
@@ -336,6 +419,10 @@ pub struct Extras {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
 
+    /// A solution quality estimation. Available when solution is produced by solver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<Quality>,
+
     /// Represents solution as a collection of geo json features.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub features: Option<FeatureCollection>,
@@ -3,17 +3,21 @@
 mod writer_test;
 
 use crate::construction::enablers::{JobTie, VehicleTie};
+use crate::construction::features::{get_job_preference_score, get_load_cost_factor, LoadCostFactors};
 use crate::format::coord_index::CoordIndex;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
+use hashbrown::HashSet;
 use vrp_core::construction::enablers::{get_route_intervals, ReservedTimesIndex};
+use vrp_core::construction::features::{get_tour_load, MinTourLimits};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Multi, TravelTime};
+use vrp_core::models::problem::{get_time_band_duration, Multi, TimeBandDimension, TravelTime, Vehicle};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
 use vrp_core::solver::processing::{ReservedTimeDimension, VicinityDimension};
+use vrp_core::solver::{get_relative_gap, LowerBounds};
 use vrp_core::utils::CollectGroupBy;
 
 struct Leg {
@@ -32,8 +36,8 @@ impl Leg {
     }
 }
 
-/// Creates solution.
-pub(crate) fn create_solution(
+/// Creates solution in pragmatic format from the domain one.
+pub fn create_solution(
     problem: &DomainProblem,
     solution: &DomainSolution,
     output_type: &PragmaticOutputType,
@@ -55,7 +59,7 @@ pub(crate) fn create_solution(
 
     let api_solution = ApiSolution { statistic, tours, unassigned, violations, extras: None };
 
-    let extras = create_extras(problem, &api_solution, solution.telemetry.as_ref(), output_type);
+    let extras = create_extras(problem, solution, &api_solution, output_type);
 
     ApiSolution { extras, ..api_solution }
 }
@@ -81,8 +85,15 @@ fn create_tour(
         shift_index: vehicle.dimens.get_shift_index().unwrap(),
         stops: vec![],
         statistic: Statistic::default(),
+        preferences: None,
     };
 
+    let has_time_bands = vehicle.dimens.get_time_bands().is_some_and(|time_bands| !time_bands.is_empty());
+    let mut overtime_cost = Cost::default();
+
+    let load_capacity =
+        vehicle.dimens.get_load_cost_factors().and_then(|_| get_vehicle_capacity(vehicle, is_multi_dimen));
+
     let intervals = get_route_intervals(route, |a| get_activity_type(a).map_or(false, |t| t == "reload"));
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
@@ -167,7 +178,11 @@ fn create_tour(
                     let prev_departure = TravelTime::Departure(prev_departure);
                     let duration = transport.duration(route, prev_location, act.place.location, prev_departure);
                     let transport_cost = transport.cost(route, prev_location, act.place.location, prev_departure);
-                    (duration, transport_cost)
+                    let load_factor = load_capacity.as_ref().map_or(1., |capacity| {
+                        let ratio = leg.load.unwrap().ratio(capacity);
+                        get_load_cost_factor(actor, if ratio.is_finite() { ratio.clamp(0., 1.) } else { 0. })
+                    });
+                    (duration, transport_cost * load_factor)
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
                     (0., commuting * vehicle.costs.per_service_time)
@@ -189,7 +204,19 @@ fn create_tour(
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let waiting_time = TimeWindow::new(activity_arrival, service_start);
+                let waiting_cost = get_time_band_duration(route, &waiting_time) * vehicle.costs.per_waiting_time;
+                let total_cost = serving_cost + transport_cost + waiting_cost;
+
+                if has_time_bands {
+                    let get_extra_duration = |time: TimeWindow| get_time_band_duration(route, &time) - time.duration();
+                    let driving_time = TimeWindow::new(prev_departure, prev_departure + driving);
+                    let serving_time = TimeWindow::new(service_start, service_start + act.place.duration);
+
+                    overtime_cost += get_extra_duration(driving_time) * vehicle.costs.per_driving_time
+                        + get_extra_duration(waiting_time) * vehicle.costs.per_waiting_time
+                        + get_extra_duration(serving_time) * vehicle.costs.per_service_time;
+                }
 
                 let location_distance =
                     transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
@@ -270,6 +297,7 @@ fn create_tour(
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
                         },
+                        breakdown: None,
                     },
                     load: Some(load),
                 }
@@ -282,7 +310,12 @@ fn create_tour(
     });
 
     leg.statistic.cost += vehicle.costs.fixed;
+    if has_time_bands {
+        leg.statistic.breakdown =
+            Some(CostBreakdown { regular: leg.statistic.cost - overtime_cost, overtime: overtime_cost });
+    }
     tour.statistic = leg.statistic;
+    tour.preferences = get_preference_score(route);
 
     insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
 
@@ -314,6 +347,20 @@ fn create_tour(
     tour
 }
 
+fn get_preference_score(route: &Route) -> Option<PreferenceScore> {
+    let actor = route.actor.as_ref();
+
+    route
+        .tour
+        .jobs()
+        .filter(|job| job.dimens().get_job_preferences().is_some())
+        .map(|job| get_job_preference_score(actor, job))
+        .reduce(|(satisfied_acc, unsatisfied_acc), (satisfied, unsatisfied)| {
+            (satisfied_acc + satisfied, unsatisfied_acc + unsatisfied)
+        })
+        .map(|(satisfied, unsatisfied)| PreferenceScore { satisfied, unsatisfied })
+}
+
 fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
@@ -384,7 +431,7 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
 }
 
 fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
-    // NOTE at the moment only break violation is mapped
+    // NOTE at the moment only break and min tour limit violations are mapped
     let violations = solution
         .unassigned
         .iter()
@@ -393,6 +440,7 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
             vehicle_id: job.dimens().get_vehicle_id().expect("vehicle id").clone(),
             shift_index: job.dimens().get_shift_index().expect("shift index"),
         })
+        .chain(solution.routes.iter().filter_map(create_min_tour_limit_violation))
         .collect::<Vec<_>>();
 
     if violations.is_empty() {
@@ -402,6 +450,49 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     }
 }
 
+fn create_min_tour_limit_violation(route: &Route) -> Option<Violation> {
+    let dimens = &route.actor.vehicle.dimens;
+
+    let limits = if let Some(limits) = dimens.get_min_tour_limits::<MultiDimLoad>() {
+        get_violated_min_tour_limits(route, limits)
+    } else if let Some(limits) = dimens.get_min_tour_limits::<SingleDimLoad>() {
+        get_violated_min_tour_limits(route, limits)
+    } else {
+        return None;
+    };
+
+    if limits.is_empty() {
+        None
+    } else {
+        Some(Violation::MinTourLimit {
+            vehicle_id: dimens.get_vehicle_id().expect("vehicle id").clone(),
+            shift_index: dimens.get_shift_index().expect("shift index"),
+            limits,
+        })
+    }
+}
+
+fn get_violated_min_tour_limits<T: LoadOps>(route: &Route, limits: &MinTourLimits<T>) -> Vec<String> {
+    if !route.tour.has_jobs() {
+        return vec![];
+    }
+
+    let duration = route
+        .tour
+        .end()
+        .zip(route.tour.start())
+        .map_or(Duration::default(), |(end, start)| end.schedule.departure - start.schedule.departure);
+    let load = get_tour_load(route.tour.all_activities());
+
+    let (tour_size_ok, duration_ok, load_ok) = limits.check(route.tour.job_activity_count(), duration, load);
+
+    [(tour_size_ok, "minTourSize"), (duration_ok, "minDuration"), (load_ok, "minLoad")]
+        .into_iter()
+        .filter(|(is_ok, _)| !is_ok)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
 fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
@@ -424,31 +515,87 @@ fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<Mult
     }
 }
 
+fn get_vehicle_capacity(vehicle: &Vehicle, is_multi_dimen: bool) -> Option<MultiDimLoad> {
+    if is_multi_dimen {
+        vehicle.dimens.get_capacity().cloned()
+    } else {
+        vehicle.dimens.get_capacity().map(|capacity: &SingleDimLoad| MultiDimLoad::new(vec![capacity.value]))
+    }
+}
+
 fn get_parking_time(extras: &DomainExtras) -> f64 {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
 
 fn create_extras(
     problem: &DomainProblem,
-    solution: &ApiSolution,
-    metrics: Option<&TelemetryMetrics>,
+    solution: &DomainSolution,
+    api_solution: &ApiSolution,
     output_type: &PragmaticOutputType,
 ) -> Option<Extras> {
+    let metrics = solution.telemetry.as_ref();
+    // NOTE estimate quality only for final solutions produced by solver
+    let get_quality = || solution.is_final.then(|| create_quality(problem, solution, api_solution));
+
     match output_type {
         PragmaticOutputType::OnlyPragmatic => {
-            get_api_metrics(metrics).map(|metrics| Extras { metrics: Some(metrics), features: None })
+            let (metrics, quality) = (get_api_metrics(metrics), get_quality());
+            (metrics.is_some() || quality.is_some()).then_some(Extras { metrics, quality, features: None })
         }
         PragmaticOutputType::OnlyGeoJson => None,
         PragmaticOutputType::Combined => {
             Some(Extras {
                 metrics: get_api_metrics(metrics),
+                quality: get_quality(),
                 // TODO do not hide error here, propagate it to the caller
-                features: create_feature_collection(problem, solution).ok(),
+                features: create_feature_collection(problem, api_solution).ok(),
             })
         }
     }
 }
 
+fn create_quality(problem: &DomainProblem, solution: &DomainSolution, api_solution: &ApiSolution) -> Quality {
+    // NOTE skip conditional jobs such as breaks or reloads: they are not required to be served
+    let jobs = solution
+        .routes
+        .iter()
+        .flat_map(|route| route.tour.jobs())
+        .filter(|job| job.dimens().get_vehicle_id().is_none())
+        .cloned()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let has_reloads =
+        problem.jobs.all().any(|job| job.dimens().get_job_type().is_some_and(|job_type| job_type == "reload"));
+
+    // NOTE vehicles without load cost factors are not affected by load
+    let load_factor = problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| vehicle.dimens.get_load_cost_factors().map_or(1., LoadCostFactors::get_min_factor))
+        .reduce(f64::min)
+        .unwrap_or(1.);
+
+    let bounds = if has_multi_dim_demand(problem) {
+        LowerBounds::new::<MultiDimLoad>(problem, jobs.as_slice(), !has_reloads, load_factor)
+    } else {
+        LowerBounds::new::<SingleDimLoad>(problem, jobs.as_slice(), !has_reloads, load_factor)
+    };
+
+    let create_bound =
+        |value: f64, lower_bound: f64| QualityBound { lower_bound, gap: get_relative_gap(value, lower_bound) };
+    let statistic = &api_solution.statistic;
+
+    Quality {
+        cost: create_bound(statistic.cost, bounds.cost),
+        distance: create_bound(statistic.distance as f64, bounds.distance),
+        driving: create_bound(statistic.times.driving as f64, bounds.duration),
+        tours: bounds.tours.map(|tours| create_bound(api_solution.tours.len() as f64, tours as f64)),
+    }
+}
+
 fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
     metrics.as_ref().map(|metrics| ApiMetrics {
         duration: metrics.duration,
@@ -473,5 +620,24 @@ fn get_api_metrics(metrics: Option<&TelemetryMetrics>) -> Option<ApiMetrics> {
                 },
             })
             .collect(),
+        operators: if metrics.operators.is_empty() {
+            None
+        } else {
+            Some(
+                metrics
+                    .operators
+                    .iter()
+                    .map(|o| ApiOperatorStatistic {
+                        state: o.state.clone(),
+                        name: o.name.clone(),
+                        alpha: o.alpha,
+                        beta: o.beta,
+                        mu: o.mu,
+                        v: o.v,
+                        n: o.n,
+                    })
+                    .collect(),
+            )
+        },
     })
 }
@@ -61,3 +61,15 @@ fn parse_time_safe(time: &str) -> Result<f64, GenericError> {
         .map(|time| time.unix_timestamp() as f64)
         .map_err(|err| format!("cannot parse date: {err}").into())
 }
+
+fn parse_time_of_day_safe(time: &str) -> Result<f64, GenericError> {
+    let parts = time.split(':').map(|part| part.parse::<u32>().ok()).collect::<Option<Vec<_>>>();
+
+    match parts.as_deref() {
+        Some([hours, minutes]) if *hours < 24 && *minutes < 60 => Ok((hours * 3600 + minutes * 60) as f64),
+        Some([hours, minutes, seconds]) if *hours < 24 && *minutes < 60 && *seconds < 60 => {
+            Ok((hours * 3600 + minutes * 60 + seconds) as f64)
+        }
+        _ => Err(format!("cannot parse time of day: '{time}'").into()),
+    }
+}
@@ -179,6 +179,34 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job preferences have non-empty skills and positive weights.
+fn check_e1108_invalid_preferences(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.preferences.as_ref().is_some_and(|preferences| {
+                preferences
+                    .wanted
+                    .iter()
+                    .chain(preferences.unwanted.iter())
+                    .flatten()
+                    .any(|preference| preference.skills.is_empty() || preference.weight <= 0.)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job has invalid preferences".to_string(),
+            format!("use non-empty skills and positive weights for preferences in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +218,7 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_invalid_preferences(ctx),
     ])
     .map_err(|errors| errors.into())
 }
@@ -31,6 +31,9 @@ use self::relations::validate_relations;
 
 mod routing;
 use self::routing::validate_routing;
+
+mod territories;
+use self::territories::validate_territories;
 use hashbrown::HashMap;
 
 impl<'a> ValidationContext<'a> {
@@ -53,6 +56,7 @@ impl<'a> ValidationContext<'a> {
             .chain(validate_objectives(self).err())
             .chain(validate_routing(self).err())
             .chain(validate_relations(self).err())
+            .chain(validate_territories(self).err())
             .flatten()
             .collect::<Vec<_>>()
             .into();
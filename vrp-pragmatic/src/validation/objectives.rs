@@ -5,6 +5,7 @@ mod objectives_test;
 use super::*;
 use crate::format::problem::Objective::*;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 
 /// Checks that objective is not empty when specified.
 fn check_e1600_empty_objective(objectives: &[&Objective]) -> Result<(), FormatError> {
@@ -40,6 +41,10 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 CompactTour { .. } => acc.entry("compact-tour"),
                 TourOrder => acc.entry("tour-order"),
                 FastService { .. } => acc.entry("fast-service"),
+                TerritoryAffinity => acc.entry("territory-affinity"),
+                JobPreference => acc.entry("job-preference"),
+                MinTourLimits => acc.entry("min-tour-limits"),
+                MinimizeChanges { .. } => acc.entry("minimize-changes"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -186,6 +191,135 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that territory objective is specified only when vehicles with soft territories are used and vice versa.
+fn check_e1608_territory_objective(ctx: &ValidationContext, objectives: &[&Objective]) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_territory_objective = objectives.iter().any(|objective| matches!(objective, TerritoryAffinity));
+    let has_soft_territories = ctx
+        .vehicles()
+        .filter_map(|vehicle| vehicle.territories.as_ref())
+        .any(|territories| matches!(territories.policy, VehicleTerritoryPolicy::Soft));
+
+    match (has_territory_objective, has_soft_territories) {
+        (true, false) => Err(FormatError::new(
+            "E1608".to_string(),
+            "redundant territory objective".to_string(),
+            "specify at least one vehicle with soft territory policy or delete 'territory-affinity' objective"
+                .to_string(),
+        )),
+        (false, true) => Err(FormatError::new(
+            "E1608".to_string(),
+            "missing territory objective".to_string(),
+            "specify 'territory-affinity' objective, remove objectives property or use hard territory policy"
+                .to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that job preference objective is used together with job preferences.
+fn check_e1609_job_preference_objective(ctx: &ValidationContext, objectives: &[&Objective]) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_preference_objective = objectives.iter().any(|objective| matches!(objective, JobPreference));
+    let has_preferences = ctx.jobs().any(has_job_preferences);
+
+    match (has_preference_objective, has_preferences) {
+        (true, false) => Err(FormatError::new(
+            "E1609".to_string(),
+            "redundant job preference objective".to_string(),
+            "specify at least one job with preferences or delete 'job-preference' objective".to_string(),
+        )),
+        (false, true) => Err(FormatError::new(
+            "E1609".to_string(),
+            "missing job preference objective".to_string(),
+            "specify 'job-preference' objective or remove objectives property".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that min tour limits objective is used together with vehicle minimum limits.
+fn check_e1610_min_tour_limits_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_min_limits_objective = objectives.iter().any(|objective| matches!(objective, MinTourLimits));
+    let has_min_limits = ctx
+        .vehicles()
+        .filter_map(|vehicle| vehicle.limits.as_ref())
+        .any(|limits| limits.min_tour_size.is_some() || limits.min_duration.is_some() || limits.min_load.is_some());
+
+    match (has_min_limits_objective, has_min_limits) {
+        (true, false) => Err(FormatError::new(
+            "E1610".to_string(),
+            "redundant min tour limits objective".to_string(),
+            "specify at least one vehicle with minimum limits or delete 'min-tour-limits' objective".to_string(),
+        )),
+        (false, true) => Err(FormatError::new(
+            "E1610".to_string(),
+            "missing min tour limits objective".to_string(),
+            "specify 'min-tour-limits' objective or remove objectives property".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that minimize changes objective has a valid reference solution.
+fn check_e1611_minimize_changes_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let Some((reference, options)) = objectives.iter().find_map(|objective| match objective {
+        MinimizeChanges { reference, options } => Some((reference, options)),
+        _ => None,
+    }) else {
+        return Ok(());
+    };
+
+    let vehicle_ids = ctx.vehicles().flat_map(|vehicle| vehicle.vehicle_ids.iter()).collect::<HashSet<_>>();
+    let unknown_ids = reference
+        .iter()
+        .filter(|tour| !vehicle_ids.contains(&tour.vehicle_id))
+        .map(|tour| tour.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    let has_negative_weights = options.as_ref().is_some_and(|options| {
+        options.reassignment.is_some_and(|weight| weight < 0.) || options.sequence.is_some_and(|weight| weight < 0.)
+    });
+
+    if reference.is_empty() {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "empty reference solution in minimize changes objective".to_string(),
+            "specify reference tours in 'minimize-changes' objective or supply reference solution".to_string(),
+        ))
+    } else if !unknown_ids.is_empty() {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "unknown vehicle in minimize changes objective".to_string(),
+            format!("remove reference tours of unknown vehicles: '{}'", unknown_ids.join(", ")),
+        ))
+    } else if has_negative_weights {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "negative weight in minimize changes objective".to_string(),
+            "use non-negative weights in 'minimize-changes' objective options".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
 }
@@ -201,6 +335,10 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_territory_objective(ctx, &objectives),
+            check_e1609_job_preference_objective(ctx, &objectives),
+            check_e1610_min_tour_limits_objective(ctx, &objectives),
+            check_e1611_minimize_changes_objective(ctx, &objectives),
         ])
         .map_err(|errors| errors.into())
     } else {
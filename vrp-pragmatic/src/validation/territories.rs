@@ -0,0 +1,126 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/territories_test.rs"]
+mod territories_test;
+
+use super::*;
+use crate::format::Location;
+use crate::utils::combine_error_results;
+use hashbrown::HashSet;
+
+/// Checks that territory ids are unique.
+fn check_e1400_duplicate_territory_ids(territories: &[Territory]) -> Result<(), FormatError> {
+    let mut ids = HashSet::new();
+    let mut duplicates = territories
+        .iter()
+        .filter(|territory| !ids.insert(territory.id.as_str()))
+        .map(|territory| territory.id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    duplicates.sort();
+
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1400".to_string(),
+            "duplicated territory ids".to_string(),
+            format!("remove duplicated territory ids: '{}'", duplicates.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle territory ids are defined in plan.
+fn check_e1401_vehicle_territory_existence(
+    ctx: &ValidationContext,
+    territories: &[Territory],
+) -> Result<(), FormatError> {
+    let ids = territories.iter().map(|territory| territory.id.as_str()).collect::<HashSet<_>>();
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.territories.as_ref().is_some_and(|territories| {
+                territories.ids.is_empty() || territories.ids.iter().any(|id| !ids.contains(id.as_str()))
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1401".to_string(),
+            "vehicle has unknown or empty territory ids".to_string(),
+            format!(
+                "define territories in the plan or fix vehicle territory ids, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that territory geometry is valid.
+fn check_e1402_territory_geometry(territories: &[Territory]) -> Result<(), FormatError> {
+    let is_valid_polygon = |rings: &Vec<Vec<(f64, f64)>>| !rings.is_empty() && rings.iter().all(|ring| ring.len() > 2);
+
+    let ids = territories
+        .iter()
+        .filter(|territory| match &territory.geometry {
+            TerritoryGeometry::Polygon { coordinates } => !is_valid_polygon(coordinates),
+            TerritoryGeometry::MultiPolygon { coordinates } => {
+                coordinates.is_empty() || !coordinates.iter().all(is_valid_polygon)
+            }
+        })
+        .map(|territory| territory.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1402".to_string(),
+            "invalid territory geometry".to_string(),
+            format!("ensure that each polygon ring has at least three points, territory ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job locations are specified as geo coordinates when territories are used.
+fn check_e1403_job_locations_are_coordinates(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_vehicle_territories = ctx.vehicles().any(|vehicle| vehicle.territories.is_some());
+
+    let job_ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .any(|place| !matches!(place.location, Location::Coordinate { .. }))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if !has_vehicle_territories || job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1403".to_string(),
+            "territories cannot be used with non geo coordinate job locations".to_string(),
+            format!("use geo coordinates for job locations, job ids: '{}'", job_ids.join(", ")),
+        ))
+    }
+}
+
+/// Validates territories from the plan and their usage by the fleet.
+pub fn validate_territories(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    let territories = ctx.problem.plan.territories.as_deref().unwrap_or(&[]);
+
+    combine_error_results(&[
+        check_e1400_duplicate_territory_ids(territories),
+        check_e1401_vehicle_territory_existence(ctx, territories),
+        check_e1402_territory_geometry(territories),
+        check_e1403_job_locations_are_coordinates(ctx),
+    ])
+    .map_err(|errors| errors.into())
+}
@@ -5,7 +5,7 @@ mod vehicles_test;
 use super::*;
 use crate::utils::combine_error_results;
 use crate::validation::common::get_time_windows;
-use crate::{parse_time, parse_time_safe};
+use crate::{parse_time, parse_time_of_day_safe, parse_time_safe};
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use vrp_core::models::common::TimeWindow;
@@ -264,6 +264,112 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle minimum tour limits are consistent with other vehicle properties.
+fn check_e1309_vehicle_min_tour_limits(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().is_some_and(|limits| {
+                let is_invalid_tour_size = limits.min_tour_size.is_some_and(|min_size| {
+                    min_size == 0 || limits.tour_size.is_some_and(|max_size| min_size > max_size)
+                });
+                let is_invalid_duration = limits.min_duration.is_some_and(|min_duration| {
+                    min_duration <= 0. || limits.max_duration.is_some_and(|max_duration| min_duration > max_duration)
+                });
+                let is_invalid_load = limits.min_load.as_ref().is_some_and(|min_load| {
+                    min_load.len() != vehicle.capacity.len()
+                        || min_load.iter().zip(vehicle.capacity.iter()).any(|(min, max)| *min < 0 || min > max)
+                });
+
+                is_invalid_tour_size || is_invalid_duration || is_invalid_load
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle minimum tour limits".to_string(),
+            format!(
+                "ensure that minimum limits are positive and do not exceed maximum limits or capacity, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle time bands are valid.
+fn check_e1310_vehicle_time_bands(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.costs.time_bands.iter().flat_map(|time_bands| time_bands.iter()).any(|time_band| match time_band {
+                VehicleTimeBand::Duration { start, factor } => *start < 0. || *factor <= 0.,
+                VehicleTimeBand::TimeOfDay { start, end, factor } => {
+                    match (parse_time_of_day_safe(start), parse_time_of_day_safe(end)) {
+                        (Ok(start), Ok(end)) => start == end || *factor <= 0.,
+                        _ => true,
+                    }
+                }
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle time bands".to_string(),
+            format!(
+                "ensure that time band factors are positive, duration is not negative and time of day is specified \
+                 in 'HH:MM' or 'HH:MM:SS' format with different start and end, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle load dependent cost factors are valid.
+fn check_e1311_vehicle_load_factors(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.costs.load_factors.as_ref().is_some_and(|load_factors| {
+                let has_invalid_values = load_factors.iter().any(|load_factor| {
+                    !(0. ..=1.).contains(&load_factor.load)
+                        || !load_factor.factor.is_finite()
+                        || load_factor.factor <= 0.
+                });
+                let unique_loads =
+                    load_factors.iter().map(|load_factor| load_factor.load.to_bits()).collect::<HashSet<_>>();
+
+                load_factors.is_empty() || has_invalid_values || unique_loads.len() != load_factors.len()
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle load factors".to_string(),
+            format!(
+                "ensure that load factors are not empty, loads are unique and in range [0, 1] and factors are \
+                 positive, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -311,6 +417,9 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_min_tour_limits(ctx),
+        check_e1310_vehicle_time_bands(ctx),
+        check_e1311_vehicle_load_factors(ctx),
     ])
     .map_err(|errors| errors.into())
 }
@@ -100,6 +100,7 @@ fn create_statistic(data: (f64, i64, i64, (i64, i64, i64, i64))) -> Statistic {
             parking: data.3 .3,
             ..Timing::default()
         },
+        breakdown: None,
     }
 }
 
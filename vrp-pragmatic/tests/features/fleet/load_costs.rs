@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_prefer_delivering_heavy_job_first_with_load_factors() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", (5., 0.), vec![1]),
+                create_delivery_job_with_demand("job2", (0., 5.), vec![9]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    load_factors: Some(vec![
+                        VehicleLoadFactor { load: 0., factor: 1. },
+                        VehicleLoadFactor { load: 1., factor: 2. },
+                    ]),
+                    ..create_default_vehicle_costs()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![
+            vec!["departure".to_string()],
+            vec!["job2".to_string()],
+            vec!["job1".to_string()],
+            vec!["arrival".to_string()]
+        ]
+    );
+    // NOTE regular cost is 46, extra load cost: 10 on first leg (full load) and 1.4 on second (10% load)
+    assert!((solution.statistic.cost - 57.4).abs() < 1E-6);
+}
@@ -1,5 +1,7 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod load_costs;
 mod multi_dimens;
 mod profile_variation;
+mod time_bands;
 mod unreachable_jobs;
@@ -0,0 +1,35 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_time_bands_in_costs, (time_band, expected), {
+    can_use_time_bands_in_costs_impl(time_band, expected);
+}}
+
+can_use_time_bands_in_costs! {
+    case01_duration: (VehicleTimeBand::Duration { start: 2., factor: 3. }, (15., 2.)),
+    case02_time_of_day: (VehicleTimeBand::TimeOfDay { start: "00:00:01".to_string(), end: "00:00:02".to_string(), factor: 2. }, (15., 1.)),
+    case03_not_used: (VehicleTimeBand::Duration { start: 10., factor: 3. }, (15., 0.)),
+}
+
+fn can_use_time_bands_in_costs_impl(time_band: VehicleTimeBand, expected: (f64, f64)) {
+    let (regular, overtime) = expected;
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { time_bands: Some(vec![time_band]), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.cost, regular + overtime);
+    assert_eq!(solution.statistic.breakdown, Some(CostBreakdown { regular, overtime }));
+}
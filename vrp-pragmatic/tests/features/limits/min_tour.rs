@@ -0,0 +1,75 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_min_tour_size_limits(min_tour_size: usize) -> Option<VehicleLimits> {
+    Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: None,
+        min_tour_size: Some(min_tour_size),
+        min_duration: None,
+        min_load: None,
+    })
+}
+
+#[test]
+fn can_report_min_tour_size_violation() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { limits: create_min_tour_size_limits(3), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::MinTourLimit {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            limits: vec!["minTourSize".to_string()],
+        }])
+    );
+}
+
+#[test]
+fn can_balance_jobs_to_meet_min_tour_size() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (-1., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![3],
+                limits: create_min_tour_size_limits(2),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.violations.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.tours.iter().all(|tour| tour.stops.iter().flat_map(|stop| stop.activities()).count() == 4));
+}
@@ -1,3 +1,4 @@
 mod max_distance;
 mod max_duration;
+mod min_tour;
 mod tour_size;
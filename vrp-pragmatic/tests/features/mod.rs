@@ -11,11 +11,14 @@ mod group;
 mod limits;
 mod multjob;
 mod pickdev;
+mod preferences;
 mod priorities;
 mod recharge;
 mod relations;
 mod reload;
 mod skills;
+mod stability;
+mod territories;
 mod timing;
 mod tour_shape;
 mod unassigned;
@@ -0,0 +1,72 @@
+use crate::format::problem::*;
+use crate::format::solution::PreferenceScore;
+use crate::helpers::*;
+
+fn create_vehicle_with_skills(type_id: &str, skills: Vec<&str>) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{type_id}_1")],
+        skills: Some(to_strings(skills)),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_job_with_preferences(
+    id: &str,
+    wanted: Option<Vec<(Vec<&str>, f64)>>,
+    unwanted: Option<Vec<(Vec<&str>, f64)>>,
+) -> Job {
+    let map = |preferences: Option<Vec<(Vec<&str>, f64)>>| {
+        preferences.map(|preferences| {
+            preferences
+                .into_iter()
+                .map(|(skills, weight)| JobPreference { skills: to_strings(skills), weight })
+                .collect()
+        })
+    };
+
+    Job {
+        preferences: Some(JobPreferences { wanted: map(wanted), unwanted: map(unwanted) }),
+        ..create_delivery_job(id, (1., 0.))
+    }
+}
+
+parameterized_test! {can_prefer_vehicle_with_preferred_skills, (wanted, unwanted, expected_vehicle, expected_score), {
+    can_prefer_vehicle_with_preferred_skills_impl(wanted, unwanted, expected_vehicle, expected_score);
+}}
+
+can_prefer_vehicle_with_preferred_skills! {
+    case01_wanted: (Some(vec![(vec!["van"], 10.)]), None, "van_1", (10., 0.)),
+    case02_unwanted: (None, Some(vec![(vec!["van"], 10.)]), "truck_1", (10., 0.)),
+    case03_mixed: (Some(vec![(vec!["truck"], 5.)]), Some(vec![(vec!["truck"], 10.)]), "van_1", (10., 5.)),
+}
+
+fn can_prefer_vehicle_with_preferred_skills_impl(
+    wanted: Option<Vec<(Vec<&str>, f64)>>,
+    unwanted: Option<Vec<(Vec<&str>, f64)>>,
+    expected_vehicle: &str,
+    expected_score: (f64, f64),
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_job_with_preferences("job1", wanted, unwanted)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_skills("truck", vec!["truck"]),
+                create_vehicle_with_skills("van", vec!["van"]),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, expected_vehicle);
+    assert_eq!(
+        solution.tours[0].preferences,
+        Some(PreferenceScore { satisfied: expected_score.0, unsatisfied: expected_score.1 })
+    );
+}
@@ -15,7 +15,14 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None })
+    Some(VehicleLimits {
+        max_distance: Some(15.),
+        max_duration: None,
+        tour_size: None,
+        min_tour_size: None,
+        min_duration: None,
+        min_load: None,
+    })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Vec<Objective>> {
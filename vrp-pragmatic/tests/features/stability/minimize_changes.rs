@@ -0,0 +1,95 @@
+use crate::format::problem::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+fn create_reference_tour(vehicle_id: &str, job_ids: &[&str]) -> ReferenceTour {
+    ReferenceTour { vehicle_id: vehicle_id.to_string(), shift_index: 0, job_ids: to_strings(job_ids.to_vec()) }
+}
+
+fn create_problem(vehicles: Vec<VehicleType>, reference: Vec<ReferenceTour>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles, ..create_default_fleet() },
+        objectives: Some(vec![
+            vec![Objective::MinimizeUnassigned { breaks: None }],
+            vec![Objective::MinimizeChanges { reference, options: None }],
+            vec![Objective::MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(tour: &Tour) -> Vec<String> {
+    get_ids_from_tour(tour).into_iter().flatten().filter(|id| id.starts_with("job")).collect()
+}
+
+#[test]
+fn can_keep_jobs_assigned_to_reference_vehicles() {
+    let problem = create_problem(
+        vec![create_default_vehicle("a"), create_default_vehicle("b")],
+        vec![create_reference_tour("a_1", &["job2"]), create_reference_tour("b_1", &["job1", "job3"])],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let tour_a = solution.tours.iter().find(|tour| tour.vehicle_id == "a_1").unwrap();
+    let tour_b = solution.tours.iter().find(|tour| tour.vehicle_id == "b_1").unwrap();
+    assert_eq!(get_job_ids(tour_a), vec!["job2".to_string()]);
+    assert_eq!(get_job_ids(tour_b), vec!["job1".to_string(), "job3".to_string()]);
+}
+
+#[test]
+fn can_keep_reference_job_sequence() {
+    let problem = create_problem(
+        vec![create_default_vehicle("a")],
+        vec![create_reference_tour("a_1", &["job3", "job1", "job2"])],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_job_ids(&solution.tours[0]), to_strings(vec!["job3", "job1", "job2"]));
+}
+
+#[test]
+fn can_set_reference_solution() {
+    let problem = create_problem(vec![create_default_vehicle("a")], vec![]);
+    let reference_problem = create_problem(
+        vec![create_default_vehicle("a")],
+        vec![create_reference_tour("a_1", &["job2", "job1", "job3"])],
+    );
+    let matrix = create_matrix_from_problem(&reference_problem);
+    let reference_solution = solve_with_metaheuristic(reference_problem, Some(vec![matrix.clone()]));
+    let mut problem = problem;
+
+    set_reference_solution(&mut problem, &reference_solution).unwrap();
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+    assert_eq!(get_job_ids(&solution.tours[0]), to_strings(vec!["job2", "job1", "job3"]));
+}
+
+#[test]
+fn can_return_error_when_setting_reference_without_objective() {
+    let mut problem = Problem { objectives: None, ..create_problem(vec![create_default_vehicle("a")], vec![]) };
+    let solution = crate::format::solution::Solution {
+        statistic: Default::default(),
+        tours: vec![],
+        unassigned: None,
+        violations: None,
+        extras: None,
+    };
+
+    assert!(set_reference_solution(&mut problem, &solution).is_err());
+}
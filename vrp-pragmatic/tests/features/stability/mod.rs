@@ -0,0 +1 @@
+mod minimize_changes;
@@ -0,0 +1,100 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(policy: VehicleTerritoryPolicy, objectives: Option<Vec<Vec<Objective>>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (-1., 0.))],
+            territories: Some(vec![
+                create_rect_territory("north", (0.5, -1.), (2., 1.)),
+                create_rect_territory("south", (-2., -1.), (-0.5, 1.)),
+            ]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    type_id: "north_vehicle".to_string(),
+                    vehicle_ids: vec!["north_vehicle_1".to_string()],
+                    territories: create_vehicle_territories(vec!["north"], policy.clone()),
+                    ..create_default_vehicle_type()
+                },
+                VehicleType {
+                    type_id: "south_vehicle".to_string(),
+                    vehicle_ids: vec!["south_vehicle_1".to_string()],
+                    territories: create_vehicle_territories(vec!["south"], policy),
+                    costs: VehicleCosts {
+                        fixed: Some(1000.),
+                        distance: 1.,
+                        time: 1.,
+                        time_bands: None,
+                        load_factors: None,
+                    },
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        objectives,
+    }
+}
+
+fn get_assignments(solution: &crate::format::solution::Solution) -> Vec<(String, Vec<String>)> {
+    let mut assignments = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let mut job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .filter(|activity| activity.activity_type == "delivery")
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+            job_ids.sort();
+            (tour.vehicle_id.clone(), job_ids)
+        })
+        .collect::<Vec<_>>();
+    assignments.sort();
+
+    assignments
+}
+
+#[test]
+fn can_serve_jobs_only_inside_hard_territories() {
+    let problem = create_test_problem(VehicleTerritoryPolicy::Hard, None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_assignments(&solution),
+        vec![
+            ("north_vehicle_1".to_string(), vec!["job1".to_string()]),
+            ("south_vehicle_1".to_string(), vec!["job2".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn can_serve_jobs_outside_soft_territories() {
+    let problem = create_test_problem(
+        VehicleTerritoryPolicy::Soft,
+        Some(vec![
+            vec![Objective::MinimizeUnassigned { breaks: None }],
+            vec![Objective::MinimizeTours],
+            vec![Objective::TerritoryAffinity],
+            vec![Objective::MinimizeCost],
+        ]),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_assignments(&solution),
+        vec![("north_vehicle_1".to_string(), vec!["job1".to_string(), "job2".to_string()])]
+    );
+}
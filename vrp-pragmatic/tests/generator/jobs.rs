@@ -88,6 +88,7 @@ prop_compose! {
             replacements: None,
             services: None,
             skills,
+            preferences: None,
             value,
             group,
             compatibility
@@ -134,6 +135,7 @@ prop_compose! {
             replacements,
             services,
             skills,
+            preferences: None,
             value,
             group,
             compatibility,
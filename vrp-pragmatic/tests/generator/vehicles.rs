@@ -33,6 +33,7 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            territories: None,
         }
     }
 }
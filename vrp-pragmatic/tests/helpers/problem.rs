@@ -24,6 +24,7 @@ pub fn create_job(id: &str) -> Job {
         replacements: None,
         services: None,
         skills: None,
+        preferences: None,
         value: None,
         group: None,
         compatibility: None,
@@ -226,7 +227,7 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., time_bands: None, load_factors: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -255,6 +256,7 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        territories: None,
     }
 }
 
@@ -271,7 +273,7 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, territories: None }
 }
 
 pub fn create_empty_problem() -> Problem {
@@ -322,6 +324,27 @@ pub fn all_of_skills(skills: Vec<String>) -> JobSkills {
     JobSkills { all_of: Some(skills), one_of: None, none_of: None }
 }
 
+pub fn create_rect_territory(id: &str, min: (f64, f64), max: (f64, f64)) -> Territory {
+    let ((min_lat, min_lng), (max_lat, max_lng)) = (min, max);
+
+    Territory {
+        id: id.to_string(),
+        geometry: TerritoryGeometry::Polygon {
+            coordinates: vec![vec![
+                (min_lng, min_lat),
+                (max_lng, min_lat),
+                (max_lng, max_lat),
+                (min_lng, max_lat),
+                (min_lng, min_lat),
+            ]],
+        },
+    }
+}
+
+pub fn create_vehicle_territories(ids: Vec<&str>, policy: VehicleTerritoryPolicy) -> Option<VehicleTerritories> {
+    Some(VehicleTerritories { ids: to_strings(ids), policy })
+}
+
 fn convert_times(times: &[(i32, i32)]) -> Option<Vec<Vec<String>>> {
     if times.is_empty() {
         None
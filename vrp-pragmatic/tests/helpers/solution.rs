@@ -371,6 +371,7 @@ impl Default for TourBuilder {
                 shift_index: 0,
                 stops: vec![],
                 statistic: Default::default(),
+                preferences: None,
             },
         }
     }
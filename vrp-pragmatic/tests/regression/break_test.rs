@@ -116,7 +116,13 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
+                    costs: VehicleCosts {
+                        fixed: Some(20.),
+                        distance: 0.002,
+                        time: 0.003,
+                        time_bands: None,
+                        load_factors: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
@@ -142,6 +148,7 @@ fn can_handle_properly_invalid_break_removal() {
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    territories: None,
                 }],
                 ..create_default_fleet()
             },
@@ -36,7 +36,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0 },
+                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0, time_bands: None, load_factors: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: format_time(0.),
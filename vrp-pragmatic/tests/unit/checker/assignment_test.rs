@@ -33,6 +33,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 shift_index,
                 stops: vec![],
                 statistic: Statistic::default(),
+                preferences: None,
             })
             .collect(),
         ..SolutionBuilder::default().build()
@@ -166,6 +167,7 @@ fn check_jobs_impl(
                 shift_index,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                preferences: None,
             })
             .collect(),
         unassigned: Some(
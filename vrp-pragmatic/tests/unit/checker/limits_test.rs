@@ -25,6 +25,7 @@ fn create_test_solution(statistic: Statistic, stops: Vec<Stop>) -> Solution {
             shift_index: 0,
             stops,
             statistic,
+            preferences: None,
         })
         .build()
 }
@@ -59,7 +60,14 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), GenericError>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        max_duration,
+        tour_size: None,
+        min_tour_size: None,
+        min_duration: None,
+        min_load: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -71,8 +79,14 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        min_tour_size: None,
+        min_duration: None,
+        min_load: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
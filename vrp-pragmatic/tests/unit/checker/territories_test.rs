@@ -0,0 +1,63 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+parameterized_test! {can_check_territories, (policy, job_location, expected), {
+    can_check_territories_impl(policy, job_location, expected);
+}}
+
+can_check_territories! {
+    case01_hard_inside: (VehicleTerritoryPolicy::Hard, (1., 0.), Ok(())),
+    case02_hard_outside: (VehicleTerritoryPolicy::Hard, (5., 0.), Err(vec![
+        "territory violation, job 'job1' is served outside of territories, vehicle id 'my_vehicle_1', shift index: 0".into()
+    ])),
+    case03_soft_outside: (VehicleTerritoryPolicy::Soft, (5., 0.), Ok(())),
+}
+
+fn can_check_territories_impl(
+    policy: VehicleTerritoryPolicy,
+    job_location: (f64, f64),
+    expected: Result<(), Vec<GenericError>>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", job_location)],
+            territories: Some(vec![create_rect_territory("t1", (-2., -2.), (2., 2.))]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                territories: create_vehicle_territories(vec!["t1"], policy),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate(job_location)
+                        .schedule_stamp(1., 2.)
+                        .load(vec![0])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(3., 3.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_territories(&ctx);
+
+    assert_eq!(result, expected);
+}
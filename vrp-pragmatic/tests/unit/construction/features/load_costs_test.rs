@@ -0,0 +1,38 @@
+use super::*;
+
+parameterized_test! {can_get_load_cost_factor, (points, ratio, expected), {
+    can_get_load_cost_factor_impl(points, ratio, expected);
+}}
+
+can_get_load_cost_factor! {
+    case01_no_points: (vec![], 0.5, 1.),
+    case02_single_point: (vec![(0.5, 1.2)], 0.2, 1.2),
+    case03_exact_point: (vec![(0., 1.), (1., 1.4)], 1., 1.4),
+    case04_interpolated: (vec![(0., 1.), (1., 1.4)], 0.5, 1.2),
+    case05_before_first: (vec![(0.5, 1.2), (1., 1.4)], 0.2, 1.2),
+    case06_unsorted: (vec![(1., 1.4), (0.5, 1.2), (0., 1.)], 0.75, 1.3),
+}
+
+fn can_get_load_cost_factor_impl(points: Vec<(f64, f64)>, ratio: f64, expected: f64) {
+    let factors = LoadCostFactors::new(points);
+
+    let result = factors.get_factor(ratio);
+
+    assert!((result - expected).abs() < 1E-6);
+}
+
+parameterized_test! {can_get_min_load_cost_factor, (points, expected), {
+    can_get_min_load_cost_factor_impl(points, expected);
+}}
+
+can_get_min_load_cost_factor! {
+    case01_no_points: (vec![], 1.),
+    case02_increasing: (vec![(0., 1.), (1., 1.4)], 1.),
+    case03_decreasing: (vec![(0., 1.2), (0.5, 0.8), (1., 0.9)], 0.8),
+}
+
+fn can_get_min_load_cost_factor_impl(points: Vec<(f64, f64)>, expected: f64) {
+    let factors = LoadCostFactors::new(points);
+
+    assert_eq!(factors.get_min_factor(), expected);
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+fn to_skills(skills: Vec<&str>) -> Vec<String> {
+    skills.into_iter().map(|skill| skill.to_string()).collect()
+}
+
+parameterized_test! {can_get_preference_score, (wanted, unwanted, vehicle_skills, expected), {
+    can_get_preference_score_impl(wanted, unwanted, vehicle_skills, expected);
+}}
+
+can_get_preference_score! {
+    case01_no_preferences: (vec![], vec![], Some(vec!["s1"]), (0., 0.)),
+    case02_wanted_satisfied: (vec![(vec!["s1"], 2.)], vec![], Some(vec!["s1", "s2"]), (2., 0.)),
+    case03_wanted_unsatisfied: (vec![(vec!["s1", "s3"], 2.)], vec![], Some(vec!["s1", "s2"]), (0., 2.)),
+    case04_wanted_no_skills: (vec![(vec!["s1"], 2.)], vec![], None, (0., 2.)),
+    case05_unwanted_satisfied: (vec![], vec![(vec!["s3"], 3.)], Some(vec!["s1", "s2"]), (3., 0.)),
+    case06_unwanted_unsatisfied: (vec![], vec![(vec!["s1"], 3.)], Some(vec!["s1", "s2"]), (0., 3.)),
+    case07_unwanted_no_skills: (vec![], vec![(vec!["s1"], 3.)], None, (3., 0.)),
+    case08_mixed: (vec![(vec!["s1"], 1.), (vec!["s3"], 2.)], vec![(vec!["s2"], 4.)], Some(vec!["s1", "s2"]), (1., 6.)),
+}
+
+fn can_get_preference_score_impl(
+    wanted: Vec<(Vec<&str>, f64)>,
+    unwanted: Vec<(Vec<&str>, f64)>,
+    vehicle_skills: Option<Vec<&str>>,
+    expected: (f64, f64),
+) {
+    let map = |preferences: Vec<(Vec<&str>, f64)>| {
+        preferences.into_iter().map(|(skills, weight)| (to_skills(skills), weight)).collect()
+    };
+    let preferences = JobPreferences::new(map(wanted), map(unwanted));
+    let vehicle_skills = vehicle_skills.map(|skills| to_skills(skills).into_iter().collect::<HashSet<_>>());
+
+    let result = preferences.get_score(vehicle_skills.as_ref());
+
+    assert_eq!(result, expected);
+}
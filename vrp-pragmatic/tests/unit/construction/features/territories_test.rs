@@ -0,0 +1,152 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteState};
+use vrp_core::models::problem::{Fleet, Vehicle};
+
+const VIOLATION_CODE: ViolationCode = 1;
+
+fn create_territory_index() -> Arc<TerritoryIndex> {
+    Arc::new(TerritoryIndex::new(
+        vec![
+            (1, vec!["t1".to_string()].into_iter().collect()),
+            (2, vec!["t2".to_string()].into_iter().collect()),
+            (3, vec!["t1".to_string(), "t2".to_string()].into_iter().collect()),
+        ]
+        .into_iter()
+        .collect(),
+    ))
+}
+
+fn create_vehicle_with_territories(territories: Option<(Vec<&str>, TerritoryPolicy)>) -> Vehicle {
+    let mut vehicle = test_vehicle("v1");
+
+    if let Some((ids, policy)) = territories {
+        vehicle.dimens.set_vehicle_territories(VehicleTerritories {
+            ids: ids.into_iter().map(|id| id.to_string()).collect(),
+            policy,
+        });
+    }
+
+    vehicle
+}
+
+fn create_test_fleet(territories: Option<(Vec<&str>, TerritoryPolicy)>) -> Fleet {
+    test_fleet_with_vehicles(vec![Arc::new(create_vehicle_with_territories(territories))])
+}
+
+fn create_job_with_locations(locations: Vec<Location>) -> Job {
+    let mut single = create_single_with_location(None);
+    single.places = locations
+        .into_iter()
+        .map(|location| Place { location: Some(location), ..single.places.first().unwrap().clone() })
+        .collect();
+
+    Job::Single(Arc::new(single))
+}
+
+fn failure() -> Option<ConstraintViolation> {
+    ConstraintViolation::fail(VIOLATION_CODE)
+}
+
+parameterized_test! {can_evaluate_job, (territories, locations, expected), {
+    can_evaluate_job_impl(territories, locations, expected);
+}}
+
+can_evaluate_job! {
+    case01_no_territories: (None, vec![2], None),
+    case02_inside: (Some((vec!["t1"], TerritoryPolicy::Hard)), vec![1], None),
+    case03_outside: (Some((vec!["t1"], TerritoryPolicy::Hard)), vec![2], failure()),
+    case04_unknown_location: (Some((vec!["t1"], TerritoryPolicy::Hard)), vec![4], failure()),
+    case05_one_of_places: (Some((vec!["t1"], TerritoryPolicy::Hard)), vec![2, 3], None),
+    case06_many_territories: (Some((vec!["t1", "t2"], TerritoryPolicy::Hard)), vec![2], None),
+    case07_soft_policy: (Some((vec!["t1"], TerritoryPolicy::Soft)), vec![2], None),
+}
+
+fn can_evaluate_job_impl(
+    territories: Option<(Vec<&str>, TerritoryPolicy)>,
+    locations: Vec<Location>,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_test_fleet(territories);
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
+    let constraint = create_territory_hard_feature("territory", create_territory_index(), VIOLATION_CODE)
+        .unwrap()
+        .constraint
+        .unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &create_job_with_locations(locations),
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+parameterized_test! {can_evaluate_activity, (territories, location, job_type, expected), {
+    can_evaluate_activity_impl(territories, location, job_type, expected);
+}}
+
+can_evaluate_activity! {
+    case01_inside: (Some((vec!["t1"], TerritoryPolicy::Hard)), 1, "delivery", None),
+    case02_outside: (Some((vec!["t1"], TerritoryPolicy::Hard)), 2, "delivery", ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_break: (Some((vec!["t1"], TerritoryPolicy::Hard)), 2, "break", None),
+    case04_no_territories: (None, 2, "delivery", None),
+}
+
+fn can_evaluate_activity_impl(
+    territories: Option<(Vec<&str>, TerritoryPolicy)>,
+    location: Location,
+    job_type: &str,
+    expected: Option<ConstraintViolation>,
+) {
+    let fleet = create_test_fleet(territories);
+    let route_ctx =
+        RouteContext::new_with_state(create_route_with_activities(&fleet, "v1", vec![]), RouteState::default());
+    let constraint = create_territory_hard_feature("territory", create_territory_index(), VIOLATION_CODE)
+        .unwrap()
+        .constraint
+        .unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.start().unwrap(),
+            target: &create_activity_with_job_at_location(create_single_with_type("job1", job_type), location),
+            next: route_ctx.route().tour.end(),
+        },
+    ));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_estimate_soft_territory_cost() {
+    let fleet = create_test_fleet(Some((vec!["t1"], TerritoryPolicy::Soft)));
+    let route_ctx = RouteContext::new_with_state(
+        create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity_with_job_at_location(create_single("job1"), 1)],
+        ),
+        RouteState::default(),
+    );
+    let objective =
+        create_territory_soft_feature("territory_affinity", create_territory_index()).unwrap().objective.unwrap();
+    let estimate = |location: Location| {
+        objective.estimate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: route_ctx.route().tour.start().unwrap(),
+                target: &create_activity_with_job_at_location(create_single("job4"), location),
+                next: route_ctx.route().tour.get(1),
+            },
+        ))
+    };
+
+    assert_eq!(estimate(1), 0.);
+    assert_eq!(estimate(2), 1.);
+}
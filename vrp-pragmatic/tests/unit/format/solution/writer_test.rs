@@ -5,11 +5,16 @@ use crate::helpers::*;
 use std::cmp::Ordering;
 use std::sync::Arc;
 use vrp_core::construction::enablers::ReservedTimeSpan;
+use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::{TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
-use vrp_core::utils::compare_floats;
+use vrp_core::rosomaxa::evolution::TelemetryMode;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::{compare_floats, Environment};
 
 type DomainProblem = vrp_core::models::Problem;
+type DomainSolution = vrp_core::models::Solution;
 type DomainActivity = vrp_core::models::solution::Activity;
 type DomainCommute = vrp_core::models::solution::Commute;
 type DomainCommuteInfo = vrp_core::models::solution::CommuteInfo;
@@ -99,6 +104,7 @@ fn can_merge_activities_with_same_location_in_one_stop() {
             distance: 10,
             duration: 12,
             times: Timing { driving: 10, serving: 2, ..Timing::default() },
+            breakdown: None,
         }
     );
     assert_eq!(solution.tours.len(), 1);
@@ -210,3 +216,37 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+#[test]
+fn can_estimate_solution_quality() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let population = create_elitism_population(core_problem.goal.clone(), environment.clone());
+    let refinement_ctx =
+        RefinementContext::new(core_problem.clone(), Box::new(population), TelemetryMode::None, environment.clone());
+    let mut core_solution: DomainSolution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&refinement_ctx, InsertionContext::new(core_problem.clone(), environment))
+        .into();
+
+    let solution = create_solution(&core_problem, &core_solution, &Default::default());
+    assert!(solution.extras.is_none());
+
+    core_solution.is_final = true;
+    let solution = create_solution(&core_problem, &core_solution, &Default::default());
+
+    let quality = solution.extras.and_then(|extras| extras.quality).expect("no quality");
+    assert_eq!(quality.distance, QualityBound { lower_bound: 15., gap: 0.25 });
+    assert_eq!(quality.driving, QualityBound { lower_bound: 15., gap: 0.25 });
+    assert_eq!(quality.tours, Some(QualityBound { lower_bound: 1., gap: 0. }));
+    assert_eq!(quality.cost.lower_bound, 42.);
+    assert!(compare_floats(quality.cost.gap, 10. / 52.) == Ordering::Equal);
+}
@@ -73,3 +73,35 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_preferences, (skills, weight, expected), {
+    can_detect_invalid_preferences_impl(skills, weight, expected);
+}}
+
+can_detect_invalid_preferences! {
+    case01_valid: (vec!["s1"], 1., None),
+    case02_empty_skills: (vec![], 1., Some(())),
+    case03_zero_weight: (vec!["s1"], 0., Some(())),
+    case04_negative_weight: (vec!["s1"], -1., Some(())),
+}
+
+fn can_detect_invalid_preferences_impl(skills: Vec<&str>, weight: f64, expected: Option<()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                preferences: Some(JobPreferences {
+                    wanted: None,
+                    unwanted: Some(vec![JobPreference { skills: to_strings(skills), weight }]),
+                }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_invalid_preferences(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_eq!(result.map(|err| err.code), expected.map(|_| "E1108".to_string()));
+}
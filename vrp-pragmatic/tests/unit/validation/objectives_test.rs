@@ -218,3 +218,150 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Vec<Objective>
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_job_preference_objective_mismatch, (wanted_size, objectives, expected), {
+    can_detect_job_preference_objective_mismatch_impl(wanted_size, objectives, expected);
+}}
+
+can_detect_job_preference_objective_mismatch! {
+    case01_missing: (Some(1), Some(vec![
+                vec![MinimizeUnassigned { breaks: None }],
+                vec![MinimizeCost],
+            ]), Some("E1609".to_string())),
+    case02_redundant: (None, Some(vec![
+                vec![MinimizeUnassigned { breaks: None }],
+                vec![JobPreference],
+                vec![MinimizeCost],
+            ]), Some("E1609".to_string())),
+    case03_valid: (Some(1), Some(vec![
+                vec![MinimizeUnassigned { breaks: None }],
+                vec![JobPreference],
+                vec![MinimizeCost],
+            ]), None),
+    case04_default: (Some(1), None, None),
+    case05_empty: (Some(0), Some(vec![
+                vec![MinimizeUnassigned { breaks: None }],
+                vec![MinimizeCost],
+            ]), None),
+}
+
+fn can_detect_job_preference_objective_mismatch_impl(
+    wanted_size: Option<usize>,
+    objectives: Option<Vec<Vec<Objective>>>,
+    expected: Option<String>,
+) {
+    let preferences = wanted_size.map(|size| JobPreferences {
+        wanted: Some(
+            (0..size)
+                .map(|idx| crate::format::problem::JobPreference { skills: vec![format!("s{idx}")], weight: 1. })
+                .collect(),
+        ),
+        unwanted: None,
+    });
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { preferences, ..create_delivery_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1609_job_preference_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_min_tour_limits_objective_mismatch, (has_min_limits, objectives, expected), {
+    can_detect_min_tour_limits_objective_mismatch_impl(has_min_limits, objectives, expected);
+}}
+
+can_detect_min_tour_limits_objective_mismatch! {
+    case01_missing: (true, Some(vec![
+                vec![MinimizeUnassigned { breaks: None }],
+                vec![MinimizeCost],
+            ]), Some("E1610".to_string())),
+    case02_redundant: (false, Some(vec![
+                vec![MinimizeUnassigned { breaks: None }],
+                vec![MinTourLimits],
+                vec![MinimizeCost],
+            ]), Some("E1610".to_string())),
+    case03_valid: (true, Some(vec![
+                vec![MinimizeUnassigned { breaks: None }],
+                vec![MinTourLimits],
+                vec![MinimizeCost],
+            ]), None),
+    case04_default: (true, None, None),
+}
+
+fn can_detect_min_tour_limits_objective_mismatch_impl(
+    has_min_limits: bool,
+    objectives: Option<Vec<Vec<Objective>>>,
+    expected: Option<String>,
+) {
+    let limits = VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: None,
+        min_tour_size: Some(2),
+        min_duration: None,
+        min_load: None,
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: if has_min_limits { Some(limits) } else { None },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1610_min_tour_limits_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_minimize_changes_objective, (vehicle_ids, weight, expected), {
+    can_detect_invalid_minimize_changes_objective_impl(vehicle_ids, weight, expected);
+}}
+
+can_detect_invalid_minimize_changes_objective! {
+    case01_valid: (vec!["my_vehicle_1"], Some(1.), None),
+    case02_empty_reference: (vec![], None, Some("E1611".to_string())),
+    case03_unknown_vehicle: (vec!["my_vehicle_1", "unknown"], None, Some("E1611".to_string())),
+    case04_negative_weight: (vec!["my_vehicle_1"], Some(-1.), Some("E1611".to_string())),
+}
+
+fn can_detect_invalid_minimize_changes_objective_impl(
+    vehicle_ids: Vec<&str>,
+    weight: Option<f64>,
+    expected: Option<String>,
+) {
+    let reference = vehicle_ids
+        .into_iter()
+        .map(|vehicle_id| ReferenceTour {
+            vehicle_id: vehicle_id.to_string(),
+            shift_index: 0,
+            job_ids: vec!["job1".to_string()],
+        })
+        .collect();
+    let options = Some(MinimizeChangesOptions { reassignment: weight, sequence: None });
+    let problem = Problem {
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        objectives: Some(vec![vec![MinimizeChanges { reference, options }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1611_minimize_changes_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_problem(territories: Vec<Territory>, vehicle_territories: Option<VehicleTerritories>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            territories: Some(territories),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { territories: vehicle_territories, ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn validate(problem: &Problem) -> Option<Vec<String>> {
+    validate_territories(&ValidationContext::new(problem, None, &CoordIndex::new(problem)))
+        .err()
+        .map(|errors| errors.errors.into_iter().map(|err| err.code).collect())
+}
+
+#[test]
+fn can_detect_duplicated_territory_ids() {
+    let problem = create_test_problem(
+        vec![create_rect_territory("t1", (0., 0.), (1., 1.)), create_rect_territory("t1", (2., 2.), (3., 3.))],
+        None,
+    );
+
+    assert_eq!(validate(&problem), Some(vec!["E1400".to_string()]));
+}
+
+parameterized_test! {can_detect_invalid_vehicle_territory_ids, (ids, expected), {
+    can_detect_invalid_vehicle_territory_ids_impl(ids, expected);
+}}
+
+can_detect_invalid_vehicle_territory_ids! {
+    case01_known: (vec!["t1"], None),
+    case02_unknown: (vec!["t2"], Some(vec!["E1401".to_string()])),
+    case03_empty: (vec![], Some(vec!["E1401".to_string()])),
+}
+
+fn can_detect_invalid_vehicle_territory_ids_impl(ids: Vec<&str>, expected: Option<Vec<String>>) {
+    let problem = create_test_problem(
+        vec![create_rect_territory("t1", (0., 0.), (1., 1.))],
+        create_vehicle_territories(ids, VehicleTerritoryPolicy::Hard),
+    );
+
+    assert_eq!(validate(&problem), expected);
+}
+
+#[test]
+fn can_detect_invalid_territory_geometry() {
+    let problem = create_test_problem(
+        vec![Territory {
+            id: "t1".to_string(),
+            geometry: TerritoryGeometry::Polygon { coordinates: vec![vec![(0., 0.), (1., 1.)]] },
+        }],
+        None,
+    );
+
+    assert_eq!(validate(&problem), Some(vec!["E1402".to_string()]));
+}
+
+#[test]
+fn can_detect_non_coordinate_job_locations() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0)],
+            territories: Some(vec![create_rect_territory("t1", (0., 0.), (1., 1.))]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                territories: create_vehicle_territories(vec!["t1"], VehicleTerritoryPolicy::Hard),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    assert_eq!(validate(&problem), Some(vec!["E1403".to_string()]));
+}
@@ -44,7 +44,7 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts { fixed: None, distance, time, time_bands: None, load_factors: None },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -136,3 +136,103 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_check_min_tour_limits, (limits, expected), {
+    can_check_min_tour_limits_impl(limits, expected);
+}}
+
+can_check_min_tour_limits! {
+    case01_valid: ((Some(2), Some(100.), Some(vec![5])), None),
+    case02_zero_tour_size: ((Some(0), None, None), Some("E1309".to_string())),
+    case03_tour_size_above_max: ((Some(4), None, None), Some("E1309".to_string())),
+    case04_negative_duration: ((None, Some(-1.), None), Some("E1309".to_string())),
+    case05_duration_above_max: ((None, Some(1001.), None), Some("E1309".to_string())),
+    case06_load_above_capacity: ((None, None, Some(vec![11])), Some("E1309".to_string())),
+    case07_load_wrong_dimension: ((None, None, Some(vec![1, 1])), Some("E1309".to_string())),
+}
+
+fn can_check_min_tour_limits_impl(limits: (Option<usize>, Option<f64>, Option<Vec<i32>>), expected: Option<String>) {
+    let (min_tour_size, min_duration, min_load) = limits;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: Some(1000.),
+                    tour_size: Some(3),
+                    min_tour_size,
+                    min_duration,
+                    min_load,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1309_vehicle_min_tour_limits(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_check_time_bands, (time_band, expected), {
+    can_check_time_bands_impl(time_band, expected);
+}}
+
+can_check_time_bands! {
+    case01_valid_duration: (VehicleTimeBand::Duration { start: 28800., factor: 1.5 }, None),
+    case02_negative_duration: (VehicleTimeBand::Duration { start: -1., factor: 1.5 }, Some("E1310".to_string())),
+    case03_zero_factor: (VehicleTimeBand::Duration { start: 28800., factor: 0. }, Some("E1310".to_string())),
+    case04_valid_time_of_day: (VehicleTimeBand::TimeOfDay { start: "22:00".to_string(), end: "06:00:00".to_string(), factor: 1.25 }, None),
+    case05_invalid_time_of_day: (VehicleTimeBand::TimeOfDay { start: "25:00".to_string(), end: "06:00".to_string(), factor: 1.25 }, Some("E1310".to_string())),
+    case06_same_time_of_day: (VehicleTimeBand::TimeOfDay { start: "06:00".to_string(), end: "06:00".to_string(), factor: 1.25 }, Some("E1310".to_string())),
+}
+
+fn can_check_time_bands_impl(time_band: VehicleTimeBand, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { time_bands: Some(vec![time_band]), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_time_bands(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_check_load_factors, (load_factors, expected), {
+    can_check_load_factors_impl(load_factors, expected);
+}}
+
+can_check_load_factors! {
+    case01_valid: (vec![(0., 1.), (1., 1.4)], None),
+    case02_empty: (vec![], Some("E1311".to_string())),
+    case03_load_out_of_range: (vec![(0., 1.), (1.5, 1.4)], Some("E1311".to_string())),
+    case04_zero_factor: (vec![(0., 0.)], Some("E1311".to_string())),
+    case05_duplicate_load: (vec![(0.5, 1.), (0.5, 1.4)], Some("E1311".to_string())),
+}
+
+fn can_check_load_factors_impl(load_factors: Vec<(f64, f64)>, expected: Option<String>) {
+    let load_factors = load_factors.into_iter().map(|(load, factor)| VehicleLoadFactor { load, factor }).collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { load_factors: Some(load_factors), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_load_factors(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
@@ -26,6 +26,7 @@ pub fn read_init_solution<R: Read>(
         routes: vec![],
         unassigned: Default::default(),
         telemetry: None,
+        is_final: false,
     };
 
     loop {
@@ -14,12 +14,18 @@ pub use self::initial_reader::read_init_solution;
 mod routing;
 pub use self::routing::{CoordIndex, CoordIndexAccessor};
 
+use vrp_core::algorithms::geometry::Point;
 use vrp_core::models::{Extras, ExtrasBuilder};
+use vrp_core::solver::search::LocationCoordinates;
 use vrp_core::solver::HeuristicFilter;
 
 pub(crate) fn get_extras(coord_index: CoordIndex) -> Extras {
     let mut extras = ExtrasBuilder::default().build().expect("cannot build extras");
 
+    let locations = coord_index.locations.clone();
+    extras.set_location_coordinates(Arc::new(move |location| {
+        locations.get(location).map(|&(x, y)| Point::new(x as f64, y as f64))
+    }));
     extras.set_coord_index(coord_index);
     extras.set_heuristic_filter(Arc::new(|name| name != "local_reschedule_departure"));
 